@@ -0,0 +1,263 @@
+//! Hidden throughput benchmark (`--benchmark`).
+//!
+//! Feeds synthetic RX data through the same append and render path as a real
+//! connection, at a target byte rate, and reports sustained throughput, slow
+//! (dropped) frames and render FPS when it exits.
+
+use std::fmt;
+use std::io;
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::{Terminal, backend::CrosstermBackend};
+use tuiserial_core::AppState;
+use tuiserial_ui::draw;
+
+use crate::cli_args::BenchmarkOptions;
+
+/// A frame taking longer than this counts as dropped (below 30 FPS).
+const FRAME_BUDGET: Duration = Duration::from_millis(33);
+
+/// Upper bound of synthetic reads delivered per loop iteration, so a slow
+/// frame shows up as backlog instead of one giant catch-up burst.
+const MAX_CHUNKS_PER_FRAME: usize = 256;
+
+/// Synthetic RX source producing a repeating printable pattern at a fixed rate.
+pub struct SyntheticRx {
+    rate: u64,
+    chunk_size: usize,
+    started: Instant,
+    emitted: u64,
+    pattern_pos: u8,
+}
+
+impl SyntheticRx {
+    pub fn new(rate: u64, chunk_size: usize, started: Instant) -> Self {
+        Self {
+            rate,
+            chunk_size: chunk_size.max(1),
+            started,
+            emitted: 0,
+            pattern_pos: 0,
+        }
+    }
+
+    /// Bytes that should have been produced by `now` but have not been yet.
+    pub fn backlog(&self, now: Instant) -> u64 {
+        let due = (now.duration_since(self.started).as_secs_f64() * self.rate as f64) as u64;
+        due.saturating_sub(self.emitted)
+    }
+
+    /// Emit the reads that are due at `now`, at most `max_chunks` of them.
+    pub fn poll(&mut self, now: Instant, max_chunks: usize) -> Vec<Vec<u8>> {
+        let mut chunks = Vec::new();
+        let mut backlog = self.backlog(now);
+        while backlog > 0 && chunks.len() < max_chunks {
+            let len = backlog.min(self.chunk_size as u64) as usize;
+            chunks.push(self.next_chunk(len));
+            self.emitted += len as u64;
+            backlog -= len as u64;
+        }
+        chunks
+    }
+
+    fn next_chunk(&mut self, len: usize) -> Vec<u8> {
+        (0..len)
+            .map(|_| {
+                let b = b' ' + self.pattern_pos;
+                self.pattern_pos = (self.pattern_pos + 1) % 95;
+                b
+            })
+            .collect()
+    }
+}
+
+/// Counters collected while the benchmark runs.
+#[derive(Debug, Default)]
+pub struct BenchmarkReport {
+    pub target_rate: u64,
+    pub chunk_size: usize,
+    pub elapsed: Duration,
+    pub bytes_appended: u64,
+    pub chunks_appended: u64,
+    pub backlog_bytes: u64,
+    pub frames: u64,
+    pub dropped_frames: u64,
+    pub max_frame: Duration,
+    pub render_time: Duration,
+    pub retained_entries: usize,
+}
+
+impl BenchmarkReport {
+    /// Record one loop iteration (ingest + render).
+    pub fn record_frame(&mut self, frame: Duration, render: Duration) {
+        self.frames += 1;
+        self.render_time += render;
+        self.max_frame = self.max_frame.max(frame);
+        if frame > FRAME_BUDGET {
+            self.dropped_frames += 1;
+        }
+    }
+
+    pub fn throughput(&self) -> f64 {
+        per_second(self.bytes_appended, self.elapsed)
+    }
+
+    pub fn fps(&self) -> f64 {
+        per_second(self.frames, self.elapsed)
+    }
+}
+
+fn per_second(count: u64, elapsed: Duration) -> f64 {
+    let secs = elapsed.as_secs_f64();
+    if secs > 0.0 { count as f64 / secs } else { 0.0 }
+}
+
+impl fmt::Display for BenchmarkReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let avg_render_ms = if self.frames > 0 {
+            self.render_time.as_secs_f64() * 1000.0 / self.frames as f64
+        } else {
+            0.0
+        };
+        writeln!(f, "tuiserial benchmark report")?;
+        writeln!(f, "  duration        {:.2} s", self.elapsed.as_secs_f64())?;
+        writeln!(f, "  target rate     {} B/s", self.target_rate)?;
+        writeln!(f, "  read size       {} B", self.chunk_size)?;
+        writeln!(
+            f,
+            "  throughput      {:.0} B/s ({:.1}% of target)",
+            self.throughput(),
+            if self.target_rate > 0 {
+                self.throughput() * 100.0 / self.target_rate as f64
+            } else {
+                0.0
+            }
+        )?;
+        writeln!(
+            f,
+            "  appended        {} B in {} reads",
+            self.bytes_appended, self.chunks_appended
+        )?;
+        writeln!(f, "  backlog at end  {} B", self.backlog_bytes)?;
+        writeln!(
+            f,
+            "  frames          {} ({:.1} FPS)",
+            self.frames,
+            self.fps()
+        )?;
+        writeln!(
+            f,
+            "  dropped frames  {} (> {} ms)",
+            self.dropped_frames,
+            FRAME_BUDGET.as_millis()
+        )?;
+        writeln!(
+            f,
+            "  frame time      avg render {:.2} ms, max frame {:.2} ms",
+            avg_render_ms,
+            self.max_frame.as_secs_f64() * 1000.0
+        )?;
+        write!(f, "  log entries     {}", self.retained_entries)
+    }
+}
+
+/// Run the benchmark until the duration elapses or the user presses q/Esc.
+pub fn run_benchmark(
+    mut terminal: Terminal<CrosstermBackend<io::Stdout>>,
+    opts: &BenchmarkOptions,
+) -> Result<BenchmarkReport> {
+    let mut app = AppState::default();
+    app.load_config();
+    app.config.port = "benchmark".to_string();
+    app.mark_connected();
+
+    let chunk_size = opts
+        .chunk_size
+        .unwrap_or_else(|| app.settings.rx_read_buffer_size());
+    let duration = Duration::from_secs(opts.duration_secs);
+    let started = Instant::now();
+    let mut source = SyntheticRx::new(opts.rate, chunk_size, started);
+    let mut report = BenchmarkReport {
+        target_rate: opts.rate,
+        chunk_size,
+        ..Default::default()
+    };
+
+    while started.elapsed() < duration {
+        let frame_start = Instant::now();
+
+        for chunk in source.poll(frame_start, MAX_CHUNKS_PER_FRAME) {
+            report.bytes_appended += chunk.len() as u64;
+            report.chunks_appended += 1;
+            app.append_rx(chunk);
+        }
+
+        let render_start = Instant::now();
+        terminal.draw(|f| draw(f, &app))?;
+        let render = render_start.elapsed();
+
+        if event::poll(Duration::ZERO)?
+            && let Event::Key(key) = event::read()?
+            && key.kind == KeyEventKind::Press
+            && matches!(key.code, KeyCode::Char('q') | KeyCode::Esc)
+        {
+            break;
+        }
+
+        report.record_frame(frame_start.elapsed(), render);
+    }
+
+    report.elapsed = started.elapsed();
+    report.backlog_bytes = source.backlog(Instant::now());
+    report.retained_entries = app.message_log.entries.len();
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_synthetic_rate() {
+        let start = Instant::now();
+        let mut source = SyntheticRx::new(1000, 64, start);
+
+        let chunks = source.poll(start + Duration::from_millis(500), usize::MAX);
+        let total: usize = chunks.iter().map(Vec::len).sum();
+        assert_eq!(total, 500);
+        assert!(chunks.iter().all(|c| c.len() <= 64));
+        assert_eq!(source.backlog(start + Duration::from_millis(500)), 0);
+    }
+
+    #[test]
+    fn test_synthetic_chunk_cap_leaves_backlog() {
+        let start = Instant::now();
+        let mut source = SyntheticRx::new(10_000, 10, start);
+        let now = start + Duration::from_secs(1);
+        assert_eq!(source.poll(now, 5).len(), 5);
+        assert_eq!(source.backlog(now), 10_000 - 50);
+    }
+
+    #[test]
+    fn test_synthetic_data_printable() {
+        let start = Instant::now();
+        let mut source = SyntheticRx::new(1000, 1000, start);
+        let chunk = source.poll(start + Duration::from_secs(1), 1).remove(0);
+        assert!(chunk.iter().all(|b| (0x20..0x7f).contains(b)));
+    }
+
+    #[test]
+    fn test_report_dropped_frames() {
+        let mut report = BenchmarkReport::default();
+        report.record_frame(Duration::from_millis(10), Duration::from_millis(5));
+        report.record_frame(Duration::from_millis(50), Duration::from_millis(40));
+        report.elapsed = Duration::from_secs(1);
+        assert_eq!(report.frames, 2);
+        assert_eq!(report.dropped_frames, 1);
+        assert_eq!(report.max_frame, Duration::from_millis(50));
+        assert_eq!(report.fps(), 2.0);
+        assert!(report.to_string().contains("dropped frames  1"));
+    }
+}
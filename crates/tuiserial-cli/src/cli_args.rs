@@ -0,0 +1,609 @@
+//! Command-line argument parsing.
+//!
+//! The interactive TUI takes no arguments; flags here select alternative
+//! run modes. Parsing is hand-rolled to keep the binary dependency-free.
+
+use std::path::PathBuf;
+
+use tuiserial_core::ExportFormat;
+use tuiserial_serial::PortInfo;
+
+/// Usage text printed on `--help` or on a parse error.
+pub const USAGE: &str = "\
+Usage: tuiserial [OPTIONS]
+
+Options:
+  -p, --port <PORT>    Serial port for non-interactive modes
+      --usb-serial <SERIAL>
+                       Use the port whose USB adapter has this serial number
+      --port-index <N> Use the N-th detected port (0-based)
+                       Precedence: --port, then --usb-serial, then --port-index
+  -b, --baud <RATE>    Baud rate (default: saved configuration)
+      --stdin          Send standard input to --port and exit at EOF
+      --lines          With --stdin, send one line per write
+      --delay-ms <MS>  With --stdin, pause between writes
+      --headless       Capture --port to --output without the UI until Ctrl+C
+  -o, --output <FILE>  With --headless, file to append entries to
+      --format <FMT>   With --headless, entry format: text, hex (default), csv
+      --metrics-file <FILE>
+                       Write Prometheus-style metrics to FILE every 5 seconds
+                       (UI and --headless)
+      --connect        Connect to the saved port on launch
+      --no-connect     Start disconnected even with auto-connect enabled
+  -h, --help           Print this help
+  -V, --version        Print version";
+
+/// Options for the synthetic RX benchmark (`--benchmark`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchmarkOptions {
+    /// Target synthetic RX rate in bytes per second
+    pub rate: u64,
+    /// How long to run before exiting and printing the report
+    pub duration_secs: u64,
+    /// Bytes per synthetic read; `None` uses the configured RX read buffer
+    pub chunk_size: Option<usize>,
+}
+
+impl Default for BenchmarkOptions {
+    fn default() -> Self {
+        Self {
+            rate: 115_200 / 10,
+            duration_secs: 10,
+            chunk_size: None,
+        }
+    }
+}
+
+/// Options for piping standard input to the port (`--stdin`).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StdinOptions {
+    /// Send one line (up to and including `\n`) per write instead of raw reads
+    pub lines: bool,
+    /// Pause between consecutive writes, in milliseconds
+    pub delay_ms: u64,
+}
+
+/// Options for capturing a port to a file without the UI (`--headless`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct HeadlessOptions {
+    /// File that captured entries are appended to
+    pub output: PathBuf,
+    pub format: ExportFormat,
+}
+
+/// Parsed command-line arguments.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CliArgs {
+    pub help: bool,
+    pub version: bool,
+    /// Run the hidden throughput benchmark instead of the normal UI
+    pub benchmark: Option<BenchmarkOptions>,
+    /// Port used by the non-interactive modes
+    pub port: Option<String>,
+    /// USB adapter serial number, used when `port` is not given
+    pub usb_serial: Option<String>,
+    /// Position in the detected port list, used when neither `port` nor
+    /// `usb_serial` is given
+    pub port_index: Option<usize>,
+    /// Baud rate override for the non-interactive modes
+    pub baud: Option<u32>,
+    /// Transmit standard input instead of running the UI
+    pub stdin: Option<StdinOptions>,
+    /// Capture to a file instead of running the UI
+    pub headless: Option<HeadlessOptions>,
+    /// File the UI or headless capture periodically writes metrics to
+    pub metrics_file: Option<PathBuf>,
+    /// `--connect` / `--no-connect`, overriding `auto_connect_on_start`
+    pub connect: Option<bool>,
+}
+
+/// What the UI does about connecting at launch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StartupConnect {
+    /// Start disconnected without comment
+    Skip,
+    /// Connect to the saved port
+    Connect,
+    /// Connecting was asked for but the saved port was not detected
+    PortMissing,
+}
+
+impl CliArgs {
+    /// Parse arguments (without the program name).
+    pub fn parse<I, S>(args: I) -> Result<Self, String>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let mut out = CliArgs::default();
+        let mut bench = BenchmarkOptions::default();
+        let mut bench_option_seen = false;
+        let mut stdin = StdinOptions::default();
+        let mut stdin_option_seen = false;
+        let mut headless = false;
+        let mut output = None;
+        let mut format = None;
+
+        let mut iter = args.into_iter().map(Into::into);
+        while let Some(arg) = iter.next() {
+            match arg.as_str() {
+                "-h" | "--help" => out.help = true,
+                "-V" | "--version" => out.version = true,
+                "--benchmark" => out.benchmark = Some(BenchmarkOptions::default()),
+                "--bench-rate" => {
+                    bench.rate = parse_value(&arg, iter.next())?;
+                    bench_option_seen = true;
+                }
+                "--bench-secs" => {
+                    bench.duration_secs = parse_value(&arg, iter.next())?;
+                    bench_option_seen = true;
+                }
+                "--bench-chunk" => {
+                    bench.chunk_size = Some(parse_value(&arg, iter.next())?);
+                    bench_option_seen = true;
+                }
+                "-p" | "--port" => {
+                    out.port = Some(
+                        iter.next()
+                            .ok_or_else(|| format!("{arg} requires a value"))?,
+                    );
+                }
+                "--usb-serial" => {
+                    out.usb_serial = Some(
+                        iter.next()
+                            .ok_or_else(|| format!("{arg} requires a value"))?,
+                    );
+                }
+                "--port-index" => {
+                    let value = iter
+                        .next()
+                        .ok_or_else(|| format!("{arg} requires a value"))?;
+                    let index = value
+                        .parse()
+                        .map_err(|_| format!("invalid value '{value}' for {arg}"))?;
+                    out.port_index = Some(index);
+                }
+                "-b" | "--baud" => out.baud = Some(parse_value(&arg, iter.next())?),
+                "--stdin" => out.stdin = Some(StdinOptions::default()),
+                "--lines" => {
+                    stdin.lines = true;
+                    stdin_option_seen = true;
+                }
+                "--delay-ms" => {
+                    stdin.delay_ms = parse_value(&arg, iter.next())?;
+                    stdin_option_seen = true;
+                }
+                "--headless" => headless = true,
+                "-o" | "--output" => {
+                    let value = iter
+                        .next()
+                        .ok_or_else(|| format!("{arg} requires a value"))?;
+                    output = Some(PathBuf::from(value));
+                }
+                "--format" => {
+                    let value = iter
+                        .next()
+                        .ok_or_else(|| format!("{arg} requires a value"))?;
+                    format = Some(value.parse::<ExportFormat>()?);
+                }
+                "--metrics-file" => {
+                    let value = iter
+                        .next()
+                        .ok_or_else(|| format!("{arg} requires a value"))?;
+                    out.metrics_file = Some(PathBuf::from(value));
+                }
+                "--connect" | "--no-connect" => {
+                    let connect = arg == "--connect";
+                    if out.connect.is_some_and(|c| c != connect) {
+                        return Err("--connect and --no-connect cannot be combined".to_string());
+                    }
+                    out.connect = Some(connect);
+                }
+                other => return Err(format!("unexpected argument '{other}'")),
+            }
+        }
+
+        if bench_option_seen {
+            match out.benchmark.as_mut() {
+                Some(opts) => *opts = bench,
+                None => return Err("--bench-* options require --benchmark".to_string()),
+            }
+        }
+
+        if stdin_option_seen {
+            match out.stdin.as_mut() {
+                Some(opts) => *opts = stdin,
+                None => return Err("--lines and --delay-ms require --stdin".to_string()),
+            }
+        }
+
+        match (headless, output) {
+            (true, Some(output)) => {
+                out.headless = Some(HeadlessOptions {
+                    output,
+                    format: format.unwrap_or_default(),
+                })
+            }
+            (true, None) => return Err("--headless requires --output".to_string()),
+            (false, output) if output.is_some() || format.is_some() => {
+                return Err("--output and --format require --headless".to_string());
+            }
+            (false, _) => {}
+        }
+
+        let port_mode = out.stdin.is_some() || out.headless.is_some();
+        if out.stdin.is_some() && out.headless.is_some() {
+            return Err("--stdin and --headless cannot be combined".to_string());
+        }
+        if out.metrics_file.is_some() && (out.stdin.is_some() || out.benchmark.is_some()) {
+            return Err(
+                "--metrics-file cannot be combined with --stdin or --benchmark".to_string(),
+            );
+        }
+        if out.connect.is_some() && (port_mode || out.benchmark.is_some()) {
+            return Err("--connect and --no-connect only apply to the UI".to_string());
+        }
+        let has_port = out.port.is_some() || out.usb_serial.is_some() || out.port_index.is_some();
+        if port_mode && !has_port {
+            return Err(
+                "--stdin and --headless require --port, --usb-serial or --port-index".to_string(),
+            );
+        }
+        if (has_port || out.baud.is_some()) && !port_mode {
+            return Err(
+                "--port, --usb-serial, --port-index and --baud require --stdin or --headless"
+                    .to_string(),
+            );
+        }
+
+        Ok(out)
+    }
+
+    /// Whether the UI connects at launch: the `--connect`/`--no-connect`
+    /// flags take precedence over the `auto_connect_on_start` setting, and
+    /// `saved_port` must be among the detected `ports`.
+    pub fn startup_connect(
+        &self,
+        auto_connect_on_start: bool,
+        saved_port: &str,
+        ports: &[String],
+    ) -> StartupConnect {
+        if !self.connect.unwrap_or(auto_connect_on_start) {
+            StartupConnect::Skip
+        } else if !saved_port.is_empty() && ports.iter().any(|p| p == saved_port) {
+            StartupConnect::Connect
+        } else {
+            StartupConnect::PortMissing
+        }
+    }
+
+    /// The port for the non-interactive modes: `--port` if given, otherwise
+    /// `--usb-serial`, then `--port-index`, looked up in the list from
+    /// `list_ports` (only called when needed). `Ok(None)` when none of the
+    /// flags was given.
+    pub fn resolve_port(
+        &self,
+        list_ports: impl FnOnce() -> Vec<PortInfo>,
+    ) -> Result<Option<String>, String> {
+        if let Some(port) = &self.port {
+            return Ok(Some(port.clone()));
+        }
+        match (&self.usb_serial, self.port_index) {
+            (Some(serial), _) => resolve_usb_serial(serial, &list_ports()).map(Some),
+            (None, Some(index)) => {
+                let names: Vec<String> = list_ports().into_iter().map(|p| p.name).collect();
+                resolve_port_index(index, &names).map(Some)
+            }
+            (None, None) => Ok(None),
+        }
+    }
+}
+
+/// Look up the port whose USB adapter reports `serial`.
+pub fn resolve_usb_serial(serial: &str, ports: &[PortInfo]) -> Result<String, String> {
+    if let Some(port) = ports
+        .iter()
+        .find(|p| p.usb_serial.as_deref() == Some(serial))
+    {
+        return Ok(port.name.clone());
+    }
+    let found: Vec<String> = ports
+        .iter()
+        .filter_map(|p| Some(format!("{}: {}", p.name, p.usb_serial.as_ref()?)))
+        .collect();
+    if found.is_empty() {
+        Err(format!("--usb-serial {serial}: no USB serial ports found"))
+    } else {
+        Err(format!(
+            "--usb-serial {serial}: no such adapter ({})",
+            found.join(", ")
+        ))
+    }
+}
+
+/// Look up the port at `index` in `ports`.
+pub fn resolve_port_index(index: usize, ports: &[String]) -> Result<String, String> {
+    match ports.get(index) {
+        Some(port) => Ok(port.clone()),
+        None if ports.is_empty() => Err(format!("--port-index {index}: no serial ports found")),
+        None => Err(format!(
+            "--port-index {index} is out of range: {} ports found ({})",
+            ports.len(),
+            ports
+                .iter()
+                .enumerate()
+                .map(|(i, p)| format!("{i}: {p}"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )),
+    }
+}
+
+/// Parse the value following `flag` as a positive number.
+fn parse_value<T>(flag: &str, value: Option<String>) -> Result<T, String>
+where
+    T: std::str::FromStr + PartialOrd + Default,
+{
+    let value = value.ok_or_else(|| format!("{flag} requires a value"))?;
+    match value.parse::<T>() {
+        Ok(v) if v > T::default() => Ok(v),
+        _ => Err(format!("invalid value '{value}' for {flag}")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_no_args() {
+        assert_eq!(CliArgs::parse(Vec::<String>::new()), Ok(CliArgs::default()));
+    }
+
+    #[test]
+    fn test_benchmark_defaults() {
+        let args = CliArgs::parse(["--benchmark"]).unwrap();
+        assert_eq!(args.benchmark, Some(BenchmarkOptions::default()));
+    }
+
+    #[test]
+    fn test_benchmark_options_any_order() {
+        let args = CliArgs::parse([
+            "--bench-rate",
+            "1000000",
+            "--benchmark",
+            "--bench-secs",
+            "3",
+            "--bench-chunk",
+            "4096",
+        ])
+        .unwrap();
+        assert_eq!(
+            args.benchmark,
+            Some(BenchmarkOptions {
+                rate: 1_000_000,
+                duration_secs: 3,
+                chunk_size: Some(4096),
+            })
+        );
+    }
+
+    #[test]
+    fn test_stdin_options() {
+        let args = CliArgs::parse(["--stdin", "-p", "/dev/ttyUSB0"]).unwrap();
+        assert_eq!(args.port.as_deref(), Some("/dev/ttyUSB0"));
+        assert_eq!(args.stdin, Some(StdinOptions::default()));
+
+        let args = CliArgs::parse([
+            "--delay-ms",
+            "50",
+            "--port",
+            "COM3",
+            "--baud",
+            "115200",
+            "--stdin",
+            "--lines",
+        ])
+        .unwrap();
+        assert_eq!(args.baud, Some(115_200));
+        assert_eq!(
+            args.stdin,
+            Some(StdinOptions {
+                lines: true,
+                delay_ms: 50,
+            })
+        );
+    }
+
+    #[test]
+    fn test_stdin_errors() {
+        assert!(CliArgs::parse(["--stdin"]).is_err());
+        assert!(CliArgs::parse(["--port", "COM3"]).is_err());
+        assert!(CliArgs::parse(["--port"]).is_err());
+        assert!(CliArgs::parse(["--lines", "--port", "COM3"]).is_err());
+        assert!(CliArgs::parse(["--stdin", "--port", "COM3", "--baud", "0"]).is_err());
+    }
+
+    #[test]
+    fn test_headless_options() {
+        let args = CliArgs::parse([
+            "--headless",
+            "-p",
+            "COM3",
+            "-o",
+            "capture.log",
+            "--format",
+            "csv",
+        ])
+        .unwrap();
+        assert_eq!(
+            args.headless,
+            Some(HeadlessOptions {
+                output: PathBuf::from("capture.log"),
+                format: ExportFormat::Csv,
+            })
+        );
+
+        let args = CliArgs::parse(["--headless", "--port", "COM3", "--output", "x"]).unwrap();
+        assert_eq!(args.headless.unwrap().format, ExportFormat::Hex);
+    }
+
+    #[test]
+    fn test_headless_errors() {
+        assert!(CliArgs::parse(["--headless", "--port", "COM3"]).is_err());
+        assert!(CliArgs::parse(["--headless", "--output", "x"]).is_err());
+        assert!(CliArgs::parse(["--output", "x"]).is_err());
+        assert!(
+            CliArgs::parse(["--headless", "-p", "COM3", "-o", "x", "--format", "xml"]).is_err()
+        );
+        assert!(CliArgs::parse(["--headless", "--stdin", "-p", "COM3", "-o", "x"]).is_err());
+    }
+
+    #[test]
+    fn test_metrics_file() {
+        let args = CliArgs::parse(["--metrics-file", "/tmp/tuiserial.prom"]).unwrap();
+        assert_eq!(
+            args.metrics_file,
+            Some(PathBuf::from("/tmp/tuiserial.prom"))
+        );
+        let args =
+            CliArgs::parse(["--headless", "-p", "COM3", "-o", "x", "--metrics-file", "m"]).unwrap();
+        assert!(args.headless.is_some() && args.metrics_file.is_some());
+
+        assert!(CliArgs::parse(["--metrics-file"]).is_err());
+        assert!(CliArgs::parse(["--stdin", "-p", "COM3", "--metrics-file", "m"]).is_err());
+        assert!(CliArgs::parse(["--benchmark", "--metrics-file", "m"]).is_err());
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(CliArgs::parse(["--bogus"]).is_err());
+        assert!(CliArgs::parse(["--benchmark", "--bench-rate"]).is_err());
+        assert!(CliArgs::parse(["--benchmark", "--bench-rate", "0"]).is_err());
+        assert!(CliArgs::parse(["--benchmark", "--bench-secs", "x"]).is_err());
+        assert!(CliArgs::parse(["--bench-secs", "5"]).is_err());
+    }
+
+    #[test]
+    fn test_port_index_parsing() {
+        let args = CliArgs::parse(["--stdin", "--port-index", "0"]).unwrap();
+        assert_eq!(args.port_index, Some(0));
+        assert_eq!(args.port, None);
+
+        assert!(CliArgs::parse(["--port-index", "1"]).is_err());
+        assert!(CliArgs::parse(["--stdin", "--port-index"]).is_err());
+        assert!(CliArgs::parse(["--stdin", "--port-index", "-1"]).is_err());
+        assert!(CliArgs::parse(["--stdin", "--port-index", "x"]).is_err());
+    }
+
+    #[test]
+    fn test_resolve_port_index() {
+        let ports = vec!["/dev/ttyS0".to_string(), "/dev/ttyUSB0".to_string()];
+        assert_eq!(resolve_port_index(0, &ports).unwrap(), "/dev/ttyS0");
+        assert_eq!(resolve_port_index(1, &ports).unwrap(), "/dev/ttyUSB0");
+
+        let err = resolve_port_index(2, &ports).unwrap_err();
+        assert!(err.contains("out of range"), "{err}");
+        assert!(err.contains("1: /dev/ttyUSB0"), "{err}");
+
+        let err = resolve_port_index(0, &[]).unwrap_err();
+        assert!(err.contains("no serial ports found"), "{err}");
+    }
+
+    fn port(name: &str, usb_serial: Option<&str>) -> PortInfo {
+        PortInfo {
+            name: name.to_string(),
+            usb_serial: usb_serial.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_resolve_usb_serial() {
+        let ports = vec![port("COM1", None), port("COM4", Some("A50285BI"))];
+        assert_eq!(resolve_usb_serial("A50285BI", &ports).unwrap(), "COM4");
+
+        let err = resolve_usb_serial("FT123", &ports).unwrap_err();
+        assert!(err.contains("no such adapter (COM4: A50285BI)"), "{err}");
+        let err = resolve_usb_serial("FT123", &ports[..1]).unwrap_err();
+        assert!(err.contains("no USB serial ports found"), "{err}");
+    }
+
+    #[test]
+    fn test_resolve_port_precedence() {
+        let ports = || vec![port("COM1", Some("S1")), port("COM4", Some("S4"))];
+
+        let args = CliArgs::parse(["--stdin", "--port-index", "1"]).unwrap();
+        assert_eq!(args.resolve_port(ports).unwrap().as_deref(), Some("COM4"));
+
+        // --usb-serial wins over --port-index
+        let args = CliArgs::parse(["--stdin", "--port-index", "1", "--usb-serial", "S1"]).unwrap();
+        assert_eq!(args.usb_serial.as_deref(), Some("S1"));
+        assert_eq!(args.resolve_port(ports).unwrap().as_deref(), Some("COM1"));
+
+        // --port wins and the port list is never queried
+        let args = CliArgs::parse([
+            "--stdin",
+            "--port-index",
+            "9",
+            "--usb-serial",
+            "S9",
+            "-p",
+            "COM3",
+        ])
+        .unwrap();
+        let resolved = args.resolve_port(|| unreachable!("port list not needed"));
+        assert_eq!(resolved.unwrap().as_deref(), Some("COM3"));
+
+        assert_eq!(CliArgs::default().resolve_port(ports), Ok(None));
+        assert!(CliArgs::parse(["--usb-serial", "S1"]).is_err());
+        assert!(CliArgs::parse(["--headless", "-o", "x", "--usb-serial"]).is_err());
+    }
+
+    #[test]
+    fn test_connect_flags() {
+        assert_eq!(CliArgs::parse(["--connect"]).unwrap().connect, Some(true));
+        assert_eq!(
+            CliArgs::parse(["--no-connect"]).unwrap().connect,
+            Some(false)
+        );
+        assert!(CliArgs::parse(["--connect", "--no-connect"]).is_err());
+        assert!(CliArgs::parse(["--headless", "-o", "x", "-p", "COM3", "--connect"]).is_err());
+    }
+
+    #[test]
+    fn test_startup_connect() {
+        let ports = vec!["/dev/ttyUSB0".to_string(), "/dev/ttyUSB1".to_string()];
+        let plain = CliArgs::default();
+        assert_eq!(
+            plain.startup_connect(true, "/dev/ttyUSB1", &ports),
+            StartupConnect::Connect
+        );
+        assert_eq!(
+            plain.startup_connect(true, "/dev/ttyACM0", &ports),
+            StartupConnect::PortMissing
+        );
+        assert_eq!(
+            plain.startup_connect(true, "", &ports),
+            StartupConnect::PortMissing
+        );
+        assert_eq!(
+            plain.startup_connect(false, "/dev/ttyUSB1", &ports),
+            StartupConnect::Skip
+        );
+
+        let connect = CliArgs::parse(["--connect"]).unwrap();
+        assert_eq!(
+            connect.startup_connect(false, "/dev/ttyUSB0", &ports),
+            StartupConnect::Connect
+        );
+        assert_eq!(
+            connect.startup_connect(false, "/dev/ttyUSB0", &[]),
+            StartupConnect::PortMissing
+        );
+        let no_connect = CliArgs::parse(["--no-connect"]).unwrap();
+        assert_eq!(
+            no_connect.startup_connect(true, "/dev/ttyUSB0", &ports),
+            StartupConnect::Skip
+        );
+    }
+}
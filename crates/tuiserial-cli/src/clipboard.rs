@@ -0,0 +1,97 @@
+//! System clipboard access.
+//!
+//! Reads and writes the clipboard through the platform's command-line tools rather than
+//! a windowing library, so the binary keeps working on headless machines
+//! and over SSH (where the tools are simply missing and the read fails).
+
+use std::io::{self, Write};
+use std::process::{Command, Stdio};
+
+/// Commands tried in order until one succeeds
+#[cfg(target_os = "macos")]
+const READERS: &[&[&str]] = &[&["pbpaste"]];
+
+#[cfg(windows)]
+const READERS: &[&[&str]] = &[&["powershell", "-NoProfile", "-Command", "Get-Clipboard"]];
+
+#[cfg(not(any(target_os = "macos", windows)))]
+const READERS: &[&[&str]] = &[
+    &["wl-paste", "--no-newline"],
+    &["xclip", "-selection", "clipboard", "-o"],
+    &["xsel", "--clipboard", "--output"],
+];
+
+/// Commands tried in order until one accepts the text on stdin
+#[cfg(target_os = "macos")]
+const WRITERS: &[&[&str]] = &[&["pbcopy"]];
+
+#[cfg(windows)]
+const WRITERS: &[&[&str]] = &[&["clip"]];
+
+#[cfg(not(any(target_os = "macos", windows)))]
+const WRITERS: &[&[&str]] = &[
+    &["wl-copy"],
+    &["xclip", "-selection", "clipboard", "-i"],
+    &["xsel", "--clipboard", "--input"],
+];
+
+/// Read the clipboard as text. Fails if no clipboard tool is available or
+/// the content is not valid UTF-8.
+pub fn read_clipboard() -> io::Result<String> {
+    let mut last_err = io::Error::new(io::ErrorKind::NotFound, "no clipboard tool found");
+    for argv in READERS {
+        let output = Command::new(argv[0])
+            .args(&argv[1..])
+            .stdin(Stdio::null())
+            .stderr(Stdio::null())
+            .output();
+        match output {
+            Ok(out) if out.status.success() => {
+                return String::from_utf8(out.stdout)
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e));
+            }
+            Ok(out) => {
+                last_err = io::Error::other(format!("{} exited with {}", argv[0], out.status));
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+            Err(e) => last_err = e,
+        }
+    }
+    Err(last_err)
+}
+
+/// Put `text` on the clipboard. Fails if no clipboard tool is available.
+pub fn write_clipboard(text: &str) -> io::Result<()> {
+    let mut last_err = io::Error::new(io::ErrorKind::NotFound, "no clipboard tool found");
+    for argv in WRITERS {
+        let child = Command::new(argv[0])
+            .args(&argv[1..])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn();
+        let mut child = match child {
+            Ok(child) => child,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => continue,
+            Err(e) => {
+                last_err = e;
+                continue;
+            }
+        };
+        if let Some(mut stdin) = child.stdin.take()
+            && let Err(e) = stdin.write_all(text.as_bytes())
+        {
+            last_err = e;
+            let _ = child.wait();
+            continue;
+        }
+        match child.wait() {
+            Ok(status) if status.success() => return Ok(()),
+            Ok(status) => {
+                last_err = io::Error::other(format!("{} exited with {}", argv[0], status));
+            }
+            Err(e) => last_err = e,
+        }
+    }
+    Err(last_err)
+}
@@ -0,0 +1,448 @@
+//! Log export formatting.
+//!
+//! Turns `LogEntry`s into one line of text each, in a format chosen by the
+//! user. Shared by everything that writes the log outside the UI.
+
+use std::fs::{File, OpenOptions};
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use flate2::Compression;
+use flate2::write::GzEncoder;
+use tuiserial_core::chrono::Local;
+use tuiserial_core::{AppState, DisplayMode, ExportFilter, ExportFormat, LogDirection, LogEntry};
+use tuiserial_serial::{bytes_to_hex, bytes_to_string};
+
+/// Timestamp layout used in every export format
+const TIMESTAMP_FORMAT: &str = "%Y-%m-%d %H:%M:%S%.3f";
+
+/// Format one entry as a single line (without the trailing newline)
+pub fn format_entry(format: ExportFormat, entry: &LogEntry) -> String {
+    let timestamp = entry.timestamp.format(TIMESTAMP_FORMAT);
+    let direction = direction_label(entry.direction);
+    let data = match (format, entry.direction) {
+        (_, LogDirection::Event(_)) => String::from_utf8_lossy(&entry.data).into_owned(),
+        (ExportFormat::Text, _) => bytes_to_string(&entry.data),
+        (ExportFormat::Hex | ExportFormat::Csv, _) => bytes_to_hex(&entry.data),
+    };
+
+    match format {
+        ExportFormat::Csv => format!(
+            "{timestamp},{direction},{},{},{}",
+            entry.seq,
+            entry.data.len(),
+            csv_field(&data)
+        ),
+        ExportFormat::Text | ExportFormat::Hex => {
+            format!("{timestamp} {direction:<2} {data}")
+        }
+    }
+}
+
+/// The entries in the log's drag selection as clipboard text, one line
+/// each in the format matching the log's display mode (hex or text).
+/// `None` when no data entry is selected.
+pub fn selection_text(app: &AppState) -> Option<String> {
+    let format = match app.display_mode {
+        DisplayMode::Hex => ExportFormat::Hex,
+        DisplayMode::Text => ExportFormat::Text,
+    };
+    let entries = app.selected_entries();
+    if entries.is_empty() {
+        return None;
+    }
+    let mut text = String::new();
+    for entry in entries {
+        text.push_str(&format_entry(format, entry));
+        text.push('\n');
+    }
+    Some(text)
+}
+
+/// Write the entries of `app`'s log selected by `filter` to `out`,
+/// preceded by the format header. Returns the number of entries written.
+pub fn export_log<W: Write>(
+    out: W,
+    app: &AppState,
+    format: ExportFormat,
+    filter: &ExportFilter,
+) -> io::Result<usize> {
+    let mut out = BufWriter::new(out);
+    if let Some(header) = format.header() {
+        writeln!(out, "{header}")?;
+    }
+    let entries = app.export_entries(filter);
+    for entry in &entries {
+        writeln!(out, "{}", format_entry(format, entry))?;
+    }
+    out.flush()?;
+    Ok(entries.len())
+}
+
+/// [`export_log`] through a gzip encoder, finishing the stream at the end
+pub fn export_log_gzip<W: Write>(
+    out: W,
+    app: &AppState,
+    format: ExportFormat,
+    filter: &ExportFilter,
+) -> io::Result<usize> {
+    let mut encoder = GzEncoder::new(out, Compression::default());
+    let count = export_log(&mut encoder, app, format, filter)?;
+    encoder.finish()?;
+    Ok(count)
+}
+
+/// Export into a new timestamped file in `dir`, e.g.
+/// `export-20240501-123045.hex` (`.hex.gz` with `gzip`). Returns the path
+/// and entry count, or `None` without creating a file if nothing matches
+/// `filter`.
+pub fn export_to_dir(
+    dir: &Path,
+    app: &AppState,
+    format: ExportFormat,
+    filter: &ExportFilter,
+    gzip: bool,
+) -> io::Result<Option<(PathBuf, usize)>> {
+    export_to_dir_named(dir, "export", app, format, filter, gzip)
+}
+
+/// Like [`export_to_dir`] with `prefix` instead of `export` at the start
+/// of the file name, e.g. `checkpoint-2-20240501-123045.hex`.
+pub fn export_to_dir_named(
+    dir: &Path,
+    prefix: &str,
+    app: &AppState,
+    format: ExportFormat,
+    filter: &ExportFilter,
+    gzip: bool,
+) -> io::Result<Option<(PathBuf, usize)>> {
+    if app.export_entries(filter).is_empty() {
+        return Ok(None);
+    }
+    std::fs::create_dir_all(dir)?;
+    let stem = format!("{prefix}-{}", Local::now().format("%Y%m%d-%H%M%S"));
+    let extension = format!("{}{}", format.extension(), if gzip { ".gz" } else { "" });
+    let (path, file) = create_unique(dir, &stem, &extension)?;
+    let count = if gzip {
+        export_log_gzip(file, app, format, filter)?
+    } else {
+        export_log(file, app, format, filter)?
+    };
+    Ok(Some((path, count)))
+}
+
+/// Create `dir/stem.extension`, or `stem-2`, `stem-3`, … if that name is
+/// taken, e.g. by another export within the same second
+fn create_unique(dir: &Path, stem: &str, extension: &str) -> io::Result<(PathBuf, File)> {
+    for n in 1u32.. {
+        let name = if n == 1 {
+            format!("{stem}.{extension}")
+        } else {
+            format!("{stem}-{n}.{extension}")
+        };
+        let path = dir.join(name);
+        match OpenOptions::new().write(true).create_new(true).open(&path) {
+            Ok(file) => return Ok((path, file)),
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists => continue,
+            Err(e) => return Err(e),
+        }
+    }
+    unreachable!("ran out of export file names")
+}
+
+fn direction_label(direction: LogDirection) -> &'static str {
+    match direction {
+        LogDirection::Rx => "RX",
+        LogDirection::Tx => "TX",
+        LogDirection::Event(_) => "--",
+    }
+}
+
+/// Quote a CSV field if it contains a separator, quote or newline
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tuiserial_core::LogEventKind;
+    use tuiserial_core::chrono::{Local, TimeZone};
+
+    fn entry(direction: LogDirection, data: &[u8]) -> LogEntry {
+        LogEntry {
+            timestamp: Local.with_ymd_and_hms(2024, 5, 1, 12, 30, 45).unwrap(),
+            direction,
+            data: data.to_vec(),
+            seq: 7,
+            tx_mode: None,
+        }
+    }
+
+    #[test]
+    fn test_text_and_hex_lines() {
+        let rx = entry(LogDirection::Rx, b"OK\r\n");
+        assert_eq!(
+            format_entry(ExportFormat::Text, &rx),
+            "2024-05-01 12:30:45.000 RX OK\\x0D\\x0A"
+        );
+        assert_eq!(
+            format_entry(ExportFormat::Hex, &rx),
+            "2024-05-01 12:30:45.000 RX 4F 4B 0D 0A"
+        );
+    }
+
+    #[test]
+    fn test_csv_quotes_event_text() {
+        let ev = entry(
+            LogDirection::Event(LogEventKind::Marker),
+            b"Marker \"a\", b",
+        );
+        assert_eq!(
+            format_entry(ExportFormat::Csv, &ev),
+            "2024-05-01 12:30:45.000,--,7,13,\"Marker \"\"a\"\", b\""
+        );
+        let tx = entry(LogDirection::Tx, &[1, 2]);
+        assert_eq!(
+            format_entry(ExportFormat::Csv, &tx),
+            "2024-05-01 12:30:45.000,TX,7,2,01 02"
+        );
+    }
+
+    fn sample_app() -> AppState {
+        let mut app = AppState::default();
+        app.message_log.push_rx(b"ok".to_vec());
+        app.message_log.push_tx(vec![0x01, 0x02]);
+        app.message_log.push_rx(vec![0xFF]);
+        app.message_log
+            .push_event(LogEventKind::Marker, "mark".to_string());
+        app
+    }
+
+    fn export_lines(app: &AppState, filter: ExportFilter) -> Vec<String> {
+        let mut out = Vec::new();
+        let count = export_log(&mut out, app, ExportFormat::Hex, &filter).unwrap();
+        let lines: Vec<String> = String::from_utf8(out)
+            .unwrap()
+            .lines()
+            .map(|l| {
+                l.split_once(' ')
+                    .unwrap()
+                    .1
+                    .split_once(' ')
+                    .unwrap()
+                    .1
+                    .to_string()
+            })
+            .collect();
+        assert_eq!(count, lines.len());
+        lines
+    }
+
+    #[test]
+    fn test_selection_text_follows_display_mode() {
+        let mut app = sample_app();
+        assert_eq!(selection_text(&app), None);
+
+        // "ok", TX 01 02 and RX FF; the marker after them is not reached
+        app.begin_log_selection(0);
+        app.extend_log_selection(2);
+        let text = selection_text(&app).unwrap();
+        let data: Vec<&str> = text.lines().map(|l| &l[24..]).collect();
+        assert_eq!(data, ["RX 6F 6B", "TX 01 02", "RX FF"]);
+
+        app.display_mode = DisplayMode::Text;
+        let text = selection_text(&app).unwrap();
+        assert!(text.starts_with(&format_entry(
+            ExportFormat::Text,
+            &app.message_log.entries[0]
+        )));
+        assert!(text.contains("RX ok\n"), "{text}");
+    }
+
+    #[test]
+    fn test_export_filter_direction() {
+        let app = sample_app();
+        assert_eq!(
+            export_lines(&app, ExportFilter::default()),
+            ["RX 6F 6B", "TX 01 02", "RX FF", "-- mark"]
+        );
+        let rx = ExportFilter {
+            direction: Some(LogDirection::Rx),
+            only_matching: false,
+            ..Default::default()
+        };
+        assert_eq!(export_lines(&app, rx), ["RX 6F 6B", "RX FF"]);
+        let tx = ExportFilter {
+            direction: Some(LogDirection::Tx),
+            only_matching: false,
+            ..Default::default()
+        };
+        assert_eq!(export_lines(&app, tx), ["TX 01 02"]);
+    }
+
+    #[test]
+    fn test_export_only_matching() {
+        let mut app = sample_app();
+        app.toggle_only_binary();
+        let visible = ExportFilter {
+            direction: None,
+            only_matching: true,
+            ..Default::default()
+        };
+        assert_eq!(export_lines(&app, visible), ["TX 01 02", "RX FF"]);
+        let visible_rx = ExportFilter {
+            direction: Some(LogDirection::Rx),
+            ..visible
+        };
+        assert_eq!(export_lines(&app, visible_rx), ["RX FF"]);
+        // Without only_matching the view filter is ignored
+        assert_eq!(export_lines(&app, ExportFilter::default()).len(), 4);
+    }
+
+    #[test]
+    fn test_export_to_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        let app = sample_app();
+        let (path, count) = export_to_dir(
+            dir.path(),
+            &app,
+            ExportFormat::Csv,
+            &ExportFilter::default(),
+            false,
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(count, 4);
+        assert_eq!(path.extension().unwrap(), "csv");
+        let text = std::fs::read_to_string(path).unwrap();
+        assert!(text.starts_with("timestamp,direction,seq,length,data\n"));
+
+        let none = ExportFilter {
+            direction: Some(LogDirection::Tx),
+            only_matching: true,
+            ..Default::default()
+        };
+        let empty = AppState::default();
+        assert!(
+            export_to_dir(dir.path(), &empty, ExportFormat::Hex, &none, false)
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_exports_in_same_second_do_not_collide() {
+        let dir = tempfile::tempdir().unwrap();
+        let app = sample_app();
+        let filter = ExportFilter::default();
+        let paths: Vec<PathBuf> = (0..3)
+            .map(|_| {
+                export_to_dir(dir.path(), &app, ExportFormat::Hex, &filter, false)
+                    .unwrap()
+                    .unwrap()
+                    .0
+            })
+            .collect();
+        assert_ne!(paths[0], paths[1]);
+        assert_ne!(paths[1], paths[2]);
+        assert_ne!(paths[0], paths[2]);
+        for path in &paths {
+            assert_eq!(std::fs::read_to_string(path).unwrap().lines().count(), 4);
+        }
+    }
+
+    #[test]
+    fn test_export_since_checkpoint_named() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut app = sample_app();
+        let checkpoint = app.set_checkpoint();
+        app.append_rx(b"hi".to_vec());
+        let (path, count) = export_to_dir_named(
+            dir.path(),
+            "checkpoint-1",
+            &app,
+            ExportFormat::Hex,
+            &checkpoint.filter(),
+            false,
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(count, 1);
+        let name = path.file_name().unwrap().to_string_lossy();
+        assert!(name.starts_with("checkpoint-1-") && name.ends_with(".hex"));
+        let text = std::fs::read_to_string(path).unwrap();
+        assert_eq!(text.lines().count(), 1);
+        assert!(text.ends_with("RX 68 69\n"), "{text}");
+    }
+
+    #[test]
+    fn test_gzip_export_round_trip() {
+        use flate2::read::GzDecoder;
+        use std::io::Read;
+
+        let dir = tempfile::tempdir().unwrap();
+        let app = sample_app();
+        let (path, count) = export_to_dir(
+            dir.path(),
+            &app,
+            ExportFormat::Csv,
+            &ExportFilter::default(),
+            true,
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(count, 4);
+        assert!(path.to_string_lossy().ends_with(".csv.gz"), "{path:?}");
+
+        let mut text = String::new();
+        GzDecoder::new(File::open(&path).unwrap())
+            .read_to_string(&mut text)
+            .unwrap();
+        let mut lines = text.lines();
+        assert_eq!(lines.next(), ExportFormat::Csv.header());
+        // timestamp,direction,seq,length,data
+        let parsed: Vec<(String, u64, String)> = lines
+            .map(|line| {
+                let fields: Vec<&str> = line.splitn(5, ',').collect();
+                (
+                    fields[1].to_string(),
+                    fields[2].parse().unwrap(),
+                    fields[4].to_string(),
+                )
+            })
+            .collect();
+        let expected: Vec<(String, u64, String)> = app
+            .message_log
+            .entries
+            .iter()
+            .map(|entry| {
+                let data = match entry.direction {
+                    LogDirection::Event(_) => String::from_utf8_lossy(&entry.data).into_owned(),
+                    _ => bytes_to_hex(&entry.data),
+                };
+                (
+                    direction_label(entry.direction).to_string(),
+                    entry.seq,
+                    data,
+                )
+            })
+            .collect();
+        assert_eq!(parsed, expected);
+
+        // The uncompressed export of the same log is the decompressed text
+        let mut plain = Vec::new();
+        export_log(
+            &mut plain,
+            &app,
+            ExportFormat::Csv,
+            &ExportFilter::default(),
+        )
+        .unwrap();
+        assert_eq!(String::from_utf8(plain).unwrap(), text);
+    }
+}
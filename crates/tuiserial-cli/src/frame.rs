@@ -0,0 +1,144 @@
+//! Render pacing for the main loop.
+//!
+//! Drawing on every loop pass means a busy port (or a burst of mouse
+//! events) renders far more frames than a terminal can show. The limiter
+//! coalesces state changes into at most one frame per `min_interval`,
+//! while key presses render immediately so typing never lags.
+
+use std::time::{Duration, Instant};
+
+/// Shortest time between two frames (~30 FPS)
+pub const FRAME_INTERVAL: Duration = Duration::from_millis(33);
+
+/// Redraw at least this often even when nothing is known to have changed,
+/// for purely time-driven UI
+pub const IDLE_REDRAW: Duration = Duration::from_secs(1);
+
+/// Decides when the main loop should call `terminal.draw`.
+#[derive(Debug)]
+pub struct FrameLimiter {
+    min_interval: Duration,
+    max_idle: Duration,
+    last_render: Option<Instant>,
+    /// State changed since the last frame
+    dirty: bool,
+    /// Render on the next pass regardless of `min_interval`
+    forced: bool,
+}
+
+impl FrameLimiter {
+    pub fn new(min_interval: Duration, max_idle: Duration) -> Self {
+        Self {
+            min_interval,
+            max_idle,
+            last_render: None,
+            dirty: false,
+            forced: false,
+        }
+    }
+
+    /// Something visible changed; render once `min_interval` has passed
+    pub fn mark_dirty(&mut self) {
+        self.dirty = true;
+    }
+
+    /// Render on the next pass (user input)
+    pub fn force(&mut self) {
+        self.forced = true;
+    }
+
+    /// Whether a frame is due at `now`
+    pub fn should_render(&self, now: Instant) -> bool {
+        let Some(last) = self.last_render else {
+            return true;
+        };
+        let elapsed = now.saturating_duration_since(last);
+        self.forced || elapsed >= self.max_idle || (self.dirty && elapsed >= self.min_interval)
+    }
+
+    /// Record that a frame was drawn at `now`
+    pub fn rendered(&mut self, now: Instant) {
+        self.last_render = Some(now);
+        self.dirty = false;
+        self.forced = false;
+    }
+
+    /// How long the loop may wait for events before the next frame is due
+    pub fn time_until_due(&self, now: Instant) -> Duration {
+        let Some(last) = self.last_render else {
+            return Duration::ZERO;
+        };
+        if self.forced {
+            return Duration::ZERO;
+        }
+        let due = if self.dirty {
+            self.min_interval
+        } else {
+            self.max_idle
+        };
+        due.saturating_sub(now.saturating_duration_since(last))
+    }
+}
+
+impl Default for FrameLimiter {
+    fn default() -> Self {
+        Self::new(FRAME_INTERVAL, IDLE_REDRAW)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    #[test]
+    fn test_first_frame_renders() {
+        let limiter = FrameLimiter::default();
+        let now = Instant::now();
+        assert!(limiter.should_render(now));
+        assert_eq!(limiter.time_until_due(now), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_dirty_changes_coalesce_until_interval() {
+        let mut limiter = FrameLimiter::new(ms(33), ms(1000));
+        let start = Instant::now();
+        limiter.rendered(start);
+
+        // A flood of changes within one interval produces no extra frames
+        for i in 1..33 {
+            limiter.mark_dirty();
+            assert!(!limiter.should_render(start + ms(i)));
+        }
+        assert_eq!(limiter.time_until_due(start + ms(20)), ms(13));
+        assert!(limiter.should_render(start + ms(33)));
+
+        limiter.rendered(start + ms(33));
+        assert!(!limiter.should_render(start + ms(80)));
+    }
+
+    #[test]
+    fn test_forced_renders_immediately() {
+        let mut limiter = FrameLimiter::new(ms(33), ms(1000));
+        let start = Instant::now();
+        limiter.rendered(start);
+        limiter.force();
+        assert!(limiter.should_render(start + ms(1)));
+        assert_eq!(limiter.time_until_due(start + ms(1)), Duration::ZERO);
+        limiter.rendered(start + ms(1));
+        assert!(!limiter.should_render(start + ms(2)));
+    }
+
+    #[test]
+    fn test_idle_redraw() {
+        let mut limiter = FrameLimiter::new(ms(33), ms(1000));
+        let start = Instant::now();
+        limiter.rendered(start);
+        assert!(!limiter.should_render(start + ms(999)));
+        assert_eq!(limiter.time_until_due(start + ms(400)), ms(600));
+        assert!(limiter.should_render(start + ms(1000)));
+    }
+}
@@ -1,14 +1,18 @@
 //! Global keyboard shortcut handler — handles keys when no modal is open and no text input is focused.
 
-use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use crossterm::event::{KeyCode, KeyEvent};
 use rust_i18n::t;
-use tuiserial_core::{AppState, DisplayMode, FocusedField};
+use tuiserial_core::{AppState, DisplayMode, FocusedField, ShortcutAction};
 use tuiserial_serial::list_ports;
 
-use crate::handler::SerialHandler;
+use crate::clipboard::write_clipboard;
+use crate::export::selection_text;
+use crate::handler::{ConnectPurpose, SerialHandler};
+use crate::menu_handler::{checkpoint_and_notify, export_since_checkpoint_and_notify};
 use crate::plugin_adapter::PluginProxy;
 
 /// Handle global keyboard shortcuts (outside TX input, menu, or modals).
+/// Bound shortcuts come first; the remaining keys navigate fields and the log.
 /// Returns `true` if the application should exit.
 pub fn handle_global_key(
     key: KeyEvent,
@@ -16,12 +20,76 @@ pub fn handle_global_key(
     handler: &mut SerialHandler,
     plugin_proxy: &mut PluginProxy,
 ) -> bool {
+    if let Some(action) = app.key_bindings.action(&key) {
+        if let Some(exit) = run_shortcut(action, app, handler, plugin_proxy) {
+            return exit;
+        }
+    }
+
     match key.code {
-        KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => true,
-        KeyCode::Char('q') if key.modifiers.contains(KeyModifiers::CONTROL) => true,
-        KeyCode::Char('q') | KeyCode::Esc => true,
+        KeyCode::Up | KeyCode::Char('k') => {
+            handle_field_up(app);
+            false
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            handle_field_down(app);
+            false
+        }
+
+        KeyCode::Right | KeyCode::Char('l') => {
+            if app.focused_field == FocusedField::BaudRate && !app.next_baud_rate() {
+                app.add_warning(t!("notify.config_locked_warning").to_string());
+            }
+            false
+        }
+        KeyCode::Left | KeyCode::Char('h') => {
+            if app.focused_field == FocusedField::BaudRate && !app.prev_baud_rate() {
+                app.add_warning(t!("notify.config_locked_warning").to_string());
+            }
+            false
+        }
+
+        KeyCode::PageUp => {
+            app.auto_scroll = false;
+            app.scroll_offset = app.scroll_offset.saturating_sub(10);
+            false
+        }
+        KeyCode::PageDown => {
+            app.scroll_offset = app.scroll_offset.saturating_add(10);
+            false
+        }
+        KeyCode::Home => {
+            app.auto_scroll = false;
+            app.scroll_offset = 0;
+            false
+        }
+        KeyCode::End => {
+            app.clear_log_selection();
+            app.auto_scroll = true;
+            let lines = app.message_log.entries.len() as u16;
+            app.scroll_offset = lines.saturating_sub(1);
+            false
+        }
+
+        _ => false,
+    }
+}
 
-        KeyCode::Char('p') | KeyCode::Char('P') => {
+/// Run a bound shortcut. Returns `Some(exit)` when it was handled here, or
+/// `None` for shortcuts handled elsewhere or not available right now.
+fn run_shortcut(
+    action: ShortcutAction,
+    app: &mut AppState,
+    handler: &mut SerialHandler,
+    plugin_proxy: &mut PluginProxy,
+) -> Option<bool> {
+    match action {
+        // Ctrl+C always force-quits; the other quit keys ask first while
+        // connected or recording (`confirm_quit_when_active`)
+        ShortcutAction::ForceQuit => return Some(true),
+        ShortcutAction::Quit => return Some(app.request_quit()),
+
+        ShortcutAction::OpenPlugins => {
             if app.show_plugin_modal {
                 app.show_plugin_modal = false;
             } else {
@@ -30,104 +98,114 @@ pub fn handle_global_key(
                 app.show_plugin_modal = true;
                 app.plugin_modal_scroll = 0;
             }
-            false
         }
 
-        KeyCode::Char('o') => {
-            if handler.is_connected() {
+        ShortcutAction::ToggleConnection => {
+            if handler.is_connecting() {
+                handler.disconnect();
+                app.cancel_connecting();
+                app.add_info(t!("notify.connect_cancelled").to_string());
+            } else if handler.is_connected() {
                 for err in plugin_proxy.on_disconnect() {
                     app.record_error(err);
                 }
                 handler.disconnect();
-                app.is_connected = false;
-                app.unlock_config();
-                log::info!("Disconnected from {}", app.config.port);
+                app.mark_disconnected(None);
+                log::info!("Disconnected from {}", app.active_config().port);
                 app.add_info(t!("notify.disconnected_unlocked").to_string());
             } else {
-                if app.config.port.is_empty() {
+                if app.active_config().port.is_empty() {
                     app.add_error(t!("notify.please_select_port").to_string());
                 } else {
-                    match handler.connect(app) {
-                        Ok(_) => {
-                            app.is_connected = true;
-                            app.lock_config();
-                            for err in plugin_proxy.on_connect(&app.config) {
-                                app.record_error(err);
-                            }
-                            log::info!(
-                                "Connected to {} @ {} baud",
-                                app.config.port,
-                                app.config.baud_rate
-                            );
-                            app.add_success(
-                                t!("notify.connected_locked", port = &app.config.port).to_string(),
-                            );
-                        }
-                        Err(e) => {
-                            app.is_connected = false;
-                            app.unlock_config();
-                            let kind: tuiserial_core::SerialErrorKind = e.into();
-                            app.record_error(tuiserial_core::AppError::Serial {
-                                kind,
-                                ctx: tuiserial_core::ErrorContext::new(
-                                    "serial",
-                                    "connect",
-                                    tuiserial_core::RecoveryStrategy::Retry,
-                                ),
-                            });
-                        }
-                    }
+                    connect_and_notify(app, handler, ConnectPurpose::Manual);
                 }
             }
-            false
         }
 
-        KeyCode::Tab => {
-            app.focus_next_field();
-            false
-        }
-        KeyCode::BackTab => {
-            app.focus_prev_field();
-            false
-        }
-
-        KeyCode::Up | KeyCode::Char('k') => {
-            handle_field_up(app);
-            false
-        }
-        KeyCode::Down | KeyCode::Char('j') => {
-            handle_field_down(app);
-            false
-        }
-
-        KeyCode::Right | KeyCode::Char('l') => {
-            if app.focused_field == FocusedField::BaudRate && !app.next_baud_rate() {
+        ShortcutAction::ApplyStaged if app.settings.stage_config_changes => {
+            if app.apply_staged_config() {
+                app.add_success(t!("notify.staged_applied").to_string());
+            } else if !app.can_modify_config() {
                 app.add_warning(t!("notify.config_locked_warning").to_string());
+            } else {
+                app.add_info(t!("notify.staged_none").to_string());
             }
-            false
         }
-        KeyCode::Left | KeyCode::Char('h') => {
-            if app.focused_field == FocusedField::BaudRate && !app.prev_baud_rate() {
+        ShortcutAction::RevertStaged if app.settings.stage_config_changes => {
+            if app.revert_staged_config() {
+                app.add_info(t!("notify.staged_reverted").to_string());
+            } else if !app.can_modify_config() {
                 app.add_warning(t!("notify.config_locked_warning").to_string());
+            } else {
+                app.add_info(t!("notify.staged_none").to_string());
             }
-            false
         }
 
-        KeyCode::Char('x') => {
+        ShortcutAction::NextField => app.focus_next_field(),
+        ShortcutAction::PrevField => app.focus_prev_field(),
+
+        ShortcutAction::DualViewMode => match app.toggle_secondary_display_mode() {
+            Some(mode) => {
+                let mode_str = match mode {
+                    DisplayMode::Hex => "HEX",
+                    DisplayMode::Text => "TEXT",
+                };
+                app.add_info(format!(
+                    "{}: {}",
+                    t!("notify.toggle_secondary_display_mode"),
+                    mode_str
+                ));
+            }
+            None => app.add_info(t!("notify.dual_view_off").to_string()),
+        },
+        ShortcutAction::ToggleDisplayMode => {
             app.toggle_display_mode();
-            let mode_str = match app.display_mode {
-                DisplayMode::Hex => "HEX",
-                DisplayMode::Text => "TEXT",
-            };
+            let mode_str = display_mode_name(app);
             app.add_info(format!(
                 "{}: {}",
                 t!("notify.toggle_display_mode"),
                 mode_str
             ));
-            false
         }
-
-        KeyCode::Char('a') => {
+        ShortcutAction::ByteInspector => {
+            if app.focused_entry().is_some() {
+                app.byte_inspector = Some(tuiserial_core::ByteInspector::default());
+            } else {
+                app.add_warning(t!("notify.inspector_no_entry").to_string());
+            }
+        }
+        ShortcutAction::BaudPrev => reconnect_at_baud_step(app, handler, plugin_proxy, false),
+        ShortcutAction::BaudNext => reconnect_at_baud_step(app, handler, plugin_proxy, true),
+        ShortcutAction::BytePad => {
+            app.byte_pad = match app.byte_pad {
+                Some(_) => None,
+                None => Some(tuiserial_core::BytePad::default()),
+            };
+        }
+        ShortcutAction::Checkpoint => checkpoint_and_notify(app),
+        ShortcutAction::ExportSinceCheckpoint => export_since_checkpoint_and_notify(app),
+        ShortcutAction::RxTiming => {
+            if app.show_rx_timing || app.settings.rx_chunk_timing {
+                app.show_rx_timing = !app.show_rx_timing;
+            } else {
+                app.add_warning(t!("notify.rx_timing_off").to_string());
+            }
+        }
+        ShortcutAction::NextBinaryEntry => {
+            if !app.jump_to_binary_entry(true) {
+                app.add_info(t!("notify.no_binary_entry").to_string());
+            }
+        }
+        ShortcutAction::BinaryOnly => {
+            app.toggle_only_binary();
+            let status = if app.only_binary {
+                t!("notify.enabled")
+            } else {
+                t!("notify.disabled")
+            };
+            app.add_info(format!("{}: {}", t!("notify.only_binary"), status));
+        }
+        ShortcutAction::ToggleAutoScroll => {
             app.auto_scroll = !app.auto_scroll;
             let status = if app.auto_scroll {
                 t!("notify.enabled")
@@ -135,68 +213,63 @@ pub fn handle_global_key(
                 t!("notify.disabled")
             };
             app.add_info(format!("{}: {}", t!("notify.auto_scroll"), status));
-            false
         }
-
-        KeyCode::Char('c') => {
-            app.message_log.clear();
+        ShortcutAction::CopySelection => copy_selection_and_notify(app, write_clipboard),
+        ShortcutAction::ClearLog => {
+            app.clear_log();
             app.add_info(t!("notify.log_cleared").to_string());
-            false
         }
-
-        KeyCode::Char('f') => {
+        ShortcutAction::FlowControl => {
             if app.toggle_flow_control() {
                 let flow_str = format!("{:?}", app.config.flow_control);
                 app.add_info(format!("{}: {}", t!("notify.flow_control"), flow_str));
             } else {
                 app.add_warning(t!("notify.config_locked_warning").to_string());
             }
-            false
         }
-
-        KeyCode::Char('n') => {
+        ShortcutAction::AppendMode => {
             app.next_append_mode();
             app.add_info(format!(
                 "{}: {}",
                 t!("notify.append_mode"),
                 app.tx_append_mode.name()
             ));
-            false
         }
-
-        KeyCode::Char('r') => {
+        ShortcutAction::RefreshPorts => {
             app.ports = list_ports();
             if !app.ports.is_empty() && app.port_list_state.selected().is_none() {
                 app.port_list_state.select(Some(0));
                 app.config.port = app.ports[0].clone();
             }
             app.add_success(t!("notify.ports_refreshed").to_string());
-            false
         }
 
-        KeyCode::PageUp => {
-            app.auto_scroll = false;
-            app.scroll_offset = app.scroll_offset.saturating_sub(10);
-            false
-        }
-        KeyCode::PageDown => {
-            app.scroll_offset = app.scroll_offset.saturating_add(10);
-            false
-        }
-        KeyCode::Home => {
-            app.auto_scroll = false;
-            app.scroll_offset = 0;
-            false
-        }
-        KeyCode::End => {
-            app.auto_scroll = true;
-            let lines = app.message_log.entries.len() as u16;
-            app.scroll_offset = lines.saturating_sub(1);
-            false
-        }
+        // Session and layout keys belong to the tabbed UI; the panic, repeat,
+        // clipboard, menu and help keys are picked up before this handler
+        _ => return None,
+    }
+    Some(false)
+}
 
-        _ => false,
+/// Emergency stop: drop the serial connection immediately and disarm all
+/// scheduled sends, whatever the current UI state. Safe to call when
+/// already disconnected.
+pub fn panic_disconnect(
+    app: &mut AppState,
+    handler: &mut SerialHandler,
+    plugin_proxy: &mut PluginProxy,
+) {
+    if handler.is_connected() {
+        for err in plugin_proxy.on_disconnect() {
+            app.record_error(err);
+        }
     }
+    // Also abandons a connect still in progress
+    handler.disconnect();
+    app.stop_all_activity();
+    app.mark_disconnected(Some(&t!("event.reason_panic")));
+    log::warn!("Panic disconnect from {}", app.active_config().port);
+    app.add_warning(t!("notify.panic_disconnect").to_string());
 }
 
 fn handle_field_up(app: &mut AppState) {
@@ -278,16 +351,126 @@ fn handle_field_up(app: &mut AppState) {
         }
         FocusedField::LogArea => {
             app.toggle_display_mode();
-            let mode_str = match app.display_mode {
-                DisplayMode::Hex => "HEX",
-                DisplayMode::Text => "TEXT",
-            };
+            let mode_str = display_mode_name(app);
             app.add_info(format!("{}: {}", t!("notify.display_mode"), mode_str));
         }
         _ => {}
     }
 }
 
+/// Start opening the active port in the background; `finish_connect`
+/// reports the outcome once it is known.
+pub fn connect_and_notify(
+    app: &mut AppState,
+    handler: &mut SerialHandler,
+    purpose: ConnectPurpose,
+) {
+    app.begin_connecting();
+    handler.start_connect(app, purpose);
+}
+
+/// Pick up the outcome of a connect started by `connect_and_notify`:
+/// notify on success, record the error on failure. Called once per event
+/// loop; does nothing while the port is still opening.
+pub fn finish_connect(
+    app: &mut AppState,
+    handler: &mut SerialHandler,
+    plugin_proxy: &mut PluginProxy,
+) {
+    let Some((purpose, result)) = handler.poll_connect() else {
+        return;
+    };
+    match result {
+        Ok(()) => {
+            match purpose {
+                ConnectPurpose::BaudStep(_) => app.mark_reconnected(),
+                ConnectPurpose::Manual | ConnectPurpose::Startup => app.mark_connected(),
+            }
+            let config = app.active_config().clone();
+            for err in plugin_proxy.on_connect(&config) {
+                app.record_error(err);
+            }
+            log::info!("Connected to {} @ {} baud", config.port, config.baud_rate);
+            let message = match purpose {
+                ConnectPurpose::BaudStep(baud) => t!("notify.reconnected_at_baud", baud = baud),
+                ConnectPurpose::Manual | ConnectPurpose::Startup => {
+                    t!("notify.connected_locked", port = &config.port)
+                }
+            };
+            app.add_success(message.to_string());
+        }
+        Err(e) => {
+            let reason = e.to_string();
+            let kind: tuiserial_core::SerialErrorKind = e.into();
+            app.record_error(tuiserial_core::AppError::Serial {
+                kind,
+                ctx: tuiserial_core::ErrorContext::new(
+                    "serial",
+                    "connect",
+                    tuiserial_core::RecoveryStrategy::Retry,
+                ),
+            });
+            match purpose {
+                ConnectPurpose::BaudStep(_) => app.mark_connection_lost(&reason),
+                ConnectPurpose::Manual => app.mark_connect_failed(reason),
+                ConnectPurpose::Startup => {
+                    app.mark_connect_failed(reason);
+                    let port = app.active_config().port.clone();
+                    app.add_info(t!("notify.auto_connect_failed", port = port).to_string());
+                }
+            }
+        }
+    }
+}
+
+/// `[`/`]` — reconnect at the previous/next baud rate, keeping the log and
+/// marking the change in it
+fn reconnect_at_baud_step(
+    app: &mut AppState,
+    handler: &mut SerialHandler,
+    plugin_proxy: &mut PluginProxy,
+    forward: bool,
+) {
+    if !handler.is_connected() {
+        app.add_warning(t!("notify.baud_step_not_connected").to_string());
+        return;
+    }
+    // Step first so the port stays open if there is no rate to step to
+    let Some((old, new)) = app.step_baud_for_reconnect(forward) else {
+        return;
+    };
+    for err in plugin_proxy.on_disconnect() {
+        app.record_error(err);
+    }
+    handler.disconnect();
+    log::info!("Reconnecting at {new} baud (was {old})");
+    connect_and_notify(app, handler, ConnectPurpose::BaudStep(new));
+}
+
+/// `y` — copy the entries in the log's drag selection to the clipboard
+fn copy_selection_and_notify(app: &mut AppState, write: impl FnOnce(&str) -> std::io::Result<()>) {
+    let Some(text) = selection_text(app) else {
+        app.add_warning(t!("notify.no_selection").to_string());
+        return;
+    };
+    let count = app.selected_entries().len();
+    match write(&text) {
+        Ok(()) => app.add_success(t!("notify.selection_copied", count = count).to_string()),
+        Err(e) => app.add_error(format!("{}: {}", t!("notify.clipboard_unavailable"), e)),
+    }
+}
+
+/// Name of the main log view's display mode for notifications
+fn display_mode_name(app: &AppState) -> String {
+    match app.active_custom_renderer() {
+        Some(custom) => custom.name.clone(),
+        None => match app.display_mode {
+            DisplayMode::Hex => "HEX".to_string(),
+            DisplayMode::Text => "TEXT".to_string(),
+        },
+    }
+}
+
 fn handle_field_down(app: &mut AppState) {
     match app.focused_field {
         FocusedField::Port => {
@@ -367,12 +550,27 @@ fn handle_field_down(app: &mut AppState) {
         }
         FocusedField::LogArea => {
             app.toggle_display_mode();
-            let mode_str = match app.display_mode {
-                DisplayMode::Hex => "HEX",
-                DisplayMode::Text => "TEXT",
-            };
+            let mode_str = display_mode_name(app);
             app.add_info(format!("{}: {}", t!("notify.display_mode"), mode_str));
         }
         _ => {}
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handler::mock::{MockDevice, connected_handler};
+
+    #[test]
+    fn test_baud_step_without_rate_keeps_port_open() {
+        let device = MockDevice::default();
+        let mut handler = connected_handler(&device);
+        let mut app = AppState::default();
+        let mut proxy = PluginProxy::init(&mut app);
+        app.baud_rate_state.select(None);
+
+        reconnect_at_baud_step(&mut app, &mut handler, &mut proxy, true);
+        assert!(handler.is_connected());
+    }
+}
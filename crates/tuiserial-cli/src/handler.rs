@@ -1,16 +1,47 @@
 //! Serial port connection handler
 
-use tuiserial_core::{AppError, AppState, ErrorContext, RecoveryStrategy, SerialErrorKind};
-use tuiserial_serial::{SerialError, serialport::SerialPort};
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+
+use tuiserial_core::{
+    AppError, AppState, ErrorContext, FramingMode, RecoveryStrategy, SerialErrorKind,
+};
+use tuiserial_serial::{SLIP_MAX_FRAME, SerialError, SlipDecoder, serialport::SerialPort};
 
 /// Maximum number of consecutive read errors before auto-disconnect.
 const MAX_CONSECUTIVE_READ_ERRORS: u32 = 5;
 
+/// Outcome of opening a port on the connect thread
+type OpenResult = Result<Box<dyn SerialPort>, SerialError>;
+
+/// Why a connect was started; decides how its outcome is reported
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectPurpose {
+    /// The user asked to connect
+    Manual,
+    /// Auto-connect to the saved port at startup
+    Startup,
+    /// Reconnect after stepping to this baud rate, keeping the log
+    BaudStep(u32),
+}
+
+/// A port being opened on a background thread
+struct PendingConnect {
+    purpose: ConnectPurpose,
+    read_buffer_size: usize,
+    result: Receiver<OpenResult>,
+}
+
 /// Handler for managing serial port connections
 pub struct SerialHandler {
     port: Option<Box<dyn SerialPort>>,
+    /// Connect started by `start_connect` and not yet picked up
+    pending: Option<PendingConnect>,
     /// Counts consecutive read errors; reset on success.
     pub consecutive_read_errors: u32,
+    /// Bytes requested per read, taken from settings on connect.
+    read_buffer_size: usize,
+    /// Partial SLIP frame carried between reads
+    slip: SlipDecoder,
 }
 
 impl SerialHandler {
@@ -18,21 +49,70 @@ impl SerialHandler {
     pub fn new() -> Self {
         Self {
             port: None,
+            pending: None,
             consecutive_read_errors: 0,
+            read_buffer_size: tuiserial_core::DEFAULT_RX_READ_BUFFER,
+            slip: SlipDecoder::default(),
         }
     }
 
-    /// Connect to the serial port using the current configuration
-    pub fn connect(&mut self, app: &AppState) -> Result<(), SerialError> {
-        let port = tuiserial_serial::open_port(&app.config)?;
-        self.port = Some(port);
-        self.consecutive_read_errors = 0;
-        Ok(())
+    /// Start opening the port of the current configuration on a background
+    /// thread, so a slow open never blocks the UI. Any open port or earlier
+    /// attempt is dropped first; `poll_connect` picks up the outcome.
+    pub fn start_connect(&mut self, app: &AppState, purpose: ConnectPurpose) {
+        let config = app.active_config().clone();
+        self.start_connect_with(app, purpose, move || tuiserial_serial::open_port(&config));
+    }
+
+    /// `start_connect` with `open` in place of opening the configured port
+    pub(crate) fn start_connect_with(
+        &mut self,
+        app: &AppState,
+        purpose: ConnectPurpose,
+        open: impl FnOnce() -> OpenResult + Send + 'static,
+    ) {
+        self.disconnect();
+        let (tx, rx) = mpsc::channel();
+        std::thread::spawn(move || {
+            // A cancelled attempt drops the receiver, closing the port here
+            let _ = tx.send(open());
+        });
+        self.pending = Some(PendingConnect {
+            purpose,
+            read_buffer_size: app.settings.rx_read_buffer_size(),
+            result: rx,
+        });
+    }
+
+    /// Whether a connect is still in progress
+    pub fn is_connecting(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    /// Outcome of the connect started by `start_connect`, once it is known
+    pub fn poll_connect(&mut self) -> Option<(ConnectPurpose, Result<(), SerialError>)> {
+        let result = match self.pending.as_ref()?.result.try_recv() {
+            Ok(result) => result,
+            Err(TryRecvError::Empty) => return None,
+            Err(TryRecvError::Disconnected) => Err(SerialError::NotConnected),
+        };
+        let pending = self.pending.take()?;
+        let result = result.map(|port| {
+            self.port = Some(port);
+            self.consecutive_read_errors = 0;
+            self.read_buffer_size = pending.read_buffer_size;
+            self.slip.reset();
+        });
+        Some((pending.purpose, result))
     }
 
-    /// Disconnect from the serial port
+    /// Disconnect from the serial port, closing it, and abandon any connect
+    /// in progress. Safe to call when already disconnected.
     pub fn disconnect(&mut self) {
-        self.port = None;
+        self.pending = None;
+        if let Some(port) = self.port.take() {
+            log::debug!("Closing serial port {:?}", port.name());
+        }
         self.consecutive_read_errors = 0;
     }
 
@@ -52,11 +132,37 @@ impl SerialHandler {
     /// Read data from the serial port
     pub fn read(&mut self) -> Result<Vec<u8>, SerialError> {
         match &mut self.port {
-            Some(port) => tuiserial_serial::read_data(port.as_mut()),
+            Some(port) => {
+                tuiserial_serial::read_data_with_capacity(port.as_mut(), self.read_buffer_size)
+            }
             None => Err(SerialError::NotConnected),
         }
     }
 
+    /// Split read data into log entries: the data itself when raw, or the
+    /// SLIP frames it completes (possibly none). Oversized SLIP frames are
+    /// dropped with a warning.
+    pub fn deframe(&mut self, framing: FramingMode, data: Vec<u8>) -> Vec<Vec<u8>> {
+        match framing {
+            FramingMode::Raw => vec![data],
+            FramingMode::Slip => {
+                let frames = self.slip.feed(&data);
+                let dropped = self.slip.take_dropped();
+                if dropped > 0 {
+                    log::warn!(
+                        "Dropped {dropped} SLIP frame(s) longer than {SLIP_MAX_FRAME} bytes"
+                    );
+                }
+                frames
+            }
+        }
+    }
+
+    /// Drop any partial SLIP frame, e.g. when the framing mode changes
+    pub fn reset_framing(&mut self) {
+        self.slip.reset();
+    }
+
     /// Convert a `SerialError` into an `AppError` and track consecutive
     /// errors for auto-disconnect logic.
     ///
@@ -101,3 +207,308 @@ impl Default for SerialHandler {
         Self::new()
     }
 }
+
+/// Close the port however the handler goes away (early return, `?`, panic
+/// unwind), so the device is never left locked for the next open.
+impl Drop for SerialHandler {
+    fn drop(&mut self) {
+        self.disconnect();
+    }
+}
+
+/// In-memory serial port for tests that need a connected handler
+#[cfg(test)]
+pub(crate) mod mock {
+    use super::*;
+    use std::io::{self, Read, Write};
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::time::Duration;
+    use tuiserial_serial::serialport::{
+        self, ClearBuffer, DataBits, FlowControl, Parity, StopBits,
+    };
+
+    /// A device that, like a real tty opened exclusively, can only be open
+    /// once at a time.
+    #[derive(Clone, Default)]
+    pub(crate) struct MockDevice {
+        in_use: Arc<AtomicBool>,
+    }
+
+    impl MockDevice {
+        pub(crate) fn open(&self) -> serialport::Result<Box<dyn SerialPort>> {
+            if self.in_use.swap(true, Ordering::SeqCst) {
+                return Err(serialport::Error::new(
+                    serialport::ErrorKind::NoDevice,
+                    "device busy",
+                ));
+            }
+            Ok(Box::new(MockPort {
+                device: self.clone(),
+            }))
+        }
+
+        pub(crate) fn is_open(&self) -> bool {
+            self.in_use.load(Ordering::SeqCst)
+        }
+    }
+
+    struct MockPort {
+        device: MockDevice,
+    }
+
+    impl Drop for MockPort {
+        fn drop(&mut self) {
+            self.device.in_use.store(false, Ordering::SeqCst);
+        }
+    }
+
+    impl Read for MockPort {
+        fn read(&mut self, _buf: &mut [u8]) -> io::Result<usize> {
+            Err(io::ErrorKind::TimedOut.into())
+        }
+    }
+
+    impl Write for MockPort {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            Ok(buf.len())
+        }
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl SerialPort for MockPort {
+        fn name(&self) -> Option<String> {
+            Some("mock".to_string())
+        }
+        fn baud_rate(&self) -> serialport::Result<u32> {
+            Ok(9600)
+        }
+        fn data_bits(&self) -> serialport::Result<DataBits> {
+            Ok(DataBits::Eight)
+        }
+        fn flow_control(&self) -> serialport::Result<FlowControl> {
+            Ok(FlowControl::None)
+        }
+        fn parity(&self) -> serialport::Result<Parity> {
+            Ok(Parity::None)
+        }
+        fn stop_bits(&self) -> serialport::Result<StopBits> {
+            Ok(StopBits::One)
+        }
+        fn timeout(&self) -> Duration {
+            Duration::ZERO
+        }
+        fn set_baud_rate(&mut self, _: u32) -> serialport::Result<()> {
+            Ok(())
+        }
+        fn set_data_bits(&mut self, _: DataBits) -> serialport::Result<()> {
+            Ok(())
+        }
+        fn set_flow_control(&mut self, _: FlowControl) -> serialport::Result<()> {
+            Ok(())
+        }
+        fn set_parity(&mut self, _: Parity) -> serialport::Result<()> {
+            Ok(())
+        }
+        fn set_stop_bits(&mut self, _: StopBits) -> serialport::Result<()> {
+            Ok(())
+        }
+        fn set_timeout(&mut self, _: Duration) -> serialport::Result<()> {
+            Ok(())
+        }
+        fn write_request_to_send(&mut self, _: bool) -> serialport::Result<()> {
+            Ok(())
+        }
+        fn write_data_terminal_ready(&mut self, _: bool) -> serialport::Result<()> {
+            Ok(())
+        }
+        fn read_clear_to_send(&mut self) -> serialport::Result<bool> {
+            Ok(false)
+        }
+        fn read_data_set_ready(&mut self) -> serialport::Result<bool> {
+            Ok(false)
+        }
+        fn read_ring_indicator(&mut self) -> serialport::Result<bool> {
+            Ok(false)
+        }
+        fn read_carrier_detect(&mut self) -> serialport::Result<bool> {
+            Ok(false)
+        }
+        fn bytes_to_read(&self) -> serialport::Result<u32> {
+            Ok(0)
+        }
+        fn bytes_to_write(&self) -> serialport::Result<u32> {
+            Ok(0)
+        }
+        fn clear(&self, _: ClearBuffer) -> serialport::Result<()> {
+            Ok(())
+        }
+        fn try_clone(&self) -> serialport::Result<Box<dyn SerialPort>> {
+            Err(serialport::Error::new(
+                serialport::ErrorKind::Unknown,
+                "not supported",
+            ))
+        }
+        fn set_break(&self) -> serialport::Result<()> {
+            Ok(())
+        }
+        fn clear_break(&self) -> serialport::Result<()> {
+            Ok(())
+        }
+    }
+
+    /// A handler connected to `device`
+    pub(crate) fn connected_handler(device: &MockDevice) -> SerialHandler {
+        let mut handler = SerialHandler::new();
+        handler.port = Some(device.open().unwrap());
+        handler
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::mock::{MockDevice, connected_handler};
+    use super::*;
+
+    #[test]
+    fn test_drop_releases_port() {
+        let device = MockDevice::default();
+        let handler = connected_handler(&device);
+        assert!(handler.is_connected());
+        assert!(device.open().is_err(), "port should be held");
+
+        drop(handler);
+        assert!(!device.is_open());
+        assert!(device.open().is_ok(), "port should be reopenable");
+    }
+
+    #[test]
+    fn test_drop_on_early_return_releases_port() {
+        fn work(device: &MockDevice) -> Result<(), SerialError> {
+            let mut handler = connected_handler(device);
+            handler.send(b"x")?;
+            Err(SerialError::NotConnected)
+        }
+
+        let device = MockDevice::default();
+        assert!(work(&device).is_err());
+        assert!(!device.is_open());
+    }
+
+    #[test]
+    fn test_double_close_is_safe() {
+        let device = MockDevice::default();
+        let mut handler = connected_handler(&device);
+        handler.disconnect();
+        assert!(!device.is_open());
+
+        // Reopened by someone else; a second disconnect and the drop must not
+        // touch it.
+        let other = device.open().unwrap();
+        handler.disconnect();
+        drop(handler);
+        assert!(device.is_open());
+        drop(other);
+        assert!(!device.is_open());
+    }
+
+    #[test]
+    fn test_deframe_slip_across_reads() {
+        let mut handler = SerialHandler::new();
+        assert_eq!(
+            handler.deframe(FramingMode::Raw, b"ab".to_vec()),
+            vec![b"ab".to_vec()]
+        );
+        assert!(
+            handler
+                .deframe(FramingMode::Slip, vec![0xC0, b'a'])
+                .is_empty()
+        );
+        assert_eq!(
+            handler.deframe(FramingMode::Slip, vec![b'b', 0xC0, 0xC0, b'c', 0xC0]),
+            vec![b"ab".to_vec(), b"c".to_vec()]
+        );
+
+        // A framing change drops the partial frame
+        handler.deframe(FramingMode::Slip, vec![b'x']);
+        handler.reset_framing();
+        assert_eq!(
+            handler.deframe(FramingMode::Slip, vec![b'y', 0xC0]),
+            vec![b"y".to_vec()]
+        );
+    }
+
+    /// Poll until the background connect finishes
+    fn wait_for_connect(handler: &mut SerialHandler) -> (ConnectPurpose, Result<(), SerialError>) {
+        for _ in 0..500 {
+            if let Some(outcome) = handler.poll_connect() {
+                return outcome;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(2));
+        }
+        panic!("connect never finished");
+    }
+
+    #[test]
+    fn test_background_connect_success_and_failure() {
+        let app = AppState::default();
+        let device = MockDevice::default();
+        let mut handler = SerialHandler::new();
+
+        let opener = device.clone();
+        handler.start_connect_with(&app, ConnectPurpose::Manual, move || Ok(opener.open()?));
+        assert!(handler.is_connecting() || handler.is_connected());
+        let (purpose, result) = wait_for_connect(&mut handler);
+        assert_eq!(purpose, ConnectPurpose::Manual);
+        assert!(result.is_ok());
+        assert!(handler.is_connected());
+        assert!(!handler.is_connecting());
+        assert!(handler.poll_connect().is_none());
+
+        // The device is held, so a second handler fails to open it
+        let mut other = SerialHandler::new();
+        let opener = device.clone();
+        other.start_connect_with(&app, ConnectPurpose::BaudStep(9600), move || {
+            Ok(opener.open()?)
+        });
+        let (purpose, result) = wait_for_connect(&mut other);
+        assert_eq!(purpose, ConnectPurpose::BaudStep(9600));
+        assert!(result.is_err());
+        assert!(!other.is_connected());
+    }
+
+    #[test]
+    fn test_cancelled_connect_releases_port() {
+        use std::sync::Arc;
+        use std::sync::atomic::{AtomicBool, Ordering};
+
+        let app = AppState::default();
+        let device = MockDevice::default();
+        let opened = Arc::new(AtomicBool::new(false));
+        let (release, wait) = mpsc::channel::<()>();
+        let mut handler = SerialHandler::new();
+        let (opener, flag) = (device.clone(), opened.clone());
+        handler.start_connect_with(&app, ConnectPurpose::Manual, move || {
+            wait.recv().ok();
+            let port = opener.open();
+            flag.store(true, Ordering::SeqCst);
+            Ok(port?)
+        });
+        assert!(handler.is_connecting());
+        assert!(handler.poll_connect().is_none());
+
+        handler.disconnect();
+        assert!(!handler.is_connecting());
+        release.send(()).unwrap();
+        // The late open is dropped by the connect thread
+        for _ in 0..500 {
+            if opened.load(Ordering::SeqCst) && !device.is_open() {
+                return;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(2));
+        }
+        panic!("port still held after cancelling");
+    }
+}
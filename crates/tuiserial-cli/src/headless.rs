@@ -0,0 +1,307 @@
+//! Headless capture (`--headless`).
+//!
+//! Opens the port, reads continuously and appends every read to a file in
+//! one of the export formats, with no terminal UI. Running totals go to
+//! stderr every few seconds; SIGINT (Ctrl+C) or SIGTERM stops the capture,
+//! flushes the file and closes the port.
+
+use std::fs::OpenOptions;
+use std::io::{self, BufWriter, Read, Write};
+use std::path::Path;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+use anyhow::{Context, Result};
+use tuiserial_core::{AppState, ExportFormat, LogDirection, LogEntry};
+use tuiserial_serial::open_port;
+
+use crate::cli_args::HeadlessOptions;
+use crate::export::format_entry;
+use crate::metrics::{MetricsFile, MetricsSnapshot};
+
+/// Interval between progress lines on stderr.
+const STATS_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Bytes requested per port read.
+const READ_CHUNK_SIZE: usize = 4096;
+
+/// Running totals for a capture.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct CaptureStats {
+    pub bytes: u64,
+    pub entries: u64,
+}
+
+/// Writes captured reads to `out` as formatted lines.
+pub struct Capture<W: Write> {
+    out: BufWriter<W>,
+    format: ExportFormat,
+    stats: CaptureStats,
+}
+
+impl<W: Write> Capture<W> {
+    /// Start a capture; writes the format header when `new_file` is set.
+    pub fn new(out: W, format: ExportFormat, new_file: bool) -> io::Result<Self> {
+        let mut out = BufWriter::new(out);
+        if new_file && let Some(header) = format.header() {
+            writeln!(out, "{header}")?;
+        }
+        Ok(Self {
+            out,
+            format,
+            stats: CaptureStats::default(),
+        })
+    }
+
+    /// Append one RX read.
+    pub fn record(&mut self, data: Vec<u8>) -> io::Result<()> {
+        self.stats.bytes += data.len() as u64;
+        self.stats.entries += 1;
+        let mut entry = LogEntry::new(LogDirection::Rx, data);
+        entry.seq = self.stats.entries;
+        writeln!(self.out, "{}", format_entry(self.format, &entry))
+    }
+
+    pub fn stats(&self) -> CaptureStats {
+        self.stats
+    }
+
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.out.flush()
+    }
+
+    /// Flush and hand back the underlying writer.
+    pub fn finish(self) -> io::Result<W> {
+        self.out.into_inner().map_err(|e| e.into_error())
+    }
+}
+
+/// Read `port` into `capture` until `stop` is set or the port fails.
+///
+/// Timeouts count as "no data"; the file is flushed whenever the port goes
+/// idle so a `tail -f` sees data promptly. `report` is called at most once
+/// per `stats_interval` with the running totals.
+pub fn capture_loop<R, W>(
+    port: &mut R,
+    capture: &mut Capture<W>,
+    stop: &AtomicBool,
+    stats_interval: Duration,
+    mut report: impl FnMut(CaptureStats),
+) -> io::Result<()>
+where
+    R: Read + ?Sized,
+    W: Write,
+{
+    let mut buf = vec![0u8; READ_CHUNK_SIZE];
+    let mut last_report = Instant::now();
+
+    while !stop.load(Ordering::Relaxed) {
+        match port.read(&mut buf) {
+            Ok(n) if n > 0 => capture.record(buf[..n].to_vec())?,
+            Ok(_) => capture.flush()?,
+            Err(e)
+                if matches!(
+                    e.kind(),
+                    io::ErrorKind::TimedOut | io::ErrorKind::Interrupted
+                ) =>
+            {
+                capture.flush()?
+            }
+            Err(e) => {
+                capture.flush()?;
+                return Err(e);
+            }
+        }
+
+        if last_report.elapsed() >= stats_interval {
+            report(capture.stats());
+            last_report = Instant::now();
+        }
+    }
+
+    capture.flush()
+}
+
+/// Run a headless capture of `port` until interrupted.
+/// With `metrics_path`, the running totals are also written there as
+/// metrics every [`STATS_INTERVAL`].
+pub fn run_headless(
+    port: &str,
+    baud: Option<u32>,
+    opts: &HeadlessOptions,
+    metrics_path: Option<&Path>,
+) -> Result<CaptureStats> {
+    let mut app = AppState::default();
+    app.load_config();
+    app.config.port = port.to_string();
+    if let Some(baud) = baud {
+        app.config.baud_rate = baud;
+    }
+
+    let stop = Arc::new(AtomicBool::new(false));
+    for signal in [signal_hook::consts::SIGINT, signal_hook::consts::SIGTERM] {
+        signal_hook::flag::register(signal, Arc::clone(&stop))
+            .context("cannot install signal handler")?;
+    }
+
+    let file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&opts.output)
+        .with_context(|| format!("cannot open {}", opts.output.display()))?;
+    let new_file = file.metadata().map(|m| m.len() == 0).unwrap_or(true);
+    let mut capture = Capture::new(file, opts.format, new_file)?;
+
+    let mut serial = open_port(&app.config).with_context(|| format!("cannot open {port}"))?;
+    log::info!(
+        "Headless capture: {} -> {} ({})",
+        app.config.format_display(),
+        opts.output.display(),
+        opts.format
+    );
+    eprintln!(
+        "tuiserial: capturing {port} to {} (Ctrl+C to stop)",
+        opts.output.display()
+    );
+
+    let mut metrics = metrics_path.map(MetricsFile::new);
+    let snapshot = |s: CaptureStats, uptime, connected| MetricsSnapshot {
+        port: port.to_string(),
+        connected,
+        rx_bytes: s.bytes,
+        rx_reads: s.entries,
+        uptime,
+        ..Default::default()
+    };
+    let mut write_metrics = |s: CaptureStats, connected: bool| {
+        if let Some(file) = &mut metrics
+            && let Err(e) = file.write(&snapshot(s, file.uptime(), connected))
+        {
+            log::warn!("Cannot write metrics to {}: {e}", file.path().display());
+        }
+    };
+
+    let result = capture_loop(&mut serial, &mut capture, &stop, STATS_INTERVAL, |s| {
+        eprintln!("tuiserial: {} bytes in {} reads", s.bytes, s.entries);
+        write_metrics(s, true);
+    });
+    let stats = capture.stats();
+    capture.finish()?;
+    drop(serial);
+    write_metrics(stats, false);
+
+    log::info!(
+        "Headless capture finished: {} bytes in {} reads",
+        stats.bytes,
+        stats.entries
+    );
+    result.with_context(|| format!("read from {port} failed"))?;
+    Ok(stats)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::VecDeque;
+
+    /// Port replaying scripted reads, then timing out; sets `stop` once
+    /// the script is exhausted.
+    struct MockPort<'a> {
+        script: VecDeque<io::Result<Vec<u8>>>,
+        stop: &'a AtomicBool,
+    }
+
+    impl Read for MockPort<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            match self.script.pop_front() {
+                Some(Ok(data)) => {
+                    buf[..data.len()].copy_from_slice(&data);
+                    Ok(data.len())
+                }
+                Some(Err(e)) => Err(e),
+                None => {
+                    self.stop.store(true, Ordering::Relaxed);
+                    Err(io::ErrorKind::TimedOut.into())
+                }
+            }
+        }
+    }
+
+    fn run(
+        script: Vec<io::Result<Vec<u8>>>,
+        format: ExportFormat,
+    ) -> (io::Result<()>, String, CaptureStats) {
+        let stop = AtomicBool::new(false);
+        let mut port = MockPort {
+            script: script.into(),
+            stop: &stop,
+        };
+        let mut capture = Capture::new(Vec::new(), format, true).unwrap();
+        let result = capture_loop(&mut port, &mut capture, &stop, Duration::ZERO, |_| {});
+        let stats = capture.stats();
+        let out = String::from_utf8(capture.finish().unwrap()).unwrap();
+        (result, out, stats)
+    }
+
+    #[test]
+    fn test_capture_formats_each_read() {
+        let (result, out, stats) = run(
+            vec![
+                Ok(b"AB".to_vec()),
+                Err(io::ErrorKind::TimedOut.into()),
+                Ok(vec![0x0D]),
+            ],
+            ExportFormat::Hex,
+        );
+        result.unwrap();
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].ends_with(" RX 41 42"));
+        assert!(lines[1].ends_with(" RX 0D"));
+        assert_eq!(
+            stats,
+            CaptureStats {
+                bytes: 3,
+                entries: 2
+            }
+        );
+    }
+
+    #[test]
+    fn test_csv_header_only_for_new_file() {
+        let (_, out, _) = run(vec![Ok(b"x".to_vec())], ExportFormat::Csv);
+        assert!(out.starts_with("timestamp,direction,seq,length,data\n"));
+        assert!(out.lines().nth(1).unwrap().ends_with(",RX,1,1,78"));
+
+        let capture = Capture::new(Vec::new(), ExportFormat::Csv, false).unwrap();
+        assert!(capture.finish().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_port_error_keeps_captured_data() {
+        let (result, out, _) = run(
+            vec![
+                Ok(b"partial".to_vec()),
+                Err(io::ErrorKind::BrokenPipe.into()),
+            ],
+            ExportFormat::Text,
+        );
+        assert_eq!(result.unwrap_err().kind(), io::ErrorKind::BrokenPipe);
+        assert!(out.trim_end().ends_with("RX partial"));
+    }
+
+    #[test]
+    fn test_flushed_when_port_goes_idle() {
+        let stop = AtomicBool::new(false);
+        let mut port = MockPort {
+            script: vec![Ok(b"hi".to_vec())].into(),
+            stop: &stop,
+        };
+        let mut capture = Capture::new(Vec::new(), ExportFormat::Text, true).unwrap();
+        capture_loop(&mut port, &mut capture, &stop, STATS_INTERVAL, |_| {}).unwrap();
+        // Nothing left in the BufWriter: the inner Vec already holds the line
+        assert!(capture.out.buffer().is_empty());
+        assert!(capture.out.get_ref().ends_with(b"RX hi\n"));
+    }
+}
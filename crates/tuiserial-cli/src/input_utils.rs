@@ -1,25 +1,20 @@
-//! Input utility functions — text width, hex input rebuilding, and paste handling.
+//! Input utility functions — hex input rebuilding and paste handling.
 
-use tuiserial_core::{AppState, FocusedField, TxMode};
-
-// Re-export display_width from core to avoid duplication
-pub use tuiserial_core::display_width;
+use tuiserial_core::{AppState, FocusedField, TextInput, TxMode};
 
 /// Rebuild hex-mode input with auto-spacing: extract hex digits, group in pairs with spaces.
 /// Preserves cursor position relative to hex content.
 pub fn rebuild_hex_input(app: &mut AppState) {
     let hex_only: String = app
         .tx_input
+        .as_str()
         .chars()
         .filter(|c| c.is_ascii_hexdigit())
         .collect();
 
-    let hex_before_cursor: usize = app.tx_input[..app
+    let hex_before_cursor: usize = app
         .tx_input
-        .char_indices()
-        .nth(app.tx_cursor)
-        .map(|(i, _)| i)
-        .unwrap_or(app.tx_input.len())]
+        .before_cursor()
         .chars()
         .filter(|c| c.is_ascii_hexdigit())
         .count();
@@ -44,8 +39,7 @@ pub fn rebuild_hex_input(app: &mut AppState) {
         }
     }
 
-    app.tx_input = new_input;
-    app.tx_cursor = new_cursor;
+    app.tx_input = TextInput::from(new_input).with_cursor(new_cursor);
 }
 
 /// Handle paste events: in hex mode filter non-hex chars and rebuild spacing; in ASCII insert as-is.
@@ -57,24 +51,10 @@ pub fn handle_paste_event(data: &str, app: &mut AppState) {
     if app.tx_mode == TxMode::Hex {
         let hex_only: String = data.chars().filter(|c| c.is_ascii_hexdigit()).collect();
         if !hex_only.is_empty() {
-            let byte_idx = app
-                .tx_input
-                .char_indices()
-                .nth(app.tx_cursor)
-                .map(|(i, _)| i)
-                .unwrap_or(app.tx_input.len());
-            app.tx_input.insert_str(byte_idx, &hex_only);
-            app.tx_cursor += hex_only.chars().count();
+            app.tx_input.insert_str(&hex_only);
             rebuild_hex_input(app);
         }
     } else {
-        let byte_idx = app
-            .tx_input
-            .char_indices()
-            .nth(app.tx_cursor)
-            .map(|(i, _)| i)
-            .unwrap_or(app.tx_input.len());
-        app.tx_input.insert_str(byte_idx, data);
-        app.tx_cursor += data.chars().count();
+        app.tx_input.insert_str(data);
     }
 }
@@ -1,13 +1,16 @@
 //! Keyboard event handler — routes key events to the appropriate sub-handler.
 
 use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
-use rust_i18n::t;
-use tuiserial_core::{AppState, FocusedField, MenuState, PluginModalMode, menu_def::MENU_BAR};
+use tuiserial_core::{
+    AppState, FocusedField, MenuState, PluginModalMode, ShortcutAction, menu_def::MENU_BAR,
+};
 
+use crate::clipboard::read_clipboard;
 use crate::handler::SerialHandler;
-use crate::menu_handler::handle_menu_action;
+use crate::menu_handler::{handle_menu_action, load_config_and_notify, save_config_and_notify};
 use crate::plugin_adapter::PluginProxy;
 use crate::plugin_adapter::filtered_registry_count;
+use crate::tx_handler::send_clipboard;
 
 /// Main keyboard event handler. Routes to sub-handlers based on application state.
 /// Returns `true` if the application should exit.
@@ -21,6 +24,22 @@ pub fn handle_key_event(
         return false;
     }
 
+    // Panic disconnect is checked before any mode-specific routing so it
+    // works regardless of focus, open menus, or overlays.
+    let action = app.key_bindings.action(&key);
+    if action == Some(ShortcutAction::PanicDisconnect) {
+        crate::global_handler::panic_disconnect(app, handler, plugin_proxy);
+        return false;
+    }
+
+    // Quit prompt: Ctrl+C still force-quits
+    if app.quit_prompt {
+        if action == Some(ShortcutAction::ForceQuit) {
+            return true;
+        }
+        return crate::menu_handler::handle_quit_prompt_key(key, app, handler);
+    }
+
     // Menu navigation takes priority over everything else
     if let Some(exit) = handle_menu_navigation(key, app, handler, plugin_proxy) {
         return exit;
@@ -33,22 +52,78 @@ pub fn handle_key_event(
 
     // Help overlay — consume all keys while showing
     if app.show_shortcuts_help {
-        match key.code {
-            KeyCode::Esc | KeyCode::F(1) | KeyCode::Char('q') | KeyCode::Char('?') => {
-                app.show_shortcuts_help = false;
-            }
-            _ => {}
+        if matches!(key.code, KeyCode::Esc | KeyCode::Char('q'))
+            || action == Some(ShortcutAction::ShowHelp)
+        {
+            app.show_shortcuts_help = false;
         }
         return false;
     }
 
+    // Repeat count/interval prompt
+    if app.repeat_prompt.is_some() {
+        return crate::tx_handler::handle_repeat_prompt_key(key, app);
+    }
+
+    // Export options dialog
+    if app.export_dialog.is_some() {
+        return crate::menu_handler::handle_export_dialog_key(key, app);
+    }
+
+    // Large send waiting for confirmation
+    if app.pending_send.is_some() {
+        return crate::tx_handler::handle_send_confirm_key(key, app, handler, plugin_proxy);
+    }
+
+    // Byte pad overlay: clicks send bytes, Esc closes it
+    if app.byte_pad.is_some() && key.code == KeyCode::Esc {
+        app.byte_pad = None;
+        return false;
+    }
+
+    // Byte inspector overlay; quit keys still reach the global handler
+    if app.byte_inspector.is_some()
+        && !matches!(
+            action,
+            Some(ShortcutAction::Quit | ShortcutAction::ForceQuit)
+        )
+    {
+        handle_inspector_key(key, app);
+        return false;
+    }
+
     // TX input mode
     if app.focused_field == FocusedField::TxInput {
         return crate::tx_handler::handle_tx_key_event(key, app, handler, plugin_proxy);
     }
 
     // Global shortcuts
-    return crate::global_handler::handle_global_key(key, app, handler, plugin_proxy);
+    crate::global_handler::handle_global_key(key, app, handler, plugin_proxy)
+}
+
+/// Handle keys while the byte inspector is open: ←/→ move the window,
+/// ↑/↓ change the focused entry, w switches between 2 and 4 bytes, i closes.
+fn handle_inspector_key(key: KeyEvent, app: &mut AppState) {
+    let data_len = app.focused_entry().map_or(0, |e| e.data.len());
+    let Some(inspector) = app.byte_inspector.as_mut() else {
+        return;
+    };
+
+    match key.code {
+        KeyCode::Char('i') => {
+            app.byte_inspector = None;
+        }
+        KeyCode::Left | KeyCode::Char('h') => inspector.move_left(),
+        KeyCode::Right | KeyCode::Char('l') => inspector.move_right(data_len),
+        KeyCode::Char('w') | KeyCode::Tab => inspector.toggle_width(data_len),
+        KeyCode::Up | KeyCode::Char('k') => {
+            app.move_entry_focus(false);
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            app.move_entry_focus(true);
+        }
+        _ => {}
+    }
 }
 
 /// Handle menu bar and dropdown navigation. Returns `Some(exit)` when a key is handled
@@ -61,25 +136,26 @@ fn handle_menu_navigation(
 ) -> Option<bool> {
     match app.menu_state {
         MenuState::None => {
-            if key.code == KeyCode::F(10) {
+            let action = app.key_bindings.action(&key);
+            if action == Some(ShortcutAction::OpenMenu) {
                 app.menu_state = MenuState::MenuBar(0);
                 app.focused_field = FocusedField::LogArea;
                 return Some(false);
             }
-            if key.code == KeyCode::F(1) || key.code == KeyCode::Char('?') {
+            if action == Some(ShortcutAction::ShowHelp) {
                 app.show_shortcuts_help = !app.show_shortcuts_help;
                 return Some(false);
             }
             if key.code == KeyCode::Char('s') && key.modifiers.contains(KeyModifiers::CONTROL) {
-                match app.save_config() {
-                    Ok(_) => app.add_success(t!("notify.config_saved").to_string()),
-                    Err(e) => app.add_error(format!("{}: {}", t!("notify.config_save_failed"), e)),
-                }
+                save_config_and_notify(app);
                 return Some(false);
             }
             if key.code == KeyCode::Char('o') && key.modifiers.contains(KeyModifiers::CONTROL) {
-                app.load_config();
-                app.add_success(t!("notify.config_loaded").to_string());
+                load_config_and_notify(app);
+                return Some(false);
+            }
+            if action == Some(ShortcutAction::SendClipboard) {
+                send_clipboard(app, handler, plugin_proxy, read_clipboard);
                 return Some(false);
             }
             None
@@ -228,3 +304,152 @@ fn handle_plugin_modal_key(
         },
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, Instant};
+    use tuiserial_core::RepeatSend;
+
+    fn ctrl_d() -> KeyEvent {
+        KeyEvent::new(KeyCode::Char('d'), KeyModifiers::CONTROL)
+    }
+
+    fn connected_app() -> AppState {
+        let mut app = AppState::default();
+        app.config.port = "/dev/ttyUSB0".into();
+        app.mark_connected();
+        app
+    }
+
+    #[test]
+    fn test_panic_disconnect_from_tx_input() {
+        let mut app = connected_app();
+        let mut handler = SerialHandler::new();
+        let mut proxy = PluginProxy::init(&mut app);
+        app.focused_field = FocusedField::TxInput;
+        app.tx_input = "AT".into();
+        app.start_repeat(RepeatSend::indefinite(
+            b"AT".to_vec(),
+            Duration::from_millis(100),
+            Instant::now(),
+        ));
+
+        let exit = handle_key_event(ctrl_d(), &mut app, &mut handler, &mut proxy);
+
+        assert!(!exit);
+        assert!(!app.is_connected());
+        assert!(!app.has_scheduled_activity());
+        assert!(app.can_modify_config());
+        assert!(!handler.is_connected());
+        // The key must not leak into the input box as a character.
+        assert_eq!(app.tx_input, "AT");
+    }
+
+    #[test]
+    fn test_panic_disconnect_with_menu_open() {
+        let mut app = connected_app();
+        let mut handler = SerialHandler::new();
+        let mut proxy = PluginProxy::init(&mut app);
+        app.menu_state = MenuState::Dropdown(1, 0);
+
+        handle_key_event(ctrl_d(), &mut app, &mut handler, &mut proxy);
+
+        assert!(!app.is_connected());
+        assert!(app.can_modify_config());
+    }
+
+    #[test]
+    fn test_plain_d_is_not_panic_disconnect() {
+        let mut app = connected_app();
+        let mut handler = SerialHandler::new();
+        let mut proxy = PluginProxy::init(&mut app);
+        app.focused_field = FocusedField::TxInput;
+
+        handle_key_event(
+            KeyEvent::new(KeyCode::Char('d'), KeyModifiers::NONE),
+            &mut app,
+            &mut handler,
+            &mut proxy,
+        );
+
+        assert!(app.is_connected());
+        assert_eq!(app.tx_input, "d");
+    }
+
+    #[test]
+    fn test_quit_keys_pass_through_byte_inspector() {
+        let mut app = AppState::default();
+        let mut handler = SerialHandler::new();
+        let mut proxy = PluginProxy::init(&mut app);
+        app.focused_field = FocusedField::LogArea;
+        app.byte_inspector = Some(tuiserial_core::ByteInspector::default());
+
+        // Inspector keys are consumed by the overlay
+        let key = KeyEvent::new(KeyCode::Char('w'), KeyModifiers::NONE);
+        assert!(!handle_key_event(key, &mut app, &mut handler, &mut proxy));
+        assert!(app.byte_inspector.is_some());
+
+        // Idle app: q quits right away instead of being swallowed
+        let key = KeyEvent::new(KeyCode::Char('q'), KeyModifiers::NONE);
+        assert!(handle_key_event(key, &mut app, &mut handler, &mut proxy));
+        let key = KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL);
+        assert!(handle_key_event(key, &mut app, &mut handler, &mut proxy));
+    }
+
+    #[test]
+    fn test_quit_prompt_keys() {
+        let mut app = connected_app();
+        let mut handler = SerialHandler::new();
+        let mut proxy = PluginProxy::init(&mut app);
+        app.focused_field = FocusedField::LogArea;
+        let q = KeyEvent::new(KeyCode::Char('q'), KeyModifiers::NONE);
+        let esc = KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE);
+
+        // Esc is not a quit key; in the prompt it cancels
+        assert!(!handle_key_event(esc, &mut app, &mut handler, &mut proxy));
+        assert!(!app.quit_prompt);
+        assert!(!handle_key_event(q, &mut app, &mut handler, &mut proxy));
+        assert!(app.quit_prompt);
+        assert!(!handle_key_event(esc, &mut app, &mut handler, &mut proxy));
+        assert!(!app.quit_prompt);
+
+        // A second press of any quit key exits
+        assert!(!handle_key_event(q, &mut app, &mut handler, &mut proxy));
+        let ctrl_q = KeyEvent::new(KeyCode::Char('q'), KeyModifiers::CONTROL);
+        assert!(handle_key_event(ctrl_q, &mut app, &mut handler, &mut proxy));
+    }
+
+    #[test]
+    fn test_rebinding_changes_dispatch_and_help() {
+        use ratatui::{Terminal, backend::TestBackend};
+        use tuiserial_core::KeyChord;
+
+        let mut app = AppState::default();
+        let mut handler = SerialHandler::new();
+        let mut proxy = PluginProxy::init(&mut app);
+        app.focused_field = FocusedField::LogArea;
+        app.key_bindings
+            .bind(ShortcutAction::ClearLog, KeyChord::ctrl(KeyCode::Char('k')));
+        app.append_rx(b"OK".to_vec());
+
+        // The old key no longer clears, the new one does
+        let old = KeyEvent::new(KeyCode::Char('c'), KeyModifiers::NONE);
+        handle_key_event(old, &mut app, &mut handler, &mut proxy);
+        assert_eq!(app.message_log.entries.len(), 1);
+        let new = KeyEvent::new(KeyCode::Char('k'), KeyModifiers::CONTROL);
+        handle_key_event(new, &mut app, &mut handler, &mut proxy);
+        assert!(app.message_log.entries.is_empty());
+
+        let mut terminal = Terminal::new(TestBackend::new(80, 60)).unwrap();
+        terminal
+            .draw(|f| tuiserial_ui::draw_shortcuts_help(f, &app.key_bindings))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        let rows: Vec<String> = (0..60)
+            .map(|y| (0..80).map(|x| buffer[(x, y)].symbol()).collect())
+            .collect();
+        let clear_row = rows.iter().find(|r| r.contains("Clear Log")).unwrap();
+        assert!(clear_row.contains("Ctrl+K"), "{clear_row}");
+    }
+}
@@ -1,37 +1,55 @@
 //! TuiSerial - Terminal User Interface for Serial Port Communication
 
 use std::io;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use anyhow::Result;
 use crossterm::{
     cursor::{Hide, MoveTo, Show},
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event},
+    event::{
+        self, DisableBracketedPaste, DisableMouseCapture, EnableBracketedPaste, EnableMouseCapture,
+        Event,
+    },
     execute,
     terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
 };
 use ratatui::{Terminal, backend::CrosstermBackend};
-use tuiserial_core::AppState;
+use rust_i18n::t;
 use tuiserial_core::file_log;
-use tuiserial_serial::list_ports;
+use tuiserial_core::{AppState, ConfigLoad, ConnectionState};
+use tuiserial_serial::{list_port_info, list_ports};
 use tuiserial_ui::draw;
 
 use rust_i18n::i18n;
 // Initialize i18n translations at compile time
 i18n!("../../locales", fallback = "en");
 
+mod benchmark;
+mod cli_args;
+mod clipboard;
+mod export;
+mod frame;
 mod global_handler;
 mod handler;
+mod headless;
 mod input_utils;
 mod key_handler;
 mod menu_handler;
+mod metrics;
 mod mouse_handler;
+mod pipe;
 mod plugin_adapter;
 mod tx_handler;
 
-use handler::SerialHandler;
+use cli_args::{CliArgs, StartupConnect};
+use frame::FrameLimiter;
+use handler::{ConnectPurpose, SerialHandler};
+use metrics::{MetricsFile, MetricsSnapshot};
 use plugin_adapter::PluginProxy;
 
+/// Event poll timeout when nothing is scheduled
+const IDLE_POLL: Duration = Duration::from_millis(100);
+
 fn setup_logger() -> Result<()> {
     let log_dir =
         file_log::log_dir().ok_or_else(|| anyhow::anyhow!("Cannot determine log directory"))?;
@@ -65,39 +83,122 @@ fn setup_logger() -> Result<()> {
 }
 
 fn main() -> Result<()> {
+    let args = match CliArgs::parse(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("tuiserial: {e}\n\n{}", cli_args::USAGE);
+            std::process::exit(2);
+        }
+    };
+    if args.help {
+        println!("{}", cli_args::USAGE);
+        return Ok(());
+    }
+    if args.version {
+        println!("tuiserial {}", env!("CARGO_PKG_VERSION"));
+        return Ok(());
+    }
+
     color_eyre::install().ok();
     setup_logger()?;
     log::info!("tuiserial v{} started", env!("CARGO_PKG_VERSION"));
 
+    let port = match args.resolve_port(list_port_info) {
+        Ok(port) => port,
+        Err(e) => {
+            eprintln!("tuiserial: {e}");
+            std::process::exit(2);
+        }
+    };
+
+    // Pipe mode never touches the terminal
+    if let (Some(opts), Some(port)) = (&args.stdin, &port) {
+        let stats = pipe::run_stdin_pipe(port, args.baud, opts)?;
+        eprintln!(
+            "tuiserial: sent {} bytes in {} writes to {port}",
+            stats.bytes, stats.writes
+        );
+        return Ok(());
+    }
+    if let (Some(opts), Some(port)) = (&args.headless, &port) {
+        let stats = headless::run_headless(port, args.baud, opts, args.metrics_file.as_deref())?;
+        eprintln!(
+            "tuiserial: captured {} bytes in {} reads to {}",
+            stats.bytes,
+            stats.entries,
+            opts.output.display()
+        );
+        return Ok(());
+    }
+
     enable_raw_mode()?;
     let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    execute!(
+        stdout,
+        EnterAlternateScreen,
+        EnableMouseCapture,
+        EnableBracketedPaste
+    )?;
     let backend = CrosstermBackend::new(stdout);
     let terminal = Terminal::new(backend)?;
 
-    let result = run_app(terminal);
+    let result = match &args.benchmark {
+        Some(opts) => benchmark::run_benchmark(terminal, opts).map(Some),
+        None => run_app(terminal, &args).map(|_| None),
+    };
 
     disable_raw_mode()?;
-    execute!(io::stdout(), LeaveAlternateScreen, DisableMouseCapture)?;
+    execute!(
+        io::stdout(),
+        LeaveAlternateScreen,
+        DisableMouseCapture,
+        DisableBracketedPaste
+    )?;
 
-    result
+    if let Some(report) = result? {
+        log::info!("Benchmark finished:\n{report}");
+        println!("{report}");
+    }
+    Ok(())
 }
 
-fn run_app(mut terminal: Terminal<CrosstermBackend<io::Stdout>>) -> Result<()> {
+fn run_app(mut terminal: Terminal<CrosstermBackend<io::Stdout>>, args: &CliArgs) -> Result<()> {
     let mut app = AppState::default();
     let mut handler = SerialHandler::new();
+    let mut metrics = args.metrics_file.as_deref().map(MetricsFile::new);
 
     // Load saved configuration
-    app.load_config();
+    let first_run = !AppState::config_file_exists();
+    let loaded = app.load_config();
+
+    // Introduce the shortcuts on the very first launch
+    if app.show_first_run_help(first_run)
+        && let Err(e) = app.save_config()
+    {
+        log::warn!("Failed to save config after first run: {e}");
+    }
 
     // Initialize locale from saved language preference
     rust_i18n::set_locale(app.language.code());
 
+    // A broken config file is left in place; tell the user defaults are in use
+    if let ConfigLoad::Corrupt { path, reason } = loaded {
+        log::warn!(
+            "Config {} unreadable, using defaults: {reason}",
+            path.display()
+        );
+        app.add_warning(t!("notify.config_reset", path = path.display().to_string()).to_string());
+    }
+
     // Initialize plugin manager (no-op when feature is disabled)
     let mut plugin_proxy = PluginProxy::init(&mut app);
 
     // Initialize available ports
     app.ports = list_ports();
+    // Decided before the saved port is replaced by a detected one below
+    let saved_port = app.config.port.clone();
+    let startup_connect =
+        args.startup_connect(app.settings.auto_connect_on_start, &saved_port, &app.ports);
     if !app.ports.is_empty() {
         if app.config.port.is_empty() {
             app.config.port = app.ports[0].clone();
@@ -109,14 +210,50 @@ fn run_app(mut terminal: Terminal<CrosstermBackend<io::Stdout>>) -> Result<()> {
             app.port_list_state.select(Some(0));
         }
     }
+    // The startup port choice is part of the applied config, not a pending edit
+    if app.settings.stage_config_changes {
+        app.set_config_staging(true);
+    }
+    // Defaults and the detected port are the baseline, not unsaved edits
+    app.mark_saved();
+
+    match startup_connect {
+        StartupConnect::Skip => {}
+        StartupConnect::Connect => {
+            global_handler::connect_and_notify(&mut app, &mut handler, ConnectPurpose::Startup);
+        }
+        StartupConnect::PortMissing => {
+            app.add_info(t!("notify.auto_connect_missing", port = saved_port).to_string());
+        }
+    }
+
+    let mut frames = FrameLimiter::default();
+    let mut last_activity = None;
 
     loop {
         app.update_notifications();
         plugin_proxy.flush_plugin_logs(&mut app);
-        terminal.draw(|f| draw(f, &app))?;
 
-        // Apply native cursor state (set during rendering)
-        {
+        // Anything logged, notified or expired since the last pass needs a
+        // frame, as does a change in the displayed byte rates
+        let activity = (
+            app.message_log.last_seq,
+            app.notifications.len(),
+            app.rx_rate.bytes_per_sec() as u64,
+            app.tx_rate.bytes_per_sec() as u64,
+        );
+        // The connecting spinner animates on its own
+        if last_activity != Some(activity) || app.connection == ConnectionState::Connecting {
+            frames.mark_dirty();
+            last_activity = Some(activity);
+        }
+
+        let now = Instant::now();
+        if frames.should_render(now) {
+            terminal.draw(|f| draw(f, &app))?;
+            frames.rendered(now);
+
+            // Apply native cursor state (set during rendering)
             let areas = tuiserial_ui::get_ui_areas();
             if areas.show_cursor {
                 execute!(io::stdout(), MoveTo(areas.cursor_x, areas.cursor_y), Show)?;
@@ -125,8 +262,24 @@ fn run_app(mut terminal: Terminal<CrosstermBackend<io::Stdout>>) -> Result<()> {
             }
         }
 
-        if event::poll(Duration::from_millis(100))? {
-            match event::read()? {
+        // Wake up early when a repeat send or a coalesced frame is due
+        // sooner than the idle poll
+        let now = Instant::now();
+        let poll_timeout = app
+            .repeat_send
+            .as_ref()
+            .map_or(IDLE_POLL, |r| r.time_until_due(now).min(IDLE_POLL))
+            .min(frames.time_until_due(now));
+
+        if event::poll(poll_timeout)? {
+            let event = event::read()?;
+            // Key presses render right away; everything else is coalesced
+            if matches!(event, Event::Key(_)) {
+                frames.force();
+            } else {
+                frames.mark_dirty();
+            }
+            match event {
                 Event::Key(key) => {
                     let should_exit = key_handler::handle_key_event(
                         key,
@@ -146,7 +299,16 @@ fn run_app(mut terminal: Terminal<CrosstermBackend<io::Stdout>>) -> Result<()> {
                         &mut plugin_proxy,
                     );
                 }
-                Event::Resize(_, _) => {}
+                Event::Resize(width, height) => {
+                    // Lay out at the new size right away so the log viewport
+                    // (and every stored area) reflects it before the next event
+                    tuiserial_ui::clamp_ui_areas(width, height);
+                    terminal.draw(|f| draw(f, &app))?;
+                    frames.force();
+                    let log_area = tuiserial_ui::get_ui_areas().log_area;
+                    let layout = tuiserial_ui::log_layout(&app, app.primary_view());
+                    app.fit_scroll_to_viewport(&layout, tuiserial_ui::log_viewport_lines(log_area));
+                }
                 Event::Paste(data) => {
                     input_utils::handle_paste_event(&data, &mut app);
                 }
@@ -154,17 +316,21 @@ fn run_app(mut terminal: Terminal<CrosstermBackend<io::Stdout>>) -> Result<()> {
             }
         }
 
+        global_handler::finish_connect(&mut app, &mut handler, &mut plugin_proxy);
+
         // Try to read from serial port if connected
         if handler.is_connected() {
             match handler.read() {
                 Ok(data) if !data.is_empty() => {
+                    // Time the read before plugins and deframing add latency
+                    let read_at = Instant::now();
                     handler.reset_read_errors();
-                    let (processed, suppressed) = plugin_proxy.process_rx(data, &app.config);
+                    app.link_quality.record(true);
+                    let (processed, suppressed) =
+                        plugin_proxy.process_rx(data, app.active_config());
                     if !suppressed {
-                        app.message_log.push_rx(processed);
-                        if app.auto_scroll {
-                            let lines_count = app.message_log.entries.len() as u16;
-                            app.scroll_offset = lines_count.saturating_sub(1);
+                        for entry in handler.deframe(app.settings.framing, processed) {
+                            app.receive_rx(entry, read_at);
                         }
                     }
                 }
@@ -173,6 +339,7 @@ fn run_app(mut terminal: Terminal<CrosstermBackend<io::Stdout>>) -> Result<()> {
                     handler.reset_read_errors();
                 }
                 Err(e) => {
+                    app.link_quality.record(false);
                     let (app_error, should_disconnect) = handler.handle_read_error(e);
                     app.record_error(app_error);
                     if should_disconnect {
@@ -181,11 +348,27 @@ fn run_app(mut terminal: Terminal<CrosstermBackend<io::Stdout>>) -> Result<()> {
                             app.record_error(err);
                         }
                         handler.disconnect();
-                        app.is_connected = false;
+                        app.mark_connection_lost(&t!("event.reason_read_errors"));
                     }
                 }
             }
         }
+
+        tx_handler::tick_repeat(&mut app, &mut handler, &mut plugin_proxy);
+
+        if let Some(file) = &mut metrics
+            && let Err(e) = file.tick(|uptime| MetricsSnapshot::from_app(&app, uptime))
+        {
+            log::warn!("Cannot write metrics to {}: {e}", file.path().display());
+            app.add_warning(
+                t!(
+                    "notify.metrics_failed",
+                    path = file.path().display().to_string()
+                )
+                .to_string(),
+            );
+            metrics = None;
+        }
     }
 
     if handler.is_connected() {
@@ -193,7 +376,14 @@ fn run_app(mut terminal: Terminal<CrosstermBackend<io::Stdout>>) -> Result<()> {
             app.record_error(err);
         }
         handler.disconnect();
-        log::info!("Disconnected from {}", app.config.port);
+        log::info!("Disconnected from {}", app.active_config().port);
+    }
+    // Leave a final snapshot showing the port closed
+    if let Some(file) = &mut metrics {
+        let snapshot = MetricsSnapshot::from_app(&app, file.uptime());
+        if let Err(e) = file.write(&snapshot) {
+            log::warn!("Cannot write metrics to {}: {e}", file.path().display());
+        }
     }
     plugin_proxy.on_app_exit();
     log::info!("tuiserial exiting normally");
@@ -1,8 +1,15 @@
 //! Menu action handler — dispatches menu bar actions to the appropriate logic.
 
+use crossterm::event::{KeyCode, KeyEvent};
 use rust_i18n::t;
-use tuiserial_core::{AppState, MenuAction, menu_def::MENU_BAR};
+use tuiserial_core::chrono::Local;
+use tuiserial_core::{
+    AppState, ConfigLoad, ConfigSave, ExportDialog, ExportFilter, ExportFormat, MenuAction,
+    ShortcutAction, menu_def::MENU_BAR,
+};
 
+use crate::clipboard::write_clipboard;
+use crate::export::{export_to_dir, export_to_dir_named};
 use crate::handler::SerialHandler;
 use crate::plugin_adapter::PluginProxy;
 
@@ -25,18 +32,21 @@ pub fn handle_menu_action(
 
     match action {
         MenuAction::SaveConfig => {
-            match app.save_config() {
-                Ok(_) => app.add_success(t!("notify.config_saved").to_string()),
-                Err(e) => app.add_error(format!("{}: {}", t!("notify.config_save_failed"), e)),
-            }
+            save_config_and_notify(app);
             false
         }
         MenuAction::LoadConfig => {
-            app.load_config();
-            app.add_success(t!("notify.config_loaded").to_string());
+            load_config_and_notify(app);
+            false
+        }
+        MenuAction::ExportLog => {
+            app.export_dialog = Some(ExportDialog::default());
             false
         }
         MenuAction::Exit => {
+            if !app.request_quit() {
+                return false;
+            }
             if handler.is_connected() {
                 handler.disconnect();
             }
@@ -47,6 +57,217 @@ pub fn handle_menu_action(
             app.add_success(t!("notify.language_changed").to_string());
             false
         }
+        MenuAction::ToggleLogEvents => {
+            app.settings.log_events = !app.settings.log_events;
+            let status = if app.settings.log_events {
+                t!("notify.enabled")
+            } else {
+                t!("notify.disabled")
+            };
+            app.add_info(format!("{}: {}", t!("notify.log_events"), status));
+            false
+        }
+        MenuAction::CycleCountDisplay => {
+            app.settings.count_display = app.settings.count_display.next();
+            let mode = match app.settings.count_display {
+                tuiserial_core::CountDisplay::Total => t!("count_display.total"),
+                tuiserial_core::CountDisplay::Retained => t!("count_display.retained"),
+                tuiserial_core::CountDisplay::Both => t!("count_display.both"),
+            };
+            app.add_info(format!("{}: {}", t!("notify.count_display"), mode));
+            false
+        }
+        MenuAction::ToggleIdleGaps => {
+            app.settings.show_idle_gaps = !app.settings.show_idle_gaps;
+            let status = if app.settings.show_idle_gaps {
+                t!("notify.enabled")
+            } else {
+                t!("notify.disabled")
+            };
+            app.add_info(format!("{}: {}", t!("notify.idle_gaps"), status));
+            false
+        }
+        MenuAction::ToggleConfigStaging => {
+            let enabled = !app.settings.stage_config_changes;
+            app.set_config_staging(enabled);
+            let status = if enabled {
+                t!("notify.enabled")
+            } else {
+                t!("notify.disabled")
+            };
+            app.add_info(format!("{}: {}", t!("notify.config_staging"), status));
+            false
+        }
+        MenuAction::ToggleNativeTxDisplay => {
+            app.settings.native_tx_display = !app.settings.native_tx_display;
+            let status = if app.settings.native_tx_display {
+                t!("notify.enabled")
+            } else {
+                t!("notify.disabled")
+            };
+            app.add_info(format!("{}: {}", t!("notify.native_tx_display"), status));
+            false
+        }
+        MenuAction::ToggleCompactConfig => {
+            app.settings.compact_config = !app.settings.compact_config;
+            let status = if app.settings.compact_config {
+                t!("notify.enabled")
+            } else {
+                t!("notify.disabled")
+            };
+            app.add_info(format!("{}: {}", t!("notify.compact_config"), status));
+            false
+        }
+        MenuAction::CyclePasteAppend => {
+            app.settings.paste_append = app.settings.paste_append.next();
+            let mode = match app.settings.paste_append {
+                tuiserial_core::PasteAppend::Never => t!("paste_append.never"),
+                tuiserial_core::PasteAppend::Once => t!("paste_append.once"),
+                tuiserial_core::PasteAppend::EachLine => t!("paste_append.each_line"),
+            };
+            app.add_info(format!("{}: {}", t!("notify.paste_append"), mode));
+            false
+        }
+        MenuAction::ToggleBitOrder => {
+            app.settings.lsb_first = !app.settings.lsb_first;
+            let status = if app.settings.lsb_first {
+                t!("notify.enabled")
+            } else {
+                t!("notify.disabled")
+            };
+            app.add_info(format!("{}: {}", t!("notify.bit_order"), status));
+            false
+        }
+        MenuAction::ToggleHighBitMask => {
+            app.settings.mask_high_bit = !app.settings.mask_high_bit;
+            let status = if app.settings.mask_high_bit {
+                t!("notify.enabled")
+            } else {
+                t!("notify.disabled")
+            };
+            app.add_info(format!("{}: {}", t!("notify.mask_high_bit"), status));
+            false
+        }
+        MenuAction::ToggleControlPictures => {
+            app.settings.control_pictures = !app.settings.control_pictures;
+            let status = if app.settings.control_pictures {
+                t!("notify.enabled")
+            } else {
+                t!("notify.disabled")
+            };
+            app.add_info(format!("{}: {}", t!("notify.control_pictures"), status));
+            false
+        }
+        MenuAction::ToggleTxFocusLock => {
+            app.settings.tx_focus_lock = !app.settings.tx_focus_lock;
+            if !app.settings.tx_focus_lock {
+                app.release_tx_lock();
+            }
+            let status = if app.settings.tx_focus_lock {
+                t!("notify.enabled")
+            } else {
+                t!("notify.disabled")
+            };
+            app.add_info(format!("{}: {}", t!("notify.tx_focus_lock"), status));
+            false
+        }
+        MenuAction::ToggleSendConfirmation => {
+            app.settings.confirm_large_sends = !app.settings.confirm_large_sends;
+            let status = if app.settings.confirm_large_sends {
+                format!(
+                    "{} (> {} B)",
+                    t!("notify.enabled"),
+                    app.settings.large_send_threshold
+                )
+            } else {
+                t!("notify.disabled").to_string()
+            };
+            app.add_info(format!("{}: {}", t!("notify.confirm_large_sends"), status));
+            false
+        }
+        MenuAction::ToggleQuitConfirmation => {
+            app.settings.confirm_quit_when_active = !app.settings.confirm_quit_when_active;
+            let status = if app.settings.confirm_quit_when_active {
+                t!("notify.enabled")
+            } else {
+                t!("notify.disabled")
+            };
+            app.add_info(format!("{}: {}", t!("notify.confirm_quit"), status));
+            false
+        }
+        MenuAction::ToggleRxChunkTiming => {
+            app.settings.rx_chunk_timing = !app.settings.rx_chunk_timing;
+            if !app.settings.rx_chunk_timing {
+                app.rx_timing.clear();
+                app.show_rx_timing = false;
+            }
+            let status = if app.settings.rx_chunk_timing {
+                t!("notify.enabled")
+            } else {
+                t!("notify.disabled")
+            };
+            app.add_info(format!("{}: {}", t!("notify.rx_chunk_timing"), status));
+            false
+        }
+        MenuAction::CycleDisconnectedRx => {
+            app.settings.disconnected_rx = app.settings.disconnected_rx.next();
+            let mode = match app.settings.disconnected_rx {
+                tuiserial_core::DisconnectedRx::Drop => t!("disconnected_rx.drop"),
+                tuiserial_core::DisconnectedRx::BufferAndWarn => {
+                    t!("disconnected_rx.buffer_and_warn")
+                }
+            };
+            app.add_info(format!("{}: {}", t!("notify.disconnected_rx"), mode));
+            false
+        }
+        MenuAction::ToggleSelectionScrollLock => {
+            app.settings.scroll_lock_on_select = !app.settings.scroll_lock_on_select;
+            let status = if app.settings.scroll_lock_on_select {
+                t!("notify.enabled")
+            } else {
+                t!("notify.disabled")
+            };
+            app.add_info(format!(
+                "{}: {}",
+                t!("notify.selection_scroll_lock"),
+                status
+            ));
+            false
+        }
+        MenuAction::ToggleAutoConnect => {
+            app.settings.auto_connect_on_start = !app.settings.auto_connect_on_start;
+            let status = if app.settings.auto_connect_on_start {
+                t!("notify.enabled")
+            } else {
+                t!("notify.disabled")
+            };
+            app.add_info(format!("{}: {}", t!("notify.auto_connect"), status));
+            false
+        }
+        MenuAction::ToggleClearLogOnConnect => {
+            app.settings.clear_log_on_connect = !app.settings.clear_log_on_connect;
+            let status = if app.settings.clear_log_on_connect {
+                t!("notify.enabled")
+            } else {
+                t!("notify.disabled")
+            };
+            app.add_info(format!("{}: {}", t!("notify.clear_log_on_connect"), status));
+            false
+        }
+        MenuAction::CycleFraming => {
+            app.settings.framing = app.settings.framing.next();
+            handler.reset_framing();
+            let mode = match app.settings.framing {
+                tuiserial_core::FramingMode::Raw => t!("framing.raw"),
+                tuiserial_core::FramingMode::Slip => t!("framing.slip"),
+            };
+            app.add_info(format!("{}: {}", t!("notify.framing"), mode));
+            false
+        }
+        MenuAction::SetTxPrefix | MenuAction::SetTxSuffix => {
+            crate::tx_handler::set_tx_affix_from_input(app, action == MenuAction::SetTxSuffix);
+            false
+        }
         MenuAction::ShowShortcuts => {
             app.show_shortcuts_help = !app.show_shortcuts_help;
             false
@@ -74,6 +295,10 @@ pub fn handle_menu_action(
             }
             false
         }
+        MenuAction::CopyDiagnostics => {
+            copy_diagnostics_and_notify(app);
+            false
+        }
         MenuAction::NewSession
         | MenuAction::DuplicateSession
         | MenuAction::RenameSession
@@ -81,6 +306,14 @@ pub fn handle_menu_action(
             app.add_info("Multi-session support coming soon!".to_string());
             false
         }
+        MenuAction::ViewDualLog => {
+            toggle_dual_view_and_notify(app);
+            false
+        }
+        MenuAction::ViewSingle if app.dual_view.is_some() => {
+            toggle_dual_view_and_notify(app);
+            false
+        }
         MenuAction::ViewSingle
         | MenuAction::ViewSplitHorizontal
         | MenuAction::ViewSplitVertical
@@ -90,6 +323,21 @@ pub fn handle_menu_action(
             app.add_info("Layout management coming soon!".to_string());
             false
         }
+        MenuAction::ToggleTimestampColumn
+        | MenuAction::ToggleDirectionColumn
+        | MenuAction::ToggleLengthColumn
+        | MenuAction::ToggleSeqColumn => {
+            if let Some(column) = action.log_column() {
+                app.settings.log_columns.toggle(column);
+                let status = if app.settings.log_columns.contains(column) {
+                    t!("notify.column_shown")
+                } else {
+                    t!("notify.column_hidden")
+                };
+                app.add_info(format!("{}: {}", t!(action.label_key()), status));
+            }
+            false
+        }
         MenuAction::PluginsInstall => {
             plugin_proxy.open_registry_modal(app);
             false
@@ -118,6 +366,182 @@ pub fn handle_menu_action(
     }
 }
 
+/// Show or hide the dual log view and report the new state.
+pub fn toggle_dual_view_and_notify(app: &mut AppState) {
+    let status = if app.toggle_dual_view() {
+        t!("notify.enabled")
+    } else {
+        t!("notify.disabled")
+    };
+    app.add_info(format!("{}: {}", t!("notify.dual_view"), status));
+}
+
+/// Save the configuration and report where it went.
+pub fn save_config_and_notify(app: &mut AppState) {
+    match app.save_config() {
+        Ok(ConfigSave::Primary(_)) => app.add_success(t!("notify.config_saved").to_string()),
+        Ok(ConfigSave::Fallback { path, reason }) => {
+            log::warn!(
+                "Config directory not writable ({reason}), saved to {}",
+                path.display()
+            );
+            app.add_warning(
+                t!(
+                    "notify.config_saved_fallback",
+                    path = path.display().to_string()
+                )
+                .to_string(),
+            );
+        }
+        Err(e) => app.add_error(format!("{}: {}", t!("notify.config_save_failed"), e)),
+    }
+}
+
+/// Reload the saved configuration on user request.
+pub fn load_config_and_notify(app: &mut AppState) {
+    match app.load_config() {
+        ConfigLoad::Loaded(_) => app.add_success(t!("notify.config_loaded").to_string()),
+        ConfigLoad::Missing => app.add_info(t!("notify.config_missing").to_string()),
+        ConfigLoad::Corrupt { path, reason } => {
+            log::warn!("Config {} unreadable: {reason}", path.display());
+            app.add_error(format!("{}: {}", t!("notify.config_load_failed"), reason));
+        }
+    }
+}
+
+/// Handle key events while the export dialog is open.
+pub fn handle_export_dialog_key(key: KeyEvent, app: &mut AppState) -> bool {
+    let Some(dialog) = app.export_dialog.as_mut() else {
+        return false;
+    };
+
+    match key.code {
+        KeyCode::Esc => app.export_dialog = None,
+        KeyCode::Tab | KeyCode::Down => dialog.move_field(true),
+        KeyCode::BackTab | KeyCode::Up => dialog.move_field(false),
+        KeyCode::Left | KeyCode::Right | KeyCode::Char(' ') => dialog.cycle_value(),
+        KeyCode::Enter => {
+            if let Some(dialog) = app.export_dialog.take() {
+                export_and_notify(app, &dialog);
+            }
+        }
+        _ => {}
+    }
+    false
+}
+
+/// Handle key events while the quit prompt is open: `s` saves the log
+/// (HEX) and quits, a second quit key, `y` or Enter quit, Esc/`n` cancel.
+pub fn handle_quit_prompt_key(
+    key: KeyEvent,
+    app: &mut AppState,
+    handler: &mut SerialHandler,
+) -> bool {
+    let quit = match key.code {
+        _ if app.key_bindings.action(&key) == Some(ShortcutAction::Quit) => app.request_quit(),
+        KeyCode::Char('s') | KeyCode::Char('S') => {
+            let result = export_to_dir(
+                &log_dir(),
+                app,
+                ExportFormat::Hex,
+                &ExportFilter::default(),
+                false,
+            );
+            let saved = result.is_ok();
+            notify_export(app, result);
+            saved
+        }
+        KeyCode::Char('y') | KeyCode::Char('Y') | KeyCode::Enter => true,
+        KeyCode::Esc | KeyCode::Char('n') | KeyCode::Char('N') => {
+            app.quit_prompt = false;
+            app.add_info(t!("notify.quit_cancelled").to_string());
+            false
+        }
+        _ => false,
+    };
+    if quit && handler.is_connected() {
+        handler.disconnect();
+    }
+    quit
+}
+
+/// Export the log into the log directory with the dialog's options.
+fn export_and_notify(app: &mut AppState, dialog: &ExportDialog) {
+    let result = export_to_dir(&log_dir(), app, dialog.format, &dialog.filter, dialog.gzip);
+    notify_export(app, result);
+}
+
+/// Set a new checkpoint, first exporting the step since the previous one.
+pub fn checkpoint_and_notify(app: &mut AppState) {
+    if app.checkpoint.is_some() {
+        export_since_checkpoint_and_notify(app);
+    }
+    let checkpoint = app.set_checkpoint();
+    app.add_info(t!("notify.checkpoint_set", number = checkpoint.number).to_string());
+}
+
+/// Export the entries logged since the current checkpoint as HEX.
+pub fn export_since_checkpoint_and_notify(app: &mut AppState) {
+    let Some(checkpoint) = app.checkpoint else {
+        app.add_warning(t!("notify.no_checkpoint").to_string());
+        return;
+    };
+    let prefix = format!("checkpoint-{}", checkpoint.number);
+    let result = export_to_dir_named(
+        &log_dir(),
+        &prefix,
+        app,
+        ExportFormat::Hex,
+        &checkpoint.filter(),
+        false,
+    );
+    notify_export(app, result);
+}
+
+fn notify_export(app: &mut AppState, result: std::io::Result<Option<(std::path::PathBuf, usize)>>) {
+    match result {
+        Ok(Some((path, count))) => app.add_success(
+            t!(
+                "notify.export_success",
+                count = count,
+                path = path.display().to_string()
+            )
+            .to_string(),
+        ),
+        Ok(None) => app.add_warning(t!("notify.export_empty").to_string()),
+        Err(e) => app.add_error(format!("{}: {}", t!("notify.export_failed"), e)),
+    }
+}
+
+/// Copy the diagnostics report to the clipboard, or save it to the log
+/// directory when no clipboard tool is available.
+fn copy_diagnostics_and_notify(app: &mut AppState) {
+    let mut report = app.diagnostics_report();
+    if let Ok((width, height)) = crossterm::terminal::size() {
+        report.push_str(&format!("terminal: {width}x{height}\n"));
+    }
+    if write_clipboard(&report).is_ok() {
+        app.add_success(t!("notify.diagnostics_copied").to_string());
+        return;
+    }
+
+    let dir = log_dir();
+    let path = dir.join(format!(
+        "diagnostics-{}.txt",
+        Local::now().format("%Y%m%d-%H%M%S")
+    ));
+    match std::fs::create_dir_all(&dir).and_then(|_| std::fs::write(&path, report)) {
+        Ok(()) => app.add_success(
+            t!(
+                "notify.diagnostics_saved",
+                path = path.display().to_string()
+            )
+            .to_string(),
+        ),
+        Err(e) => app.add_error(format!("{}: {}", t!("notify.diagnostics_failed"), e)),
+    }
+}
+
 /// Get the application config directory.
 fn config_dir() -> std::path::PathBuf {
     dirs::config_dir()
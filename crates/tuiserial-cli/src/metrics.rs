@@ -0,0 +1,252 @@
+//! Metrics file (`--metrics-file`).
+//!
+//! Writes a few counters in the Prometheus text exposition format so a
+//! node_exporter textfile collector, or any script, can watch long-running
+//! instances. The file is replaced atomically every few seconds and only
+//! holds counters the UI or the capture already keeps.
+
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use tuiserial_core::AppState;
+
+/// Interval between metrics file updates.
+pub const METRICS_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Values written to the metrics file.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MetricsSnapshot {
+    pub port: String,
+    pub connected: bool,
+    pub rx_bytes: u64,
+    pub tx_bytes: u64,
+    pub rx_reads: u64,
+    pub tx_writes: u64,
+    /// Errors and warnings still in the error log, counting repeats
+    pub errors: u64,
+    pub uptime: Duration,
+}
+
+impl MetricsSnapshot {
+    /// Snapshot of the UI's counters; the traffic counters come from the
+    /// log's totals so clearing the log does not reset them.
+    pub fn from_app(app: &AppState, uptime: Duration) -> Self {
+        let totals = app.message_log.totals;
+        Self {
+            port: app.active_config().port.clone(),
+            connected: app.is_connected(),
+            rx_bytes: totals.rx_bytes,
+            tx_bytes: totals.tx_bytes,
+            rx_reads: totals.rx_reads,
+            tx_writes: totals.tx_writes,
+            errors: app.error_log.entries.iter().map(|e| e.count as u64).sum(),
+            uptime,
+        }
+    }
+}
+
+/// Render `snapshot` in the Prometheus text format, every sample labelled
+/// with the port.
+pub fn format_metrics(snapshot: &MetricsSnapshot) -> String {
+    let label = format!("{{port=\"{}\"}}", escape_label(&snapshot.port));
+    let metrics: [(&str, &str, &str, String); 7] = [
+        (
+            "tuiserial_rx_bytes_total",
+            "counter",
+            "Bytes received since tuiserial started",
+            snapshot.rx_bytes.to_string(),
+        ),
+        (
+            "tuiserial_tx_bytes_total",
+            "counter",
+            "Bytes sent since tuiserial started",
+            snapshot.tx_bytes.to_string(),
+        ),
+        (
+            "tuiserial_rx_reads_total",
+            "counter",
+            "Port reads that returned data",
+            snapshot.rx_reads.to_string(),
+        ),
+        (
+            "tuiserial_tx_writes_total",
+            "counter",
+            "Payloads written to the port",
+            snapshot.tx_writes.to_string(),
+        ),
+        (
+            "tuiserial_errors",
+            "gauge",
+            "Errors and warnings in the error log, counting repeats",
+            snapshot.errors.to_string(),
+        ),
+        (
+            "tuiserial_connected",
+            "gauge",
+            "Whether the port is open (1) or not (0)",
+            u8::from(snapshot.connected).to_string(),
+        ),
+        (
+            "tuiserial_uptime_seconds",
+            "gauge",
+            "Seconds since tuiserial started",
+            format!("{:.3}", snapshot.uptime.as_secs_f64()),
+        ),
+    ];
+
+    let mut out = String::new();
+    for (name, kind, help, value) in metrics {
+        let _ = writeln!(out, "# HELP {name} {help}");
+        let _ = writeln!(out, "# TYPE {name} {kind}");
+        let _ = writeln!(out, "{name}{label} {value}");
+    }
+    out
+}
+
+/// Escape a label value: backslash, double quote and newline.
+fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+/// Periodically rewritten metrics file.
+pub struct MetricsFile {
+    path: PathBuf,
+    started: Instant,
+    last_write: Option<Instant>,
+}
+
+impl MetricsFile {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            started: Instant::now(),
+            last_write: None,
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Time since the file was set up, i.e. since startup.
+    pub fn uptime(&self) -> Duration {
+        self.started.elapsed()
+    }
+
+    /// Write the snapshot from `snapshot` (given the uptime) if
+    /// [`METRICS_INTERVAL`] has passed since the last write.
+    pub fn tick(&mut self, snapshot: impl FnOnce(Duration) -> MetricsSnapshot) -> io::Result<()> {
+        if self
+            .last_write
+            .is_some_and(|at| at.elapsed() < METRICS_INTERVAL)
+        {
+            return Ok(());
+        }
+        self.write(&snapshot(self.uptime()))
+    }
+
+    /// Replace the file with `snapshot`. Written to a temporary file first
+    /// so a scraper never sees a partial update.
+    pub fn write(&mut self, snapshot: &MetricsSnapshot) -> io::Result<()> {
+        let mut tmp = self.path.clone().into_os_string();
+        tmp.push(".tmp");
+        fs::write(&tmp, format_metrics(snapshot))?;
+        fs::rename(&tmp, &self.path)?;
+        self.last_write = Some(Instant::now());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tuiserial_core::{AppError, ErrorContext, RecoveryStrategy, SerialErrorKind};
+
+    #[test]
+    fn test_format_from_app_state() {
+        let mut app = AppState::default();
+        app.config.port = "/dev/ttyUSB0".into();
+        app.mark_connected();
+        app.append_rx(b"OK\r\n".to_vec());
+        app.append_rx(b"READY".to_vec());
+        app.message_log.push_tx(b"AT".to_vec());
+        let error = || AppError::Serial {
+            kind: SerialErrorKind::Io("timed out".into()),
+            ctx: ErrorContext::new("serial", "read", RecoveryStrategy::Skip),
+        };
+        app.error_log.push(error());
+        app.error_log.push(error());
+
+        let text = format_metrics(&MetricsSnapshot::from_app(
+            &app,
+            Duration::from_millis(12_500),
+        ));
+        let samples: Vec<&str> = text.lines().filter(|l| !l.starts_with('#')).collect();
+        assert_eq!(
+            samples,
+            vec![
+                "tuiserial_rx_bytes_total{port=\"/dev/ttyUSB0\"} 9",
+                "tuiserial_tx_bytes_total{port=\"/dev/ttyUSB0\"} 2",
+                "tuiserial_rx_reads_total{port=\"/dev/ttyUSB0\"} 2",
+                "tuiserial_tx_writes_total{port=\"/dev/ttyUSB0\"} 1",
+                "tuiserial_errors{port=\"/dev/ttyUSB0\"} 2",
+                "tuiserial_connected{port=\"/dev/ttyUSB0\"} 1",
+                "tuiserial_uptime_seconds{port=\"/dev/ttyUSB0\"} 12.500",
+            ]
+        );
+        assert!(text.starts_with(
+            "# HELP tuiserial_rx_bytes_total Bytes received since tuiserial started\n\
+             # TYPE tuiserial_rx_bytes_total counter\n"
+        ));
+    }
+
+    #[test]
+    fn test_port_label_escaped() {
+        let snapshot = MetricsSnapshot {
+            port: r#"COM"3\"#.into(),
+            ..Default::default()
+        };
+        let text = format_metrics(&snapshot);
+        assert!(text.contains(r#"tuiserial_connected{port="COM\"3\\"} 0"#));
+    }
+
+    #[test]
+    fn test_tick_waits_for_interval() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("tuiserial.prom");
+        let mut metrics = MetricsFile::new(&path);
+        metrics.tick(|_| MetricsSnapshot::default()).unwrap();
+        let first = fs::read_to_string(&path).unwrap();
+        assert!(first.contains("tuiserial_rx_bytes_total{port=\"\"} 0"));
+
+        metrics
+            .tick(|_| MetricsSnapshot {
+                rx_bytes: 5,
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), first);
+    }
+
+    #[test]
+    fn test_counters_survive_log_clears() {
+        let mut app = AppState::default();
+        app.append_rx(b"boot".to_vec());
+        app.message_log.push_tx(b"AT".to_vec());
+        app.clear_log();
+        app.settings.clear_log_on_connect = true;
+        app.mark_connected();
+        app.append_rx(b"OK".to_vec());
+
+        let snapshot = MetricsSnapshot::from_app(&app, Duration::ZERO);
+        assert_eq!((snapshot.rx_bytes, snapshot.rx_reads), (6, 2));
+        assert_eq!((snapshot.tx_bytes, snapshot.tx_writes), (2, 1));
+        assert_eq!(app.message_log.rx_bytes, 2);
+    }
+}
@@ -4,12 +4,15 @@ use crossterm::event::{MouseButton, MouseEvent, MouseEventKind};
 use ratatui::layout::Rect;
 use rust_i18n::t;
 use tuiserial_core::{AppState, DisplayMode, FocusedField, MenuState, menu_def::MENU_BAR};
-use tuiserial_ui::{find_clicked_menu, get_clicked_field, get_ui_areas, is_inside};
+use tuiserial_ui::{
+    byte_pad_cell, find_clicked_menu, get_clicked_field, get_ui_areas, is_inside, log_layout,
+    log_viewport_lines, menu_dropdown_layout,
+};
 
 use crate::handler::SerialHandler;
-use crate::input_utils::display_width;
 use crate::menu_handler::handle_menu_action;
 use crate::plugin_adapter::PluginProxy;
+use crate::tx_handler::send_pad_byte;
 
 /// Handle mouse events (click, scroll, drag).
 pub fn handle_mouse_event(
@@ -21,8 +24,15 @@ pub fn handle_mouse_event(
     let col = mouse.column;
     let row = mouse.row;
 
+    if is_locked_out_click(mouse.kind, col, row, app) {
+        app.add_warning(t!("notify.tx_locked").to_string());
+        return;
+    }
+
     match mouse.kind {
         MouseEventKind::Down(MouseButton::Left) => {
+            // A new press ends any previous selection; dragging starts one
+            app.clear_log_selection();
             handle_left_click(col, row, app, handler, plugin_proxy);
         }
         MouseEventKind::Down(MouseButton::Right) => {
@@ -37,11 +47,53 @@ pub fn handle_mouse_event(
         MouseEventKind::ScrollDown => {
             handle_scroll_down(col, row, app);
         }
-        MouseEventKind::Drag(MouseButton::Left) => {}
+        MouseEventKind::Drag(MouseButton::Left) => {
+            handle_log_drag(col, row, app);
+        }
         _ => {}
     }
 }
 
+/// A button press outside the TX area while the TX focus lock holds focus.
+/// Left clicks on the byte pad still send, since they leave focus alone.
+fn is_locked_out_click(kind: MouseEventKind, col: u16, row: u16, app: &AppState) -> bool {
+    let areas = get_ui_areas();
+    let pad_click =
+        kind == MouseEventKind::Down(MouseButton::Left) && is_inside(areas.byte_pad, col, row);
+    matches!(kind, MouseEventKind::Down(_))
+        && app.tx_focus_locked()
+        && !is_inside(areas.tx_area, col, row)
+        && !pad_click
+}
+
+/// Start or extend a drag selection over the log entries under the mouse
+fn handle_log_drag(col: u16, row: u16, app: &mut AppState) {
+    let areas = get_ui_areas();
+    let log_area = areas.log_area;
+    if app.tx_focus_locked()
+        || !is_inside(log_area, col, row)
+        || is_inside(areas.byte_pad, col, row)
+    {
+        return;
+    }
+    let layout = log_layout(app, app.primary_view());
+    let Some(last) = layout.len().checked_sub(1) else {
+        return;
+    };
+    let viewport_lines = log_viewport_lines(log_area);
+    // Pin the scroll position to what is on screen before following stops
+    app.fit_scroll_to_viewport(&layout, viewport_lines);
+    let row_in_view = row.saturating_sub(log_area.y + 1) as usize;
+    let position = app
+        .log_position_at_row(&layout, viewport_lines, row_in_view)
+        .unwrap_or(last);
+    if app.log_selection.is_some() {
+        app.extend_log_selection(position);
+    } else {
+        app.begin_log_selection(position);
+    }
+}
+
 fn handle_left_click(
     col: u16,
     row: u16,
@@ -56,6 +108,13 @@ fn handle_left_click(
         return;
     }
 
+    if is_inside(areas.byte_pad, col, row) {
+        if let Some(byte) = byte_pad_cell(areas.byte_pad, col, row) {
+            send_pad_byte(app, handler, plugin_proxy, byte);
+        }
+        return;
+    }
+
     if is_inside(areas.menu_bar, col, row) {
         if let Some(menu_idx) = find_clicked_menu(col, row, areas.menu_bar) {
             match app.menu_state {
@@ -71,48 +130,21 @@ fn handle_left_click(
         return;
     }
 
-    if let MenuState::Dropdown(menu_idx, _) = app.menu_state {
-        let menu = match MENU_BAR.get_menu(menu_idx) {
-            Some(m) => m,
-            None => {
-                app.menu_state = MenuState::None;
-                return;
-            }
-        };
-
-        let items: Vec<String> = menu
-            .items
-            .iter()
-            .map(|action| {
-                if action.is_separator() {
-                    String::new()
-                } else {
-                    t!(action.label_key()).to_string()
-                }
-            })
-            .collect();
-
-        let max_width = items
-            .iter()
-            .map(|s| display_width(s.as_str()))
-            .max()
-            .unwrap_or(10) as u16
-            + 6;
-        let height = items.len() as u16 + 2;
-
-        let x_offset = tuiserial_core::menu_def::calculate_menu_x_offset(menu_idx);
-
-        let dropdown_area = Rect {
-            x: areas.menu_bar.x + x_offset,
-            y: areas.menu_bar.y + 1,
-            width: max_width,
-            height,
+    if let MenuState::Dropdown(menu_idx, selected_item) = app.menu_state {
+        let screen = crossterm::terminal::size()
+            .map(|(width, height)| Rect::new(0, 0, width, height))
+            .unwrap_or(Rect::new(0, 0, u16::MAX, u16::MAX));
+        let Some((dropdown_area, first_visible)) =
+            menu_dropdown_layout(screen, areas.menu_bar, menu_idx, selected_item)
+        else {
+            app.menu_state = MenuState::None;
+            return;
         };
 
         if is_inside(dropdown_area, col, row) {
             let relative_y = row - dropdown_area.y;
-            if relative_y > 0 && relative_y <= items.len() as u16 {
-                let item_idx = (relative_y - 1) as usize;
+            if relative_y > 0 && relative_y < dropdown_area.height.saturating_sub(1) {
+                let item_idx = first_visible + (relative_y - 1) as usize;
 
                 if let Some(action) = MENU_BAR.get_action(menu_idx, item_idx)
                     && !action.is_separator()
@@ -231,10 +263,8 @@ fn handle_left_click(
                             ));
                         }
                     } else {
-                        let char_count = app.tx_input.chars().count();
-                        let cursor_pos =
-                            relative_col.saturating_sub(1).min(char_count as u16) as usize;
-                        app.tx_cursor = cursor_pos;
+                        app.tx_input
+                            .move_to_column(relative_col.saturating_sub(1) as usize);
                     }
                 }
             }
@@ -290,11 +320,10 @@ fn handle_middle_click(col: u16, row: u16, app: &mut AppState) {
     let areas = get_ui_areas();
 
     if is_inside(areas.log_area, col, row) {
-        app.message_log.clear();
+        app.clear_log();
         app.add_info(t!("notify.log_cleared").to_string());
     } else if is_inside(areas.tx_area, col, row) {
         app.tx_input.clear();
-        app.tx_cursor = 0;
         app.add_info("已清空输入");
     }
 }
@@ -335,10 +364,9 @@ fn handle_scroll_down(col: u16, row: u16, app: &mut AppState) {
     if is_inside(areas.log_area, col, row) {
         app.scroll_offset = app.scroll_offset.saturating_add(3);
 
-        let lines = app.message_log.entries.len() as u16;
-        let viewport_lines = areas.log_area.height.saturating_sub(2).max(1);
-        let max_scroll = lines.saturating_sub(viewport_lines);
-        if app.scroll_offset >= max_scroll {
+        let layout = log_layout(app, app.primary_view());
+        let max_scroll = layout.max_scroll_position(log_viewport_lines(areas.log_area));
+        if app.scroll_offset as usize >= max_scroll {
             app.auto_scroll = true;
         }
     } else if is_inside(areas.port, col, row) {
@@ -367,3 +395,184 @@ fn handle_scroll_down(col: u16, row: u16, app: &mut AppState) {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyModifiers;
+    use tuiserial_core::LogDirection;
+    use tuiserial_ui::{UiAreas, update_ui_areas};
+
+    fn click(kind: MouseEventKind, column: u16, row: u16) -> MouseEvent {
+        MouseEvent {
+            kind,
+            column,
+            row,
+            modifiers: KeyModifiers::NONE,
+        }
+    }
+
+    fn setup() -> (AppState, SerialHandler, PluginProxy) {
+        update_ui_areas(UiAreas {
+            log_area: Rect::new(0, 0, 80, 20),
+            tx_area: Rect::new(0, 20, 80, 3),
+            ..Default::default()
+        });
+        let mut app = AppState::default();
+        let proxy = PluginProxy::init(&mut app);
+        app.settings.tx_focus_lock = true;
+        app.focused_field = FocusedField::TxInput;
+        app.tx_input = "AT+RST".into();
+        (app, SerialHandler::new(), proxy)
+    }
+
+    #[test]
+    fn test_tx_focus_lock_ignores_clicks_outside() {
+        let (mut app, mut handler, mut proxy) = setup();
+        app.message_log.push_rx(b"OK".to_vec());
+        app.engage_tx_lock();
+
+        let left = click(MouseEventKind::Down(MouseButton::Left), 5, 5);
+        handle_mouse_event(left, &mut app, &mut handler, &mut proxy);
+        assert_eq!(app.focused_field, FocusedField::TxInput);
+
+        // Middle click would otherwise clear the log
+        let middle = click(MouseEventKind::Down(MouseButton::Middle), 5, 5);
+        handle_mouse_event(middle, &mut app, &mut handler, &mut proxy);
+        assert_eq!(app.message_log.entries.len(), 1);
+
+        // Once released, clicks move focus again
+        app.release_tx_lock();
+        handle_mouse_event(left, &mut app, &mut handler, &mut proxy);
+        assert_eq!(app.focused_field, FocusedField::LogArea);
+    }
+
+    #[test]
+    fn test_tx_focus_lock_not_engaged_before_typing() {
+        let (mut app, mut handler, mut proxy) = setup();
+        let left = click(MouseEventKind::Down(MouseButton::Left), 5, 5);
+        handle_mouse_event(left, &mut app, &mut handler, &mut proxy);
+        assert_eq!(app.focused_field, FocusedField::LogArea);
+    }
+
+    #[test]
+    fn test_drag_selects_log_entries_and_locks_scroll() {
+        let (mut app, mut handler, mut proxy) = setup();
+        app.settings.tx_focus_lock = false;
+        for i in 0..40 {
+            app.append_rx(vec![i]);
+        }
+
+        let drag = |row| click(MouseEventKind::Drag(MouseButton::Left), 5, row);
+        handle_mouse_event(drag(3), &mut app, &mut handler, &mut proxy);
+        handle_mouse_event(drag(6), &mut app, &mut handler, &mut proxy);
+        assert!(!app.auto_scroll);
+        // 18 rows visible, so the top of the view is entry 22 (seq 23)
+        assert_eq!(app.log_selection.unwrap().range(), 25..=28);
+
+        let left = click(MouseEventKind::Down(MouseButton::Left), 5, 5);
+        handle_mouse_event(left, &mut app, &mut handler, &mut proxy);
+        assert_eq!(app.log_selection, None);
+        assert!(app.auto_scroll);
+    }
+
+    #[test]
+    fn test_drag_hit_testing_counts_idle_gap_rows() {
+        let (mut app, mut handler, mut proxy) = setup();
+        app.settings.tx_focus_lock = false;
+        app.settings.show_idle_gaps = true;
+        app.settings.idle_gap_ms = 1000;
+        for i in 0..10 {
+            app.append_rx(vec![i]);
+            let entry = &mut app.message_log.entries[i as usize];
+            entry.timestamp += tuiserial_core::chrono::TimeDelta::seconds(2 * i as i64);
+        }
+
+        // 19 rows in an 18-row view: the top row is entry 1's gap row, and
+        // screen row 4 (view row 3) is entry 2's data row
+        let drag = |row| click(MouseEventKind::Drag(MouseButton::Left), 5, row);
+        handle_mouse_event(drag(1), &mut app, &mut handler, &mut proxy);
+        handle_mouse_event(drag(4), &mut app, &mut handler, &mut proxy);
+        assert_eq!(app.log_selection.unwrap().range(), 2..=3);
+    }
+
+    #[test]
+    fn test_drag_hit_testing_counts_multi_line_renderer_rows() {
+        use ratatui::text::Line;
+        use tuiserial_core::{DisplayRenderer, LogEntry};
+
+        struct ThreeRows;
+        impl DisplayRenderer for ThreeRows {
+            fn render(&self, _entry: &LogEntry) -> Vec<Line<'static>> {
+                vec![Line::from("a"), Line::from("b"), Line::from("c")]
+            }
+        }
+
+        let (mut app, mut handler, mut proxy) = setup();
+        app.settings.tx_focus_lock = false;
+        for i in 0..10 {
+            app.append_rx(vec![i]);
+        }
+        app.register_renderer("ThreeRows", ThreeRows);
+        app.toggle_display_mode();
+        app.toggle_display_mode();
+
+        // 30 rows in an 18-row view start at entry 4; screen row 5 (view
+        // row 4) is entry 5's second row
+        let drag = |row| click(MouseEventKind::Drag(MouseButton::Left), 5, row);
+        handle_mouse_event(drag(1), &mut app, &mut handler, &mut proxy);
+        handle_mouse_event(drag(5), &mut app, &mut handler, &mut proxy);
+        assert_eq!(app.log_selection.unwrap().range(), 5..=6);
+    }
+
+    #[test]
+    fn test_byte_pad_click_sends_byte() {
+        let (mut app, _, mut proxy) = setup();
+        app.settings.tx_focus_lock = false;
+        let device = crate::handler::mock::MockDevice::default();
+        let mut handler = crate::handler::mock::connected_handler(&device);
+        app.mark_connected();
+        app.byte_pad = Some(tuiserial_core::BytePad::default());
+        let mut areas = get_ui_areas();
+        areas.byte_pad = Rect::new(27, 0, 53, 20);
+        update_ui_areas(areas);
+
+        // Row 4, column 1: 0x41
+        let left = click(MouseEventKind::Down(MouseButton::Left), 27 + 4 + 3, 2 + 4);
+        handle_mouse_event(left, &mut app, &mut handler, &mut proxy);
+        let last = app.message_log.entries.back().unwrap();
+        assert_eq!(last.direction, LogDirection::Tx);
+        assert_eq!(last.data, vec![0x41]);
+        assert_eq!(app.byte_pad.unwrap().last_sent, Some(0x41));
+
+        // The column header sends nothing and does not reach the log
+        let header = click(MouseEventKind::Down(MouseButton::Left), 27 + 4, 1);
+        handle_mouse_event(header, &mut app, &mut handler, &mut proxy);
+        assert_eq!(app.message_log.tx_count, 1);
+        assert_eq!(app.focused_field, FocusedField::TxInput);
+    }
+
+    #[test]
+    fn test_byte_pad_click_sends_while_tx_locked() {
+        let (mut app, _, mut proxy) = setup();
+        let device = crate::handler::mock::MockDevice::default();
+        let mut handler = crate::handler::mock::connected_handler(&device);
+        app.mark_connected();
+        app.engage_tx_lock();
+        app.byte_pad = Some(tuiserial_core::BytePad::default());
+        let mut areas = get_ui_areas();
+        areas.byte_pad = Rect::new(27, 0, 53, 20);
+        update_ui_areas(areas);
+
+        let left = click(MouseEventKind::Down(MouseButton::Left), 27 + 4 + 3, 2 + 4);
+        handle_mouse_event(left, &mut app, &mut handler, &mut proxy);
+        assert_eq!(app.message_log.tx_count, 1);
+        assert_eq!(app.focused_field, FocusedField::TxInput);
+        assert!(app.tx_focus_locked());
+
+        // Other buttons over the pad stay locked out
+        let middle = click(MouseEventKind::Down(MouseButton::Middle), 27 + 4 + 3, 2 + 4);
+        handle_mouse_event(middle, &mut app, &mut handler, &mut proxy);
+        assert_eq!(app.message_log.entries.len(), 1);
+    }
+}
@@ -0,0 +1,200 @@
+//! Pipe mode (`--stdin`).
+//!
+//! Transmits standard input to a serial port without starting the UI, so
+//! tuiserial can sit at the end of a shell pipeline:
+//! `generator | tuiserial --port /dev/ttyUSB0 --stdin`. Every write is
+//! pushed to the message log as a TX entry and noted in the application
+//! log; the run ends cleanly at EOF.
+
+use std::io::{self, BufRead, Write};
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use tuiserial_core::{AppState, MessageLog};
+use tuiserial_serial::{bytes_to_hex, open_port};
+
+use crate::cli_args::StdinOptions;
+
+/// Largest write in raw (unframed) mode.
+const RAW_CHUNK_SIZE: usize = 256;
+
+/// Totals reported when the pipe reaches EOF.
+#[derive(Debug, Default, PartialEq)]
+pub struct PipeStats {
+    pub bytes: u64,
+    pub writes: u64,
+}
+
+/// Read the next chunk to send, or `None` at EOF.
+///
+/// In line mode a chunk is one line including its `\n` (the last line may
+/// lack one); otherwise it is whatever input is buffered, up to
+/// `RAW_CHUNK_SIZE` bytes.
+pub fn next_chunk<R: BufRead>(reader: &mut R, lines: bool) -> io::Result<Option<Vec<u8>>> {
+    if lines {
+        let mut line = Vec::new();
+        return match reader.read_until(b'\n', &mut line)? {
+            0 => Ok(None),
+            _ => Ok(Some(line)),
+        };
+    }
+
+    let buf = reader.fill_buf()?;
+    if buf.is_empty() {
+        return Ok(None);
+    }
+    let chunk = buf[..buf.len().min(RAW_CHUNK_SIZE)].to_vec();
+    reader.consume(chunk.len());
+    Ok(Some(chunk))
+}
+
+/// Copy `reader` to `writer` chunk by chunk until EOF, calling `pause`
+/// with the configured delay between writes (not before the first). Each
+/// write is pushed to `log` as a TX entry, as interactive sends are.
+pub fn pump<R, W>(
+    reader: &mut R,
+    writer: &mut W,
+    log: &mut MessageLog,
+    opts: &StdinOptions,
+    mut pause: impl FnMut(Duration),
+) -> io::Result<PipeStats>
+where
+    R: BufRead,
+    W: Write + ?Sized,
+{
+    let delay = Duration::from_millis(opts.delay_ms);
+    let mut stats = PipeStats::default();
+
+    while let Some(chunk) = next_chunk(reader, opts.lines)? {
+        if stats.writes > 0 && !delay.is_zero() {
+            pause(delay);
+        }
+        writer.write_all(&chunk)?;
+        writer.flush()?;
+        log::info!("TX {} bytes: {}", chunk.len(), bytes_to_hex(&chunk));
+        stats.bytes += chunk.len() as u64;
+        stats.writes += 1;
+        log.push_tx(chunk);
+    }
+
+    Ok(stats)
+}
+
+/// Open `port` with the saved settings (baud optionally overridden) and
+/// send standard input to it.
+pub fn run_stdin_pipe(port: &str, baud: Option<u32>, opts: &StdinOptions) -> Result<PipeStats> {
+    let mut app = AppState::default();
+    app.load_config();
+    app.config.port = port.to_string();
+    if let Some(baud) = baud {
+        app.config.baud_rate = baud;
+    }
+
+    let mut serial = open_port(&app.config).with_context(|| format!("cannot open {port}"))?;
+    log::info!("Pipe mode: stdin -> {}", app.config.format_display());
+
+    let stdin = io::stdin();
+    let stats = pump(
+        &mut stdin.lock(),
+        &mut serial,
+        &mut app.message_log,
+        opts,
+        std::thread::sleep,
+    )?;
+    log::info!(
+        "Pipe mode finished: {} bytes in {} writes",
+        stats.bytes,
+        stats.writes
+    );
+    Ok(stats)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tuiserial_core::LogDirection;
+
+    fn run(input: &[u8], opts: &StdinOptions) -> (Vec<u8>, PipeStats, Vec<Duration>) {
+        let mut out = Vec::new();
+        let mut pauses = Vec::new();
+        let mut log = MessageLog::new();
+        let stats = pump(&mut &input[..], &mut out, &mut log, opts, |d| {
+            pauses.push(d)
+        })
+        .unwrap();
+        assert_eq!(log.tx_count, stats.writes);
+        assert_eq!(log.tx_bytes, stats.bytes);
+        (out, stats, pauses)
+    }
+
+    #[test]
+    fn test_line_framing() {
+        let mut input: &[u8] = b"AT\r\nAT+GMR\nlast";
+        assert_eq!(
+            next_chunk(&mut input, true).unwrap(),
+            Some(b"AT\r\n".to_vec())
+        );
+        assert_eq!(
+            next_chunk(&mut input, true).unwrap(),
+            Some(b"AT+GMR\n".to_vec())
+        );
+        assert_eq!(
+            next_chunk(&mut input, true).unwrap(),
+            Some(b"last".to_vec())
+        );
+        assert_eq!(next_chunk(&mut input, true).unwrap(), None);
+    }
+
+    #[test]
+    fn test_raw_chunks_capped() {
+        let data = vec![0x55; RAW_CHUNK_SIZE * 2 + 10];
+        let (out, stats, _) = run(&data, &StdinOptions::default());
+        assert_eq!(out, data);
+        assert_eq!(stats.writes, 3);
+        assert_eq!(stats.bytes, data.len() as u64);
+    }
+
+    #[test]
+    fn test_delay_between_writes_only() {
+        let opts = StdinOptions {
+            lines: true,
+            delay_ms: 20,
+        };
+        let (out, stats, pauses) = run(b"a\nb\nc\n", &opts);
+        assert_eq!(out, b"a\nb\nc\n");
+        assert_eq!(stats.writes, 3);
+        assert_eq!(pauses, vec![Duration::from_millis(20); 2]);
+    }
+
+    #[test]
+    fn test_empty_input_sends_nothing() {
+        let (out, stats, pauses) = run(b"", &StdinOptions::default());
+        assert!(out.is_empty());
+        assert_eq!(stats, PipeStats::default());
+        assert!(pauses.is_empty());
+    }
+
+    #[test]
+    fn test_writes_logged_as_tx_entries() {
+        let opts = StdinOptions {
+            lines: true,
+            delay_ms: 0,
+        };
+        let mut out = Vec::new();
+        let mut log = MessageLog::new();
+        pump(&mut &b"AT\nATI\n"[..], &mut out, &mut log, &opts, |_| {}).unwrap();
+
+        let sent: Vec<_> = log
+            .entries
+            .iter()
+            .map(|e| (e.direction, &e.data[..]))
+            .collect();
+        assert_eq!(
+            sent,
+            vec![
+                (LogDirection::Tx, &b"AT\n"[..]),
+                (LogDirection::Tx, &b"ATI\n"[..]),
+            ]
+        );
+    }
+}
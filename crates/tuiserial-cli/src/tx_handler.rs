@@ -1,8 +1,15 @@
 //! TX input keyboard handler — handles key events when the focus is on the TX input field.
 
+use std::io;
+use std::time::Instant;
+
 use crossterm::event::{KeyCode, KeyEvent, KeyEventKind};
 use rust_i18n::t;
-use tuiserial_core::{AppState, TxMode};
+use tuiserial_core::{
+    AppSettings, AppState, AppendMode, FramingMode, PasteAppend, PendingSend, RepeatPrompt,
+    RepeatSend, SendOrigin, ShortcutAction, TxMode,
+};
+use tuiserial_serial::{SerialError, slip_encode};
 
 use crate::handler::SerialHandler;
 use crate::input_utils::rebuild_hex_input;
@@ -20,60 +27,37 @@ pub fn handle_tx_key_event(
         return false;
     }
 
+    if app.key_bindings.action(&key) == Some(ShortcutAction::RepeatSend) {
+        toggle_repeat(app, handler);
+        return false;
+    }
+
     match key.code {
         KeyCode::Tab => {
+            app.release_tx_lock();
             app.focus_next_field();
             false
         }
         KeyCode::BackTab => {
+            app.release_tx_lock();
             app.focus_prev_field();
             false
         }
         KeyCode::Char(c) => {
+            app.engage_tx_lock();
             if app.tx_mode == TxMode::Hex {
-                match c {
-                    '0'..='9' | 'a'..='f' | 'A'..='F' => {
-                        let upper = c.to_ascii_uppercase();
-                        let byte_idx = app
-                            .tx_input
-                            .char_indices()
-                            .nth(app.tx_cursor)
-                            .map(|(i, _)| i)
-                            .unwrap_or(app.tx_input.len());
-                        app.tx_input.insert(byte_idx, upper);
-                        app.tx_cursor += 1;
-                        rebuild_hex_input(app);
-                    }
-                    ' ' => {}
-                    _ => {}
+                if c.is_ascii_hexdigit() {
+                    app.tx_input.insert_char(c.to_ascii_uppercase());
+                    rebuild_hex_input(app);
                 }
             } else {
-                let byte_idx = app
-                    .tx_input
-                    .char_indices()
-                    .nth(app.tx_cursor)
-                    .map(|(i, _)| i)
-                    .unwrap_or(app.tx_input.len());
-                app.tx_input.insert(byte_idx, c);
-                app.tx_cursor += 1;
+                app.tx_input.insert_char(c);
             }
             false
         }
         KeyCode::Backspace => {
-            if app.tx_cursor > 0 {
-                let byte_idx = app
-                    .tx_input
-                    .char_indices()
-                    .nth(app.tx_cursor - 1)
-                    .map(|(i, _)| i)
-                    .unwrap_or(0);
-                if byte_idx < app.tx_input.len() {
-                    app.tx_input.remove(byte_idx);
-                }
-                app.tx_cursor -= 1;
-                if app.tx_mode == TxMode::Hex {
-                    rebuild_hex_input(app);
-                }
+            if app.tx_input.delete_before() && app.tx_mode == TxMode::Hex {
+                rebuild_hex_input(app);
             }
             false
         }
@@ -102,110 +86,917 @@ pub fn handle_tx_key_event(
             false
         }
         KeyCode::Delete => {
-            let char_count = app.tx_input.chars().count();
-            if app.tx_cursor < char_count {
-                let byte_idx = app
-                    .tx_input
-                    .char_indices()
-                    .nth(app.tx_cursor)
-                    .map(|(i, _)| i)
-                    .unwrap_or(app.tx_input.len());
-                if byte_idx < app.tx_input.len() {
-                    app.tx_input.remove(byte_idx);
-                }
-                if app.tx_mode == TxMode::Hex {
-                    rebuild_hex_input(app);
-                }
+            if app.tx_input.delete_after() && app.tx_mode == TxMode::Hex {
+                rebuild_hex_input(app);
             }
             false
         }
         KeyCode::Left => {
-            if app.tx_cursor > 0 {
-                app.tx_cursor -= 1;
-            }
+            app.tx_input.move_left();
             false
         }
         KeyCode::Right => {
-            let char_count = app.tx_input.chars().count();
-            if app.tx_cursor < char_count {
-                app.tx_cursor += 1;
-            }
+            app.tx_input.move_right();
             false
         }
         KeyCode::Home => {
-            app.tx_cursor = 0;
+            app.tx_input.move_home();
             false
         }
         KeyCode::End => {
-            app.tx_cursor = app.tx_input.chars().count();
+            app.tx_input.move_end();
             false
         }
         KeyCode::Enter => {
-            if !app.tx_input.is_empty() {
-                if handler.is_connected() {
-                    let mut bytes: Result<Vec<u8>, tuiserial_serial::SerialError> =
-                        match app.tx_mode {
-                            TxMode::Ascii => Ok(app.tx_input.as_bytes().to_vec()),
-                            TxMode::Hex => tuiserial_serial::hex_to_bytes(&app.tx_input),
-                        };
+            send_tx_input(app, handler, plugin_proxy);
+            false
+        }
+        KeyCode::Esc => {
+            // With the focus lock engaged the first Esc only releases it,
+            // keeping the text being composed
+            if app.release_tx_lock() {
+                app.add_info(t!("notify.tx_lock_released").to_string());
+            } else {
+                app.tx_input.clear();
+            }
+            false
+        }
+        _ => false,
+    }
+}
 
-                    if let Ok(ref mut data) = bytes {
-                        data.extend_from_slice(app.tx_append_mode.as_bytes());
-                    }
+/// Encode the TX input according to the current TX mode, wrap it in the
+/// configured prefix/suffix and append the line terminator. ASCII input has
+/// its `{{type:value}}` integer tokens expanded. Input holding line breaks
+/// (a bracketed paste) is sent like a clipboard paste, following
+/// `settings.paste_append`.
+fn encode_tx_input(app: &AppState) -> Result<Vec<u8>, SerialError> {
+    let text = app.tx_input.as_str();
+    if text.contains(['\n', '\r'])
+        && let Some(data) = pasted_payload(text, app)?
+    {
+        return Ok(data);
+    }
+    encode_tx_text(text, app.tx_mode, app.tx_append_mode, &app.settings)
+}
 
-                    match bytes {
-                        Ok(data) => {
-                            let (processed, suppressed) =
-                                plugin_proxy.process_tx(data, &app.config);
-
-                            if suppressed {
-                                app.add_info("TX suppressed by plugin".to_string());
-                                app.tx_input.clear();
-                                app.tx_cursor = 0;
-                                return false;
-                            }
-                            match handler.send(&processed) {
-                                Ok(_sent) => {
-                                    app.message_log.push_tx(processed.clone());
-                                    let append_info = if app.tx_append_mode.as_bytes().is_empty() {
-                                        String::new()
-                                    } else {
-                                        format!(" + {}", app.tx_append_mode.name())
-                                    };
-                                    app.add_success(format!(
-                                        "{}{}",
-                                        t!("notify.send_success"),
-                                        append_info
-                                    ));
-                                    app.tx_input.clear();
-                                    app.tx_cursor = 0;
-                                    if app.auto_scroll {
-                                        let lines_count = app.message_log.entries.len() as u16;
-                                        app.scroll_offset = lines_count.saturating_sub(1);
-                                    }
-                                }
-                                Err(e) => {
-                                    app.add_error(format!("{}: {}", t!("notify.send_failed"), e));
-                                }
-                            }
-                        }
-                        Err(e) => {
-                            app.add_error(format!("{}: {}", t!("notify.hex_format_error"), e));
-                        }
+/// Encode `text` as `mode` input and build the bytes to send with
+/// [`build_tx_bytes`].
+fn encode_tx_text(
+    text: &str,
+    mode: TxMode,
+    append: AppendMode,
+    settings: &AppSettings,
+) -> Result<Vec<u8>, SerialError> {
+    let payload = match mode {
+        TxMode::Ascii => tuiserial_serial::expand_tx_tokens(text)?,
+        TxMode::Hex => tuiserial_serial::hex_to_bytes(text)?,
+    };
+    Ok(build_tx_bytes(
+        &payload,
+        &settings.tx_prefix,
+        &settings.tx_suffix,
+        append,
+    ))
+}
+
+/// `prefix`, `payload`, `suffix`, then `append`'s terminator — the
+/// terminator always ends the transmission, after the suffix.
+fn build_tx_bytes(payload: &[u8], prefix: &[u8], suffix: &[u8], append: AppendMode) -> Vec<u8> {
+    let terminator = append.as_bytes();
+    let mut data =
+        Vec::with_capacity(prefix.len() + payload.len() + suffix.len() + terminator.len());
+    data.extend_from_slice(prefix);
+    data.extend_from_slice(payload);
+    data.extend_from_slice(suffix);
+    data.extend_from_slice(terminator);
+    data
+}
+
+/// Bytes to send for pasted `content` (the clipboard, or a multi-line
+/// bracketed paste in the TX input), encoded like the TX input.
+///
+/// One trailing line break is dropped (text copied from documentation
+/// usually ends with one) so the append mode alone decides the terminator.
+/// `settings.paste_append` decides where the terminator goes: nowhere, once
+/// at the end, or after every line (the pasted line breaks are replaced).
+/// Returns `Ok(None)` when nothing is left to send.
+fn pasted_payload(content: &str, app: &AppState) -> Result<Option<Vec<u8>>, SerialError> {
+    let text = content
+        .strip_suffix('\n')
+        .map(|t| t.strip_suffix('\r').unwrap_or(t))
+        .unwrap_or(content);
+    if text.trim().is_empty() {
+        return Ok(None);
+    }
+
+    let append = match app.settings.paste_append {
+        PasteAppend::Never => AppendMode::None,
+        PasteAppend::Once | PasteAppend::EachLine => app.tx_append_mode,
+    };
+    if app.settings.paste_append == PasteAppend::EachLine {
+        let mut data = Vec::new();
+        for line in text.lines() {
+            data.extend(encode_tx_text(line, app.tx_mode, append, &app.settings)?);
+        }
+        return Ok(Some(data));
+    }
+    if app.tx_mode == TxMode::Hex {
+        // Hex dumps are often copied across several lines
+        let joined = text.split_whitespace().collect::<Vec<_>>().join(" ");
+        return encode_tx_text(&joined, TxMode::Hex, append, &app.settings).map(Some);
+    }
+    encode_tx_text(text, app.tx_mode, append, &app.settings).map(Some)
+}
+
+/// Ctrl+Shift+V — send the clipboard contents directly, bypassing (and
+/// leaving untouched) the TX input box.
+pub fn send_clipboard(
+    app: &mut AppState,
+    handler: &mut SerialHandler,
+    plugin_proxy: &mut PluginProxy,
+    read: impl FnOnce() -> io::Result<String>,
+) {
+    if !handler.is_connected() {
+        app.add_error(t!("notify.not_connected").to_string());
+        return;
+    }
+
+    let content = match read() {
+        Ok(content) => content,
+        Err(e) => {
+            app.add_error(format!("{}: {}", t!("notify.clipboard_unavailable"), e));
+            return;
+        }
+    };
+    let data = match pasted_payload(&content, app) {
+        Ok(Some(data)) => data,
+        Ok(None) => {
+            app.add_warning(t!("notify.clipboard_empty").to_string());
+            return;
+        }
+        Err(e) => {
+            report_encode_error(app, e);
+            return;
+        }
+    };
+
+    if app.settings.needs_send_confirmation(data.len()) {
+        app.pending_send = Some(PendingSend {
+            data,
+            origin: SendOrigin::Clipboard,
+            mode: app.tx_mode,
+        });
+        return;
+    }
+    let mode = app.tx_mode;
+    send_pasted_payload(app, handler, plugin_proxy, data, mode);
+}
+
+/// Transmit a clipboard payload encoded in `mode` and report the outcome.
+fn send_pasted_payload(
+    app: &mut AppState,
+    handler: &mut SerialHandler,
+    plugin_proxy: &mut PluginProxy,
+    data: Vec<u8>,
+    mode: TxMode,
+) {
+    let len = data.len();
+    match transmit(app, handler, plugin_proxy, data, Some(mode)) {
+        Ok(true) => app.add_success(t!("notify.clipboard_sent", bytes = len).to_string()),
+        Ok(false) => app.add_info("TX suppressed by plugin".to_string()),
+        Err(e) => app.add_error(format!("{}: {}", t!("notify.send_failed"), e)),
+    }
+}
+
+/// Settings → Set TX Prefix/Suffix from Input: the TX input, encoded in the
+/// current mode, becomes the prefix (or suffix) of every send. An empty
+/// input clears it.
+pub fn set_tx_affix_from_input(app: &mut AppState, suffix: bool) {
+    let bytes = match app.tx_mode {
+        TxMode::Ascii => {
+            tuiserial_serial::expand_tx_tokens(app.tx_input.as_str()).map_err(SerialError::from)
+        }
+        TxMode::Hex => tuiserial_serial::hex_to_bytes(app.tx_input.as_str()),
+    };
+    let bytes = match bytes {
+        Ok(bytes) => bytes,
+        Err(e) => return report_encode_error(app, e),
+    };
+    let (label, shown) = (
+        if suffix {
+            t!("tx.suffix")
+        } else {
+            t!("tx.prefix")
+        },
+        if bytes.is_empty() {
+            t!("notify.tx_affix_cleared").to_string()
+        } else {
+            tuiserial_serial::bytes_to_hex(&bytes)
+        },
+    );
+    if suffix {
+        app.settings.tx_suffix = bytes;
+    } else {
+        app.settings.tx_prefix = bytes;
+    }
+    app.add_info(format!("{label}: {shown}"));
+}
+
+/// Notify the user that the TX input could not be encoded.
+fn report_encode_error(app: &mut AppState, e: SerialError) {
+    match e {
+        SerialError::Token(e) => app.add_error(format!("{}: {}", t!("notify.tx_token_error"), e)),
+        e => app.add_error(format!("{}: {}", t!("notify.hex_format_error"), e)),
+    }
+}
+
+/// Send a single byte clicked in the byte pad and highlight it there.
+pub fn send_pad_byte(
+    app: &mut AppState,
+    handler: &mut SerialHandler,
+    plugin_proxy: &mut PluginProxy,
+    byte: u8,
+) {
+    if !handler.is_connected() {
+        app.add_error(t!("notify.not_connected").to_string());
+        return;
+    }
+    // A raw byte has no typed mode; it is shown in the log's display mode
+    match transmit(app, handler, plugin_proxy, vec![byte], None) {
+        Ok(true) => {
+            if let Some(pad) = app.byte_pad.as_mut() {
+                pad.last_sent = Some(byte);
+            }
+        }
+        Ok(false) => app.add_info("TX suppressed by plugin".to_string()),
+        Err(e) => app.add_error(format!("{}: {}", t!("notify.send_failed"), e)),
+    }
+}
+
+/// Run `data` through TX plugins, write it to the port and log it, tagged
+/// with the `mode` it was encoded in if any. Returns `Ok(false)` if a
+/// plugin suppressed the send.
+pub fn transmit(
+    app: &mut AppState,
+    handler: &mut SerialHandler,
+    plugin_proxy: &mut PluginProxy,
+    data: Vec<u8>,
+    mode: Option<TxMode>,
+) -> Result<bool, SerialError> {
+    let (processed, suppressed) = plugin_proxy.process_tx(data, app.active_config());
+    if suppressed {
+        return Ok(false);
+    }
+    let wire = match app.settings.framing {
+        FramingMode::Raw => None,
+        FramingMode::Slip => Some(slip_encode(&processed)),
+    };
+    let sent = handler.send(wire.as_deref().unwrap_or(&processed));
+    app.link_quality.record(sent.is_ok());
+    let sent = sent?;
+    app.tx_rate.record(sent, Instant::now());
+    match mode {
+        Some(mode) => app.message_log.push_tx_as(processed, mode),
+        None => app.message_log.push_tx(processed),
+    }
+    if app.auto_scroll {
+        let lines_count = app.message_log.entries.len() as u16;
+        app.scroll_offset = lines_count.saturating_sub(1);
+    }
+    Ok(true)
+}
+
+/// Send the current TX input once (Enter).
+fn send_tx_input(app: &mut AppState, handler: &mut SerialHandler, plugin_proxy: &mut PluginProxy) {
+    if app.tx_input.is_empty() {
+        app.add_warning(t!("notify.input_empty").to_string());
+        return;
+    }
+    if !handler.is_connected() {
+        app.add_error(t!("notify.not_connected").to_string());
+        return;
+    }
+
+    let data = match encode_tx_input(app) {
+        Ok(data) => data,
+        Err(e) => {
+            report_encode_error(app, e);
+            return;
+        }
+    };
+
+    if app.settings.needs_send_confirmation(data.len()) {
+        app.pending_send = Some(PendingSend {
+            data,
+            origin: SendOrigin::Input,
+            mode: app.tx_mode,
+        });
+        return;
+    }
+    let mode = app.tx_mode;
+    send_input_payload(app, handler, plugin_proxy, data, mode);
+}
+
+/// Transmit the encoded TX input, clearing the input box unless the write
+/// failed.
+fn send_input_payload(
+    app: &mut AppState,
+    handler: &mut SerialHandler,
+    plugin_proxy: &mut PluginProxy,
+    data: Vec<u8>,
+    mode: TxMode,
+) {
+    match transmit(app, handler, plugin_proxy, data, Some(mode)) {
+        Ok(true) => {
+            let append_info = if app.tx_append_mode.as_bytes().is_empty() {
+                String::new()
+            } else {
+                format!(" + {}", app.tx_append_mode.name())
+            };
+            app.add_success(format!("{}{}", t!("notify.send_success"), append_info));
+            app.tx_input.clear();
+        }
+        Ok(false) => {
+            app.add_info("TX suppressed by plugin".to_string());
+            app.tx_input.clear();
+        }
+        Err(e) => {
+            app.add_error(format!("{}: {}", t!("notify.send_failed"), e));
+        }
+    }
+}
+
+/// Handle keys while a large send waits for confirmation: Enter/y sends
+/// it, Esc/n drops it.
+pub fn handle_send_confirm_key(
+    key: KeyEvent,
+    app: &mut AppState,
+    handler: &mut SerialHandler,
+    plugin_proxy: &mut PluginProxy,
+) -> bool {
+    match key.code {
+        KeyCode::Enter | KeyCode::Char('y') | KeyCode::Char('Y') => {
+            if let Some(pending) = app.pending_send.take() {
+                match pending.origin {
+                    SendOrigin::Input => {
+                        send_input_payload(app, handler, plugin_proxy, pending.data, pending.mode)
+                    }
+                    SendOrigin::Clipboard => {
+                        send_pasted_payload(app, handler, plugin_proxy, pending.data, pending.mode)
+                    }
+                    SendOrigin::Repeat { count, interval } => {
+                        let now = Instant::now();
+                        let mut repeat = match count {
+                            Some(count) => RepeatSend::bounded(pending.data, count, interval, now),
+                            None => RepeatSend::indefinite(pending.data, interval, now),
+                        };
+                        repeat.mode = Some(pending.mode);
+                        begin_repeat(app, repeat);
                     }
-                } else {
-                    app.add_error(t!("notify.not_connected").to_string());
                 }
-            } else {
-                app.add_warning(t!("notify.input_empty").to_string());
             }
-            false
         }
+        KeyCode::Esc | KeyCode::Char('n') | KeyCode::Char('N') => {
+            app.pending_send = None;
+            app.add_info(t!("notify.send_cancelled").to_string());
+        }
+        _ => {}
+    }
+    false
+}
+
+/// Ctrl+R — stop the running repeat, or open the count/interval prompt.
+fn toggle_repeat(app: &mut AppState, handler: &SerialHandler) {
+    if let Some(stopped) = app.cancel_repeat() {
+        app.add_info(t!("notify.repeat_stopped", progress = stopped.progress()).to_string());
+        return;
+    }
+    if app.tx_input.is_empty() {
+        app.add_warning(t!("notify.input_empty").to_string());
+    } else if !handler.is_connected() {
+        app.add_error(t!("notify.not_connected").to_string());
+    } else {
+        app.repeat_prompt = Some(RepeatPrompt::default());
+    }
+}
+
+/// Handle key events while the repeat count/interval prompt is open.
+pub fn handle_repeat_prompt_key(key: KeyEvent, app: &mut AppState) -> bool {
+    let Some(prompt) = app.repeat_prompt.as_mut() else {
+        return false;
+    };
+
+    match key.code {
         KeyCode::Esc => {
-            app.tx_input.clear();
-            app.tx_cursor = 0;
-            false
+            app.repeat_prompt = None;
         }
-        _ => false,
+        KeyCode::Tab | KeyCode::BackTab | KeyCode::Up | KeyCode::Down => prompt.toggle_field(),
+        KeyCode::Backspace => prompt.backspace(),
+        KeyCode::Char(c) => prompt.push_digit(c),
+        KeyCode::Enter => {
+            if prompt.parse().is_none() {
+                app.add_warning(t!("notify.repeat_invalid").to_string());
+                return false;
+            }
+            let Some(prompt) = app.repeat_prompt.take() else {
+                return false;
+            };
+            match encode_tx_input(app) {
+                Ok(data) => {
+                    let Some(repeat) = prompt.build(data, app.tx_mode, Instant::now()) else {
+                        return false;
+                    };
+                    // Checked once per repeat: every send carries this payload
+                    if app.settings.needs_send_confirmation(repeat.payload.len()) {
+                        app.pending_send = Some(PendingSend {
+                            origin: SendOrigin::Repeat {
+                                count: repeat.total,
+                                interval: repeat.interval,
+                            },
+                            data: repeat.payload,
+                            mode: app.tx_mode,
+                        });
+                    } else {
+                        begin_repeat(app, repeat);
+                    }
+                }
+                Err(e) => report_encode_error(app, e),
+            }
+        }
+        _ => {}
+    }
+    false
+}
+
+/// Start `repeat` and announce it
+fn begin_repeat(app: &mut AppState, repeat: RepeatSend) {
+    let interval = repeat.interval.as_millis();
+    let message = match repeat.total {
+        Some(count) => t!("notify.repeat_started", count = count, interval = interval),
+        None => t!("notify.repeat_started_indefinite", interval = interval),
+    };
+    app.start_repeat(repeat);
+    app.add_info(message.to_string());
+}
+
+/// Drive the repeat scheduler: send the payload if it is due and stop when
+/// a bounded repeat completes or a send fails. Called once per event loop.
+pub fn tick_repeat(
+    app: &mut AppState,
+    handler: &mut SerialHandler,
+    plugin_proxy: &mut PluginProxy,
+) {
+    let now = Instant::now();
+    let Some(payload) = app.due_repeat_payload(now) else {
+        return;
+    };
+
+    if !handler.is_connected() {
+        if let Some(stopped) = app.cancel_repeat() {
+            app.add_warning(t!("notify.repeat_stopped", progress = stopped.progress()).to_string());
+        }
+        return;
+    }
+
+    let mode = app.repeat_send.as_ref().and_then(|r| r.mode);
+    match transmit(app, handler, plugin_proxy, payload, mode) {
+        Ok(false) => app.record_repeat_skipped(now),
+        Ok(true) => {
+            if let Some(done) = app.record_repeat_sent(now) {
+                app.add_success(t!("notify.repeat_done", progress = done.progress()).to_string());
+            }
+        }
+        Err(e) => {
+            let progress = app
+                .cancel_repeat()
+                .map(|r| r.progress())
+                .unwrap_or_default();
+            app.add_error(format!(
+                "{}: {} ({})",
+                t!("notify.send_failed"),
+                e,
+                t!("notify.repeat_stopped", progress = progress)
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handler::mock::{MockDevice, connected_handler};
+    use crossterm::event::KeyModifiers;
+    use tuiserial_core::{FocusedField, TextInput};
+
+    fn press(app: &mut AppState, code: KeyCode) {
+        let mut handler = SerialHandler::new();
+        let mut proxy = PluginProxy::init(app);
+        handle_tx_key_event(
+            KeyEvent::new(code, KeyModifiers::NONE),
+            app,
+            &mut handler,
+            &mut proxy,
+        );
+    }
+
+    fn ascii_app(input: &str, cursor: usize) -> AppState {
+        AppState {
+            tx_mode: TxMode::Ascii,
+            tx_input: TextInput::from(input).with_cursor(cursor),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_insert_between_multibyte_chars() {
+        let mut app = ascii_app("你好", 1);
+        press(&mut app, KeyCode::Char('x'));
+        assert_eq!(app.tx_input, "你x好");
+        assert_eq!(app.tx_input.cursor(), 2);
+        press(&mut app, KeyCode::Char('世'));
+        assert_eq!(app.tx_input, "你x世好");
+        assert_eq!(app.tx_input.cursor(), 3);
+    }
+
+    #[test]
+    fn test_backspace_and_delete_multibyte() {
+        let mut app = ascii_app("a你🙂b", 3);
+        press(&mut app, KeyCode::Backspace);
+        assert_eq!(app.tx_input, "a你b");
+        assert_eq!(app.tx_input.cursor(), 2);
+
+        press(&mut app, KeyCode::Left);
+        press(&mut app, KeyCode::Delete);
+        assert_eq!(app.tx_input, "ab");
+        assert_eq!(app.tx_input.cursor(), 1);
+    }
+
+    #[test]
+    fn test_cursor_movement_stays_in_bounds() {
+        let mut app = ascii_app("日本", 0);
+        press(&mut app, KeyCode::Left);
+        assert_eq!(app.tx_input.cursor(), 0);
+        press(&mut app, KeyCode::End);
+        assert_eq!(app.tx_input.cursor(), 2);
+        press(&mut app, KeyCode::Right);
+        assert_eq!(app.tx_input.cursor(), 2);
+        press(&mut app, KeyCode::Backspace);
+        press(&mut app, KeyCode::Backspace);
+        press(&mut app, KeyCode::Backspace);
+        assert!(app.tx_input.is_empty());
+        assert_eq!(app.tx_input.cursor(), 0);
+    }
+
+    #[test]
+    fn test_paste_multibyte_at_cursor() {
+        let mut app = ascii_app("ab", 1);
+        app.focused_field = tuiserial_core::FocusedField::TxInput;
+        crate::input_utils::handle_paste_event("中文", &mut app);
+        assert_eq!(app.tx_input, "a中文b");
+        assert_eq!(app.tx_input.cursor(), 3);
+    }
+
+    #[test]
+    fn test_tx_focus_lock_engages_on_typing_and_esc_releases() {
+        let mut app = ascii_app("", 0);
+        app.focused_field = FocusedField::TxInput;
+        press(&mut app, KeyCode::Char('A'));
+        assert!(!app.tx_focus_locked());
+
+        app.settings.tx_focus_lock = true;
+        press(&mut app, KeyCode::Char('T'));
+        assert!(app.tx_focus_locked());
+
+        // First Esc releases the lock and keeps the text, the next clears
+        press(&mut app, KeyCode::Esc);
+        assert!(!app.tx_focus_locked());
+        assert_eq!(app.tx_input, "AT");
+        press(&mut app, KeyCode::Esc);
+        assert_eq!(app.tx_input, "");
+
+        press(&mut app, KeyCode::Char('A'));
+        press(&mut app, KeyCode::Tab);
+        assert!(!app.tx_lock_engaged);
+        assert_ne!(app.focused_field, FocusedField::TxInput);
+    }
+
+    #[test]
+    fn test_encode_expands_tokens_in_ascii_mode() {
+        let app = AppState {
+            tx_append_mode: tuiserial_core::AppendMode::CR,
+            ..ascii_app("V{{u16be:0x0102}}", 0)
+        };
+        assert_eq!(encode_tx_input(&app).unwrap(), vec![b'V', 0x01, 0x02, 0x0D]);
+
+        let bad = ascii_app("{{u16le:70000}}", 0);
+        assert!(matches!(encode_tx_input(&bad), Err(SerialError::Token(_))));
+    }
+
+    #[test]
+    fn test_encode_sends_escaped_literal_braces() {
+        let app = ascii_app("{{{{x}}", 0);
+        assert_eq!(encode_tx_input(&app).unwrap(), b"{{x}}");
+    }
+
+    #[test]
+    fn test_set_affix_from_input() {
+        let mut app = ascii_app("AT+", 0);
+        set_tx_affix_from_input(&mut app, false);
+        assert_eq!(app.settings.tx_prefix, b"AT+");
+
+        app.tx_mode = TxMode::Hex;
+        app.tx_input = TextInput::from("03");
+        set_tx_affix_from_input(&mut app, true);
+        assert_eq!(app.settings.tx_suffix, vec![0x03]);
+
+        // Bad hex leaves the suffix alone; an empty input clears it
+        app.tx_input = TextInput::from("zz");
+        set_tx_affix_from_input(&mut app, true);
+        assert_eq!(app.settings.tx_suffix, vec![0x03]);
+        app.tx_input = TextInput::from("");
+        set_tx_affix_from_input(&mut app, true);
+        assert!(app.settings.tx_suffix.is_empty());
+        assert_eq!(app.settings.tx_prefix, b"AT+");
+    }
+
+    #[test]
+    fn test_prefix_and_suffix_bracket_payload() {
+        assert_eq!(
+            build_tx_bytes(b"GMR", b"AT+", b"", AppendMode::CRLF),
+            b"AT+GMR\r\n"
+        );
+        // The terminator comes after the suffix
+        assert_eq!(
+            build_tx_bytes(&[0x10], &[0x02, 0x01], &[0x03], AppendMode::CR),
+            vec![0x02, 0x01, 0x10, 0x03, 0x0D]
+        );
+        assert_eq!(build_tx_bytes(b"x", b"", b"", AppendMode::None), b"x");
+
+        let mut app = AppState {
+            tx_append_mode: AppendMode::LF,
+            ..ascii_app("RST", 0)
+        };
+        app.settings.tx_prefix = b"AT+".to_vec();
+        app.settings.tx_suffix = b";".to_vec();
+        assert_eq!(encode_tx_input(&app).unwrap(), b"AT+RST;\n");
+
+        // Each pasted line is wrapped when the terminator goes after every line
+        app.settings.paste_append = PasteAppend::EachLine;
+        assert_eq!(
+            pasted_payload("A\nB\n", &app).unwrap(),
+            Some(b"AT+A;\nAT+B;\n".to_vec())
+        );
+    }
+
+    #[test]
+    fn test_pasted_payload_uses_mode_and_append() {
+        let app = AppState {
+            tx_append_mode: AppendMode::CRLF,
+            ..ascii_app("untouched", 0)
+        };
+        assert_eq!(
+            pasted_payload("AT+RST\n", &app).unwrap(),
+            Some(b"AT+RST\r\n".to_vec())
+        );
+        assert_eq!(
+            pasted_payload("AT\r\n", &app).unwrap(),
+            Some(b"AT\r\n".to_vec())
+        );
+        // Only one trailing line break is dropped
+        assert_eq!(
+            pasted_payload("a\n\n", &app).unwrap(),
+            Some(b"a\n\r\n".to_vec())
+        );
+
+        let hex = AppState {
+            tx_mode: TxMode::Hex,
+            ..Default::default()
+        };
+        assert_eq!(
+            pasted_payload("de ad\nbe ef\n", &hex).unwrap(),
+            Some(vec![0xDE, 0xAD, 0xBE, 0xEF])
+        );
+        assert!(pasted_payload("GG", &hex).is_err());
+    }
+
+    #[test]
+    fn test_pasted_payload_append_placement() {
+        let mut app = AppState {
+            tx_append_mode: AppendMode::CRLF,
+            ..ascii_app("", 0)
+        };
+        let script = "AT\nAT+GMR\r\nAT+RST\n";
+
+        // Once (default): the pasted line breaks stay, one terminator at the end
+        assert_eq!(
+            pasted_payload(script, &app).unwrap(),
+            Some(b"AT\nAT+GMR\r\nAT+RST\r\n".to_vec())
+        );
+
+        app.settings.paste_append = PasteAppend::EachLine;
+        assert_eq!(
+            pasted_payload("AT", &app).unwrap(),
+            Some(b"AT\r\n".to_vec())
+        );
+        assert_eq!(
+            pasted_payload(script, &app).unwrap(),
+            Some(b"AT\r\nAT+GMR\r\nAT+RST\r\n".to_vec())
+        );
+
+        app.settings.paste_append = PasteAppend::Never;
+        assert_eq!(pasted_payload("AT\n", &app).unwrap(), Some(b"AT".to_vec()));
+        assert_eq!(
+            pasted_payload(script, &app).unwrap(),
+            Some(b"AT\nAT+GMR\r\nAT+RST".to_vec())
+        );
+    }
+
+    #[test]
+    fn test_multi_line_input_paste_follows_paste_append() {
+        let mut app = AppState {
+            tx_append_mode: AppendMode::CRLF,
+            focused_field: tuiserial_core::FocusedField::TxInput,
+            ..ascii_app("", 0)
+        };
+        crate::input_utils::handle_paste_event("AT\nAT+GMR\n", &mut app);
+        assert_eq!(app.tx_input, "AT\nAT+GMR\n");
+        assert_eq!(encode_tx_input(&app).unwrap(), b"AT\nAT+GMR\r\n");
+
+        app.settings.paste_append = PasteAppend::EachLine;
+        assert_eq!(encode_tx_input(&app).unwrap(), b"AT\r\nAT+GMR\r\n");
+
+        app.settings.paste_append = PasteAppend::Never;
+        assert_eq!(encode_tx_input(&app).unwrap(), b"AT\nAT+GMR");
+
+        // Single-line input keeps the append mode whatever `paste_append` says
+        app.tx_input = "AT".into();
+        assert_eq!(encode_tx_input(&app).unwrap(), b"AT\r\n");
+    }
+
+    #[test]
+    fn test_pasted_payload_hex_each_line() {
+        let mut app = AppState {
+            tx_mode: TxMode::Hex,
+            tx_append_mode: AppendMode::LF,
+            ..ascii_app("", 0)
+        };
+        app.settings.paste_append = PasteAppend::EachLine;
+        assert_eq!(
+            pasted_payload("01 02\n03\n", &app).unwrap(),
+            Some(vec![0x01, 0x02, 0x0A, 0x03, 0x0A])
+        );
+    }
+
+    #[test]
+    fn test_pasted_payload_empty() {
+        let app = ascii_app("", 0);
+        assert_eq!(pasted_payload("", &app).unwrap(), None);
+        assert_eq!(pasted_payload(" \r\n", &app).unwrap(), None);
+    }
+
+    #[test]
+    fn test_send_clipboard_requires_connection() {
+        let mut app = ascii_app("keep", 4);
+        let mut handler = SerialHandler::new();
+        let mut proxy = PluginProxy::init(&mut app);
+        let mut read = false;
+        send_clipboard(&mut app, &mut handler, &mut proxy, || {
+            read = true;
+            Ok("AT".into())
+        });
+        assert!(!read);
+        assert_eq!(app.tx_input, "keep");
+        assert_eq!(app.message_log.tx_count, 0);
+    }
+
+    #[test]
+    fn test_large_send_waits_for_confirmation() {
+        let device = MockDevice::default();
+        let mut handler = connected_handler(&device);
+        let mut app = ascii_app(&"A".repeat(16), 16);
+        let mut proxy = PluginProxy::init(&mut app);
+        app.settings.confirm_large_sends = true;
+        app.settings.large_send_threshold = 8;
+
+        send_tx_input(&mut app, &mut handler, &mut proxy);
+        assert_eq!(app.message_log.tx_count, 0);
+        assert_eq!(app.pending_send.as_ref().map(|p| p.data.len()), Some(16));
+        assert_eq!(app.tx_input.as_str().len(), 16);
+
+        let enter = KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE);
+        handle_send_confirm_key(enter, &mut app, &mut handler, &mut proxy);
+        assert_eq!(app.pending_send, None);
+        assert_eq!(app.message_log.tx_count, 1);
+        assert!(app.tx_input.is_empty());
+    }
+
+    #[test]
+    fn test_small_send_goes_directly() {
+        let device = MockDevice::default();
+        let mut handler = connected_handler(&device);
+        let mut app = ascii_app("AT", 2);
+        let mut proxy = PluginProxy::init(&mut app);
+        app.settings.confirm_large_sends = true;
+        app.settings.large_send_threshold = 8;
+
+        send_tx_input(&mut app, &mut handler, &mut proxy);
+        assert_eq!(app.pending_send, None);
+        assert_eq!(app.message_log.tx_count, 1);
+    }
+
+    #[test]
+    fn test_cancelled_large_clipboard_send() {
+        let device = MockDevice::default();
+        let mut handler = connected_handler(&device);
+        let mut app = ascii_app("keep", 4);
+        let mut proxy = PluginProxy::init(&mut app);
+        app.settings.confirm_large_sends = true;
+        app.settings.large_send_threshold = 4;
+
+        send_clipboard(&mut app, &mut handler, &mut proxy, || {
+            Ok("0123456789".into())
+        });
+        assert_eq!(
+            app.pending_send.as_ref().map(|p| p.origin),
+            Some(SendOrigin::Clipboard)
+        );
+
+        let esc = KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE);
+        handle_send_confirm_key(esc, &mut app, &mut handler, &mut proxy);
+        assert_eq!(app.pending_send, None);
+        assert_eq!(app.message_log.tx_count, 0);
+        assert_eq!(app.tx_input, "keep");
+    }
+
+    #[test]
+    fn test_large_repeat_waits_for_confirmation() {
+        let device = MockDevice::default();
+        let mut handler = connected_handler(&device);
+        let mut app = ascii_app(&"A".repeat(16), 16);
+        let mut proxy = PluginProxy::init(&mut app);
+        app.settings.confirm_large_sends = true;
+        app.settings.large_send_threshold = 8;
+        app.repeat_prompt = Some(RepeatPrompt::default());
+
+        let enter = KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE);
+        handle_repeat_prompt_key(enter, &mut app);
+        assert!(app.repeat_send.is_none());
+        assert_eq!(
+            app.pending_send.as_ref().map(|p| p.origin),
+            Some(SendOrigin::Repeat {
+                count: Some(10),
+                interval: std::time::Duration::from_millis(1000),
+            })
+        );
+
+        handle_send_confirm_key(enter, &mut app, &mut handler, &mut proxy);
+        assert_eq!(app.pending_send, None);
+        let repeat = app.repeat_send.as_ref().expect("repeat started");
+        assert_eq!(repeat.payload.len(), 16);
+        assert_eq!(repeat.total, Some(10));
+        assert_eq!(repeat.mode, Some(TxMode::Ascii));
+
+        // Below the threshold the repeat starts straight away
+        app.cancel_repeat();
+        app.settings.large_send_threshold = 64;
+        app.repeat_prompt = Some(RepeatPrompt::default());
+        handle_repeat_prompt_key(enter, &mut app);
+        assert_eq!(app.pending_send, None);
+        assert!(app.repeat_send.is_some());
+    }
+
+    #[test]
+    fn test_sends_logged_with_originating_mode() {
+        let device = MockDevice::default();
+        let mut handler = connected_handler(&device);
+        let mut app = ascii_app("AT", 2);
+        let mut proxy = PluginProxy::init(&mut app);
+        app.mark_connected();
+        let last_mode = |app: &AppState| app.message_log.entries.back().unwrap().tx_mode;
+
+        // A confirmed send keeps the mode it was encoded in
+        app.settings.confirm_large_sends = true;
+        app.settings.large_send_threshold = 1;
+        send_tx_input(&mut app, &mut handler, &mut proxy);
+        app.tx_mode = TxMode::Hex;
+        let enter = KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE);
+        handle_send_confirm_key(enter, &mut app, &mut handler, &mut proxy);
+        assert_eq!(last_mode(&app), Some(TxMode::Ascii));
+
+        // Repeats keep the mode they started in
+        let prompt = tuiserial_core::RepeatPrompt::default();
+        let repeat = prompt
+            .build(b"AT".to_vec(), TxMode::Ascii, Instant::now())
+            .unwrap();
+        app.start_repeat(repeat);
+        tick_repeat(&mut app, &mut handler, &mut proxy);
+        assert_eq!(last_mode(&app), Some(TxMode::Ascii));
+        app.cancel_repeat();
+
+        // Byte pad bytes were never typed in a mode
+        send_pad_byte(&mut app, &mut handler, &mut proxy, 0x41);
+        assert_eq!(last_mode(&app), None);
+        assert_eq!(app.message_log.tx_count, 3);
     }
 }
@@ -9,7 +9,7 @@ use crate::error::CoreError;
 use crate::types::{FlowControl, Parity};
 
 /// Serial port configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SerialConfig {
     pub port: String,
     pub baud_rate: u32,
@@ -73,16 +73,23 @@ impl SerialConfig {
 
     /// Format configuration as a human-readable string
     pub fn format_display(&self) -> String {
+        format!(
+            "{} @ {} bps, {}",
+            self.port,
+            self.baud_rate,
+            self.frame_format()
+        )
+    }
+
+    /// Data bits, parity and stop bits in the usual short form, e.g. `8-N-1`
+    pub fn frame_format(&self) -> String {
         let parity_char = match self.parity {
             Parity::None => 'N',
             Parity::Even => 'E',
             Parity::Odd => 'O',
         };
 
-        format!(
-            "{} @ {} bps, {}-{}-{}",
-            self.port, self.baud_rate, self.data_bits, parity_char, self.stop_bits
-        )
+        format!("{}-{}-{}", self.data_bits, parity_char, self.stop_bits)
     }
 }
 
@@ -135,5 +142,6 @@ mod tests {
 
         let display = config.format_display();
         assert_eq!(display, "/dev/ttyUSB0 @ 115200 bps, 8-N-1");
+        assert_eq!(config.frame_format(), "8-N-1");
     }
 }
@@ -98,6 +98,8 @@ pub enum SerialErrorKind {
     ParseHex(String),
     /// Port is not connected.
     NotConnected,
+    /// A `{{type:value}}` TX token could not be expanded.
+    InvalidToken(String),
 }
 
 impl fmt::Display for SerialErrorKind {
@@ -108,6 +110,7 @@ impl fmt::Display for SerialErrorKind {
             Self::InvalidHexLength => write!(f, "Hex string must have an even length"),
             Self::ParseHex(e) => write!(f, "Invalid hex character: {e}"),
             Self::NotConnected => write!(f, "Port is not connected"),
+            Self::InvalidToken(e) => write!(f, "Invalid TX token: {e}"),
         }
     }
 }
@@ -166,6 +169,9 @@ pub enum ConfigErrorKind {
     Serialization(serde_json::Error),
     /// Configuration value failed validation.
     Validation(String),
+    /// Neither the config directory nor the fallback location could be
+    /// written.
+    NotWritable(String),
 }
 
 impl fmt::Display for ConfigErrorKind {
@@ -174,6 +180,7 @@ impl fmt::Display for ConfigErrorKind {
             Self::ConfigDirNotFound => write!(f, "Config directory not found"),
             Self::Serialization(e) => write!(f, "Serialization: {e}"),
             Self::Validation(m) => write!(f, "{m}"),
+            Self::NotWritable(m) => write!(f, "{m}"),
         }
     }
 }
@@ -192,6 +199,15 @@ pub enum CoreError {
     #[error("Config directory not found")]
     ConfigDirNotFound,
 
+    /// Neither the config directory nor the fallback location is writable
+    #[error("Cannot write {primary} ({primary_error}) or {fallback} ({fallback_error})")]
+    ConfigNotWritable {
+        primary: String,
+        primary_error: String,
+        fallback: String,
+        fallback_error: String,
+    },
+
     #[error("{0}")]
     Io(#[from] std::io::Error),
 
@@ -204,6 +220,7 @@ impl From<CoreError> for ConfigErrorKind {
         match e {
             CoreError::Validation(s) => Self::Validation(s),
             CoreError::ConfigDirNotFound => Self::ConfigDirNotFound,
+            e @ CoreError::ConfigNotWritable { .. } => Self::NotWritable(e.to_string()),
             CoreError::Io(io) => {
                 // IO errors from config are treated as serialization failures
                 // because they only happen during save/load.
@@ -286,9 +303,9 @@ impl AppError {
                 SerialErrorKind::PortOpen(_) => ErrorSeverity::Error,
                 SerialErrorKind::NotConnected => ErrorSeverity::Warning,
                 SerialErrorKind::Io(_) => ErrorSeverity::Warning,
-                SerialErrorKind::InvalidHexLength | SerialErrorKind::ParseHex(_) => {
-                    ErrorSeverity::Error
-                }
+                SerialErrorKind::InvalidHexLength
+                | SerialErrorKind::ParseHex(_)
+                | SerialErrorKind::InvalidToken(_) => ErrorSeverity::Error,
             },
             AppError::Plugin { kind, .. } => match kind {
                 PluginErrorKind::Panic { .. } => ErrorSeverity::Error,
@@ -0,0 +1,237 @@
+//! Log export options
+//!
+//! This module holds what the export dialog lets the user choose: which
+//! entries to write (direction and scope), the line format and whether to
+//! gzip the file. Formatting and writing the file happen in the frontend.
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::log::{LogDirection, LogEntry};
+
+/// Line format for exported log entries
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ExportFormat {
+    /// Printable text with non-printable bytes escaped as `\xNN`
+    Text,
+    /// Space-separated hex bytes
+    #[default]
+    Hex,
+    /// `timestamp,direction,seq,length,data` with hex data
+    Csv,
+}
+
+impl ExportFormat {
+    /// Header line written at the start of a new file, if the format has one
+    pub fn header(&self) -> Option<&'static str> {
+        match self {
+            ExportFormat::Csv => Some("timestamp,direction,seq,length,data"),
+            ExportFormat::Text | ExportFormat::Hex => None,
+        }
+    }
+
+    /// File extension for exports in this format
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Text => "txt",
+            ExportFormat::Hex => "hex",
+            ExportFormat::Csv => "csv",
+        }
+    }
+
+    /// Next format in the dialog's cycle order: text → hex → csv → text
+    pub fn next(self) -> Self {
+        match self {
+            ExportFormat::Text => ExportFormat::Hex,
+            ExportFormat::Hex => ExportFormat::Csv,
+            ExportFormat::Csv => ExportFormat::Text,
+        }
+    }
+}
+
+impl fmt::Display for ExportFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ExportFormat::Text => "text",
+            ExportFormat::Hex => "hex",
+            ExportFormat::Csv => "csv",
+        })
+    }
+}
+
+impl FromStr for ExportFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "text" | "txt" => Ok(ExportFormat::Text),
+            "hex" => Ok(ExportFormat::Hex),
+            "csv" => Ok(ExportFormat::Csv),
+            _ => Err(format!("unknown format '{s}' (expected text, hex or csv)")),
+        }
+    }
+}
+
+/// Which log entries an export writes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ExportFilter {
+    /// Only RX or only TX data; `None` exports everything including events
+    pub direction: Option<LogDirection>,
+    /// Only the entries the log view currently shows (e.g. the binary-only
+    /// filter), instead of the whole log
+    pub only_matching: bool,
+    /// Only entries logged after the one with this `seq`, e.g. a checkpoint
+    pub after_seq: Option<u64>,
+}
+
+impl ExportFilter {
+    /// Check whether `entry` passes the direction filter and start bound
+    pub fn matches(&self, entry: &LogEntry) -> bool {
+        self.direction.is_none_or(|d| entry.direction == d)
+            && self.after_seq.is_none_or(|seq| entry.seq > seq)
+    }
+
+    /// Cycle the direction: both → RX → TX → both
+    pub fn cycle_direction(&mut self) {
+        self.direction = match self.direction {
+            None => Some(LogDirection::Rx),
+            Some(LogDirection::Rx) => Some(LogDirection::Tx),
+            Some(_) => None,
+        };
+    }
+}
+
+/// A point in the log that later exports can start from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Checkpoint {
+    /// 1-based number shown in the log marker and export file name
+    pub number: u32,
+    /// `seq` of the checkpoint's marker entry
+    pub seq: u64,
+}
+
+impl Checkpoint {
+    /// Filter selecting everything logged since the checkpoint
+    pub fn filter(&self) -> ExportFilter {
+        ExportFilter {
+            after_seq: Some(self.seq),
+            ..ExportFilter::default()
+        }
+    }
+}
+
+/// Field focused in the export dialog
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportDialogField {
+    Format,
+    Direction,
+    Scope,
+    Compression,
+}
+
+/// Options prompt shown before exporting the log
+#[derive(Debug, Clone)]
+pub struct ExportDialog {
+    pub field: ExportDialogField,
+    pub format: ExportFormat,
+    pub filter: ExportFilter,
+    /// Write a gzip-compressed file (`.gz` appended to the extension)
+    pub gzip: bool,
+}
+
+impl Default for ExportDialog {
+    fn default() -> Self {
+        Self {
+            field: ExportDialogField::Format,
+            format: ExportFormat::default(),
+            filter: ExportFilter::default(),
+            gzip: false,
+        }
+    }
+}
+
+impl ExportDialog {
+    /// Move focus to the next (`forward`) or previous field
+    pub fn move_field(&mut self, forward: bool) {
+        use ExportDialogField::*;
+        self.field = match (self.field, forward) {
+            (Format, true) | (Scope, false) => Direction,
+            (Direction, true) | (Compression, false) => Scope,
+            (Scope, true) | (Format, false) => Compression,
+            (Compression, true) | (Direction, false) => Format,
+        };
+    }
+
+    /// Change the value of the focused field to the next option
+    pub fn cycle_value(&mut self) {
+        match self.field {
+            ExportDialogField::Format => self.format = self.format.next(),
+            ExportDialogField::Direction => self.filter.cycle_direction(),
+            ExportDialogField::Scope => self.filter.only_matching = !self.filter.only_matching,
+            ExportDialogField::Compression => self.gzip = !self.gzip,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filter_direction() {
+        let rx = LogEntry::new(LogDirection::Rx, vec![1]);
+        let tx = LogEntry::new(LogDirection::Tx, vec![2]);
+        let mut filter = ExportFilter::default();
+        assert!(filter.matches(&rx) && filter.matches(&tx));
+
+        filter.cycle_direction();
+        assert!(filter.matches(&rx) && !filter.matches(&tx));
+        filter.cycle_direction();
+        assert!(!filter.matches(&rx) && filter.matches(&tx));
+        filter.cycle_direction();
+        assert_eq!(filter.direction, None);
+    }
+
+    #[test]
+    fn test_filter_after_seq() {
+        let mut entry = LogEntry::new(LogDirection::Rx, vec![1]);
+        let filter = Checkpoint { number: 1, seq: 5 }.filter();
+        entry.seq = 5;
+        assert!(!filter.matches(&entry));
+        entry.seq = 6;
+        assert!(filter.matches(&entry));
+    }
+
+    #[test]
+    fn test_parse_format() {
+        assert_eq!("CSV".parse(), Ok(ExportFormat::Csv));
+        assert_eq!("txt".parse(), Ok(ExportFormat::Text));
+        assert!("xml".parse::<ExportFormat>().is_err());
+        assert_eq!(ExportFormat::Hex.to_string(), "hex");
+    }
+
+    #[test]
+    fn test_dialog_fields_cycle() {
+        let mut dialog = ExportDialog::default();
+        assert_eq!(dialog.format, ExportFormat::Hex);
+        dialog.cycle_value();
+        assert_eq!(dialog.format, ExportFormat::Csv);
+        dialog.cycle_value();
+        assert_eq!(dialog.format, ExportFormat::Text);
+
+        dialog.move_field(false);
+        assert_eq!(dialog.field, ExportDialogField::Compression);
+        dialog.cycle_value();
+        assert!(dialog.gzip);
+        dialog.move_field(false);
+        assert_eq!(dialog.field, ExportDialogField::Scope);
+        dialog.cycle_value();
+        assert!(dialog.filter.only_matching);
+        dialog.move_field(true);
+        dialog.move_field(true);
+        dialog.move_field(true);
+        assert_eq!(dialog.field, ExportDialogField::Direction);
+        dialog.cycle_value();
+        assert_eq!(dialog.filter.direction, Some(LogDirection::Rx));
+    }
+}
@@ -0,0 +1,176 @@
+//! Byte inspector for log entries
+//!
+//! Decodes a 2- or 4-byte window of the focused log entry as an integer in
+//! both byte orders, for reading numeric protocol fields off the wire.
+
+/// Size of the decoded window
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum InspectWidth {
+    #[default]
+    Two,
+    Four,
+}
+
+impl InspectWidth {
+    pub fn bytes(&self) -> usize {
+        match self {
+            InspectWidth::Two => 2,
+            InspectWidth::Four => 4,
+        }
+    }
+
+    pub fn toggle(&self) -> Self {
+        match self {
+            InspectWidth::Two => InspectWidth::Four,
+            InspectWidth::Four => InspectWidth::Two,
+        }
+    }
+}
+
+/// Window position within the focused entry's data
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ByteInspector {
+    /// Byte offset of the window start
+    pub offset: usize,
+    pub width: InspectWidth,
+}
+
+impl ByteInspector {
+    /// Largest valid window start for `data_len` bytes
+    fn max_offset(&self, data_len: usize) -> usize {
+        data_len.saturating_sub(self.width.bytes())
+    }
+
+    pub fn move_left(&mut self) {
+        self.offset = self.offset.saturating_sub(1);
+    }
+
+    pub fn move_right(&mut self, data_len: usize) {
+        self.offset = (self.offset + 1).min(self.max_offset(data_len));
+    }
+
+    /// Switch between 2- and 4-byte windows, keeping the window in bounds
+    pub fn toggle_width(&mut self, data_len: usize) {
+        self.width = self.width.toggle();
+        self.offset = self.offset.min(self.max_offset(data_len));
+    }
+
+    /// The window start clamped to `data_len` (the focused entry can change
+    /// under the inspector)
+    pub fn clamped_offset(&self, data_len: usize) -> usize {
+        self.offset.min(self.max_offset(data_len))
+    }
+
+    /// Bytes under the window, or `None` if `data` is shorter than the window
+    pub fn window<'a>(&self, data: &'a [u8]) -> Option<&'a [u8]> {
+        let start = self.clamped_offset(data.len());
+        data.get(start..start + self.width.bytes())
+    }
+}
+
+/// A 2- or 4-byte window decoded in both byte orders
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EndianReadout {
+    /// Window size in bytes (2 or 4)
+    pub width: usize,
+    pub le: u32,
+    pub be: u32,
+}
+
+impl EndianReadout {
+    /// Decode `bytes`; only 2- and 4-byte windows are supported
+    pub fn decode(bytes: &[u8]) -> Option<Self> {
+        let (le, be) = match *bytes {
+            [a, b] => (
+                u16::from_le_bytes([a, b]) as u32,
+                u16::from_be_bytes([a, b]) as u32,
+            ),
+            [a, b, c, d] => (
+                u32::from_le_bytes([a, b, c, d]),
+                u32::from_be_bytes([a, b, c, d]),
+            ),
+            _ => return None,
+        };
+        Some(Self {
+            width: bytes.len(),
+            le,
+            be,
+        })
+    }
+
+    /// Little-endian value reinterpreted as a signed integer of the window width
+    pub fn le_signed(&self) -> i32 {
+        self.signed(self.le)
+    }
+
+    /// Big-endian value reinterpreted as a signed integer of the window width
+    pub fn be_signed(&self) -> i32 {
+        self.signed(self.be)
+    }
+
+    fn signed(&self, value: u32) -> i32 {
+        match self.width {
+            2 => value as u16 as i16 as i32,
+            _ => value as i32,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_two_bytes() {
+        let r = EndianReadout::decode(&[0xD2, 0x04]).unwrap();
+        assert_eq!((r.le, r.be), (1234, 0xD204));
+        assert_eq!(r.le_signed(), 1234);
+        assert_eq!(r.be_signed(), 0xD204u16 as i16 as i32);
+
+        let r = EndianReadout::decode(&[0xFE, 0xFF]).unwrap();
+        assert_eq!((r.le_signed(), r.be_signed()), (-2, -257));
+    }
+
+    #[test]
+    fn test_decode_four_bytes() {
+        let r = EndianReadout::decode(&[0xDE, 0xAD, 0xBE, 0xEF]).unwrap();
+        assert_eq!(r.be, 0xDEADBEEF);
+        assert_eq!(r.le, 0xEFBEADDE);
+        assert_eq!(r.be_signed(), 0xDEADBEEFu32 as i32);
+    }
+
+    #[test]
+    fn test_decode_rejects_other_sizes() {
+        assert!(EndianReadout::decode(&[]).is_none());
+        assert!(EndianReadout::decode(&[1]).is_none());
+        assert!(EndianReadout::decode(&[1, 2, 3]).is_none());
+    }
+
+    #[test]
+    fn test_window_movement_stays_in_bounds() {
+        let data = [1, 2, 3, 4, 5];
+        let mut insp = ByteInspector::default();
+        insp.move_left();
+        assert_eq!(insp.window(&data), Some(&data[0..2]));
+
+        for _ in 0..10 {
+            insp.move_right(data.len());
+        }
+        assert_eq!(insp.offset, 3);
+        assert_eq!(insp.window(&data), Some(&data[3..5]));
+
+        insp.toggle_width(data.len());
+        assert_eq!(insp.offset, 1);
+        assert_eq!(insp.window(&data), Some(&data[1..5]));
+    }
+
+    #[test]
+    fn test_window_clamps_to_shorter_entry() {
+        let insp = ByteInspector {
+            offset: 10,
+            width: InspectWidth::Four,
+        };
+        assert_eq!(insp.window(&[9, 8, 7, 6, 5]), Some(&[8, 7, 6, 5][..]));
+        assert_eq!(insp.window(&[1, 2, 3]), None);
+    }
+}
@@ -0,0 +1,449 @@
+//! Keyboard shortcuts and their bindings
+//!
+//! Every shortcut is a [`ShortcutAction`] with a description. [`KeyBindings`]
+//! maps key presses to actions: the key handlers look up the action for a
+//! key there, and the help overlay and hint bar list each action's keys
+//! from the same map, so a remapped key both works and shows up in the help.
+
+use std::collections::HashMap;
+
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use rust_i18n::t;
+
+/// A documented keyboard shortcut
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ShortcutAction {
+    NewSession,
+    CloneSession,
+    CloseSession,
+    NextSession,
+    PrevSession,
+    SwitchSessionArrows,
+    SwitchSessionNumber,
+    CycleLayout,
+    PrevLayout,
+    NextPane,
+    PrevPane,
+    CyclePaneSession,
+    NextField,
+    PrevField,
+    ToggleConnection,
+    PanicDisconnect,
+    RepeatSend,
+    ByteInspector,
+    RxTiming,
+    BaudPrev,
+    BaudNext,
+    Checkpoint,
+    ExportSinceCheckpoint,
+    BytePad,
+    ApplyStaged,
+    RevertStaged,
+    NextBinaryEntry,
+    BinaryOnly,
+    CopySelection,
+    SendClipboard,
+    DualViewMode,
+    ClearLog,
+    ToggleDisplayMode,
+    ToggleAutoScroll,
+    FlowControl,
+    AppendMode,
+    RefreshPorts,
+    OpenMenu,
+    OpenPlugins,
+    ShowHelp,
+    Quit,
+    ForceQuit,
+}
+
+impl ShortcutAction {
+    /// Every shortcut
+    pub const ALL: &[ShortcutAction] = &[
+        ShortcutAction::NewSession,
+        ShortcutAction::CloneSession,
+        ShortcutAction::CloseSession,
+        ShortcutAction::NextSession,
+        ShortcutAction::PrevSession,
+        ShortcutAction::SwitchSessionArrows,
+        ShortcutAction::SwitchSessionNumber,
+        ShortcutAction::CycleLayout,
+        ShortcutAction::PrevLayout,
+        ShortcutAction::NextPane,
+        ShortcutAction::PrevPane,
+        ShortcutAction::CyclePaneSession,
+        ShortcutAction::NextField,
+        ShortcutAction::PrevField,
+        ShortcutAction::ToggleConnection,
+        ShortcutAction::PanicDisconnect,
+        ShortcutAction::RepeatSend,
+        ShortcutAction::ByteInspector,
+        ShortcutAction::RxTiming,
+        ShortcutAction::BaudPrev,
+        ShortcutAction::BaudNext,
+        ShortcutAction::Checkpoint,
+        ShortcutAction::ExportSinceCheckpoint,
+        ShortcutAction::BytePad,
+        ShortcutAction::ApplyStaged,
+        ShortcutAction::RevertStaged,
+        ShortcutAction::NextBinaryEntry,
+        ShortcutAction::BinaryOnly,
+        ShortcutAction::CopySelection,
+        ShortcutAction::SendClipboard,
+        ShortcutAction::DualViewMode,
+        ShortcutAction::ClearLog,
+        ShortcutAction::ToggleDisplayMode,
+        ShortcutAction::ToggleAutoScroll,
+        ShortcutAction::FlowControl,
+        ShortcutAction::AppendMode,
+        ShortcutAction::RefreshPorts,
+        ShortcutAction::OpenMenu,
+        ShortcutAction::OpenPlugins,
+        ShortcutAction::ShowHelp,
+        ShortcutAction::Quit,
+        ShortcutAction::ForceQuit,
+    ];
+
+    /// Keys bound to the action unless remapped
+    pub fn default_keys(self) -> Vec<KeyChord> {
+        use KeyCode::*;
+        let ctrl = KeyChord::ctrl;
+        let key = |c| KeyChord::plain(Char(c));
+        match self {
+            ShortcutAction::NewSession => vec![ctrl(Char('t'))],
+            ShortcutAction::CloneSession => vec![ctrl(Char('T'))],
+            ShortcutAction::CloseSession => vec![ctrl(Char('w'))],
+            ShortcutAction::NextSession => vec![ctrl(Tab)],
+            ShortcutAction::PrevSession => vec![ctrl(BackTab)],
+            ShortcutAction::SwitchSessionArrows => vec![ctrl(Left), ctrl(Right)],
+            ShortcutAction::SwitchSessionNumber => ('1'..='9').map(|n| ctrl(Char(n))).collect(),
+            ShortcutAction::CycleLayout => vec![ctrl(Char('l'))],
+            ShortcutAction::PrevLayout => vec![ctrl(Char('L'))],
+            ShortcutAction::NextPane => vec![ctrl(Char('p'))],
+            ShortcutAction::PrevPane => vec![ctrl(Char('P'))],
+            ShortcutAction::CyclePaneSession => vec![ctrl(Char('n'))],
+            ShortcutAction::NextField => vec![KeyChord::plain(Tab)],
+            ShortcutAction::PrevField => vec![KeyChord::plain(BackTab)],
+            ShortcutAction::ToggleConnection => vec![key('o')],
+            ShortcutAction::PanicDisconnect => vec![ctrl(Char('d'))],
+            ShortcutAction::RepeatSend => vec![ctrl(Char('r'))],
+            ShortcutAction::ByteInspector => vec![key('i')],
+            ShortcutAction::RxTiming => vec![key('t')],
+            ShortcutAction::BaudPrev => vec![key('[')],
+            ShortcutAction::BaudNext => vec![key(']')],
+            ShortcutAction::Checkpoint => vec![key('m')],
+            ShortcutAction::ExportSinceCheckpoint => vec![key('M')],
+            ShortcutAction::BytePad => vec![key('g')],
+            ShortcutAction::ApplyStaged => vec![key('A')],
+            ShortcutAction::RevertStaged => vec![key('R')],
+            ShortcutAction::NextBinaryEntry => vec![key('b')],
+            ShortcutAction::BinaryOnly => vec![key('B')],
+            ShortcutAction::CopySelection => vec![key('y')],
+            ShortcutAction::SendClipboard => vec![ctrl(Char('V'))],
+            ShortcutAction::DualViewMode => vec![key('X')],
+            ShortcutAction::ClearLog => vec![key('c')],
+            ShortcutAction::ToggleDisplayMode => vec![key('x')],
+            ShortcutAction::ToggleAutoScroll => vec![key('a')],
+            ShortcutAction::FlowControl => vec![key('f')],
+            ShortcutAction::AppendMode => vec![key('n')],
+            ShortcutAction::RefreshPorts => vec![key('r')],
+            ShortcutAction::OpenMenu => vec![KeyChord::plain(F(10))],
+            ShortcutAction::OpenPlugins => vec![key('p')],
+            ShortcutAction::ShowHelp => vec![key('?'), KeyChord::plain(F(1))],
+            ShortcutAction::Quit => vec![key('q'), ctrl(Char('q'))],
+            ShortcutAction::ForceQuit => vec![ctrl(Char('c'))],
+        }
+    }
+
+    /// Locale key of the description. The translations read
+    /// "<default key>: <description>"; only the part after the colon is shown.
+    pub fn description_key(self) -> &'static str {
+        match self {
+            ShortcutAction::NewSession => "shortcuts.new_session",
+            ShortcutAction::CloneSession => "shortcuts.clone_session",
+            ShortcutAction::CloseSession => "shortcuts.close_session",
+            ShortcutAction::NextSession => "shortcuts.next_session",
+            ShortcutAction::PrevSession => "shortcuts.prev_session",
+            ShortcutAction::SwitchSessionArrows => "shortcuts.switch_arrows",
+            ShortcutAction::SwitchSessionNumber => "shortcuts.switch_1_9",
+            ShortcutAction::CycleLayout => "shortcuts.cycle_layout",
+            ShortcutAction::PrevLayout => "shortcuts.prev_layout",
+            ShortcutAction::NextPane => "shortcuts.next_pane",
+            ShortcutAction::PrevPane => "shortcuts.prev_pane_key",
+            ShortcutAction::CyclePaneSession => "shortcuts.cycle_pane_session",
+            ShortcutAction::NextField => "shortcuts.tab",
+            ShortcutAction::PrevField => "shortcuts.shift_tab",
+            ShortcutAction::ToggleConnection => "shortcuts.connect",
+            ShortcutAction::PanicDisconnect => "shortcuts.panic_disconnect",
+            ShortcutAction::RepeatSend => "shortcuts.repeat_send",
+            ShortcutAction::ByteInspector => "shortcuts.byte_inspector",
+            ShortcutAction::RxTiming => "shortcuts.rx_timing",
+            ShortcutAction::BaudPrev => "shortcuts.baud_prev",
+            ShortcutAction::BaudNext => "shortcuts.baud_next",
+            ShortcutAction::Checkpoint => "shortcuts.checkpoint",
+            ShortcutAction::ExportSinceCheckpoint => "shortcuts.export_since_checkpoint",
+            ShortcutAction::BytePad => "shortcuts.byte_pad",
+            ShortcutAction::ApplyStaged => "shortcuts.apply_staged",
+            ShortcutAction::RevertStaged => "shortcuts.revert_staged",
+            ShortcutAction::NextBinaryEntry => "shortcuts.next_binary",
+            ShortcutAction::BinaryOnly => "shortcuts.binary_only",
+            ShortcutAction::CopySelection => "shortcuts.copy_selection",
+            ShortcutAction::SendClipboard => "shortcuts.send_clipboard",
+            ShortcutAction::DualViewMode => "shortcuts.dual_view_mode",
+            ShortcutAction::ClearLog => "shortcuts.clear",
+            ShortcutAction::ToggleDisplayMode => "shortcuts.display_mode",
+            ShortcutAction::ToggleAutoScroll => "shortcuts.auto_scroll",
+            ShortcutAction::FlowControl => "shortcuts.flow_control",
+            ShortcutAction::AppendMode => "shortcuts.append_mode",
+            ShortcutAction::RefreshPorts => "shortcuts.refresh_ports",
+            ShortcutAction::OpenMenu => "shortcuts.menu",
+            ShortcutAction::OpenPlugins => "shortcuts.plugins",
+            ShortcutAction::ShowHelp => "shortcuts.help",
+            ShortcutAction::Quit => "shortcuts.quit",
+            ShortcutAction::ForceQuit => "shortcuts.force_quit",
+        }
+    }
+
+    /// Translated description without the key prefix
+    pub fn description(self) -> String {
+        let text = t!(self.description_key());
+        match text.split_once(':') {
+            Some((_, description)) => description.trim().to_string(),
+            None => text.to_string(),
+        }
+    }
+}
+
+/// A key press as shortcuts see it. Letters carry Shift in their case and
+/// other characters already include it, so Shift is only kept for keys
+/// without a character (`Shift+Tab` is [`KeyCode::BackTab`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyChord {
+    pub code: KeyCode,
+    pub modifiers: KeyModifiers,
+}
+
+impl KeyChord {
+    /// `code` with `modifiers`, normalised like a key event
+    pub fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        let (code, modifiers) = match code {
+            KeyCode::Char(c) if modifiers.contains(KeyModifiers::SHIFT) => (
+                KeyCode::Char(c.to_ascii_uppercase()),
+                modifiers - KeyModifiers::SHIFT,
+            ),
+            KeyCode::BackTab => (code, modifiers - KeyModifiers::SHIFT),
+            _ => (code, modifiers),
+        };
+        Self { code, modifiers }
+    }
+
+    /// `code` without modifiers
+    pub fn plain(code: KeyCode) -> Self {
+        Self::new(code, KeyModifiers::NONE)
+    }
+
+    /// Ctrl+`code`
+    pub fn ctrl(code: KeyCode) -> Self {
+        Self::new(code, KeyModifiers::CONTROL)
+    }
+
+    /// The chord of a key event
+    pub fn from_event(key: &KeyEvent) -> Self {
+        Self::new(key.code, key.modifiers)
+    }
+
+    /// Label shown in the help: letters upper case, `Shift+` for capitals,
+    /// e.g. `C`, `Shift+X`, `Ctrl+Shift+T`
+    pub fn label(&self) -> String {
+        let mut label = String::new();
+        if self.modifiers.contains(KeyModifiers::CONTROL) {
+            label.push_str("Ctrl+");
+        }
+        if self.modifiers.contains(KeyModifiers::ALT) {
+            label.push_str("Alt+");
+        }
+        match self.code {
+            KeyCode::Char(' ') => label.push_str("Space"),
+            KeyCode::Char(c) if c.is_ascii_uppercase() => {
+                label.push_str("Shift+");
+                label.push(c);
+            }
+            KeyCode::Char(c) => label.push(c.to_ascii_uppercase()),
+            KeyCode::Tab => label.push_str("Tab"),
+            KeyCode::BackTab => label.push_str("Shift+Tab"),
+            KeyCode::Left => label.push('←'),
+            KeyCode::Right => label.push('→'),
+            KeyCode::Up => label.push('↑'),
+            KeyCode::Down => label.push('↓'),
+            KeyCode::F(n) => label.push_str(&format!("F{n}")),
+            code => label.push_str(&format!("{code:?}")),
+        }
+        label
+    }
+}
+
+/// Key presses bound to each shortcut
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyBindings {
+    actions: HashMap<KeyChord, ShortcutAction>,
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        let actions = ShortcutAction::ALL
+            .iter()
+            .flat_map(|&action| action.default_keys().into_iter().map(move |k| (k, action)))
+            .collect();
+        Self { actions }
+    }
+}
+
+impl KeyBindings {
+    /// Shortcut bound to the key event, if any
+    pub fn action(&self, key: &KeyEvent) -> Option<ShortcutAction> {
+        self.actions.get(&KeyChord::from_event(key)).copied()
+    }
+
+    /// Keys bound to `action`, unmodified keys first
+    pub fn keys(&self, action: ShortcutAction) -> Vec<KeyChord> {
+        let mut keys: Vec<KeyChord> = self
+            .actions
+            .iter()
+            .filter(|&(_, &a)| a == action)
+            .map(|(&k, _)| k)
+            .collect();
+        keys.sort_by_key(|k| (k.modifiers.bits(), k.label()));
+        keys
+    }
+
+    /// Help label of the keys bound to `action`: `Ctrl+1~9` for a run of
+    /// digits, otherwise the keys joined by ` / `; `-` when unbound
+    pub fn key_label(&self, action: ShortcutAction) -> String {
+        let keys = self.keys(action);
+        let digits: Vec<char> = keys
+            .iter()
+            .filter_map(|k| match k.code {
+                KeyCode::Char(c) if c.is_ascii_digit() => Some(c),
+                _ => None,
+            })
+            .collect();
+        if keys.len() > 2
+            && digits.len() == keys.len()
+            && keys.iter().all(|k| k.modifiers == keys[0].modifiers)
+        {
+            let first = keys[0].label();
+            let prefix = &first[..first.len() - 1];
+            return format!("{prefix}{}~{}", digits[0], digits[digits.len() - 1]);
+        }
+        if keys.is_empty() {
+            return "-".to_string();
+        }
+        keys.iter()
+            .map(KeyChord::label)
+            .collect::<Vec<_>>()
+            .join(" / ")
+    }
+
+    /// Bind `action` to `key` alone, replacing its other keys and taking
+    /// `key` from any action it was bound to
+    pub fn bind(&mut self, action: ShortcutAction, key: KeyChord) {
+        self.actions.retain(|_, &mut a| a != action);
+        self.actions.insert(key, action);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn press(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
+        KeyEvent::new(code, modifiers)
+    }
+
+    #[test]
+    fn test_default_labels() {
+        let bindings = KeyBindings::default();
+        assert_eq!(bindings.key_label(ShortcutAction::ClearLog), "C");
+        assert_eq!(bindings.key_label(ShortcutAction::BytePad), "G");
+        assert_eq!(
+            bindings.key_label(ShortcutAction::ExportSinceCheckpoint),
+            "Shift+M"
+        );
+        assert_eq!(
+            bindings.key_label(ShortcutAction::CloneSession),
+            "Ctrl+Shift+T"
+        );
+        assert_eq!(
+            bindings.key_label(ShortcutAction::PrevSession),
+            "Ctrl+Shift+Tab"
+        );
+        assert_eq!(
+            bindings.key_label(ShortcutAction::SwitchSessionNumber),
+            "Ctrl+1~9"
+        );
+        assert_eq!(bindings.key_label(ShortcutAction::ShowHelp), "? / F1");
+    }
+
+    #[test]
+    fn test_action_normalises_shift() {
+        let bindings = KeyBindings::default();
+        // Terminals report capitals with or without Shift
+        for modifiers in [KeyModifiers::NONE, KeyModifiers::SHIFT] {
+            assert_eq!(
+                bindings.action(&press(KeyCode::Char('M'), modifiers)),
+                Some(ShortcutAction::ExportSinceCheckpoint)
+            );
+        }
+        assert_eq!(
+            bindings.action(&press(KeyCode::Char('m'), KeyModifiers::NONE)),
+            Some(ShortcutAction::Checkpoint)
+        );
+        assert_eq!(
+            bindings.action(&press(
+                KeyCode::Char('v'),
+                KeyModifiers::CONTROL | KeyModifiers::SHIFT
+            )),
+            Some(ShortcutAction::SendClipboard)
+        );
+        assert_eq!(
+            bindings.action(&press(KeyCode::BackTab, KeyModifiers::SHIFT)),
+            Some(ShortcutAction::PrevField)
+        );
+        assert_eq!(
+            bindings.action(&press(KeyCode::Char('?'), KeyModifiers::SHIFT)),
+            Some(ShortcutAction::ShowHelp)
+        );
+    }
+
+    #[test]
+    fn test_bind_replaces_keys() {
+        let mut bindings = KeyBindings::default();
+        let ctrl_k = KeyChord::ctrl(KeyCode::Char('k'));
+        bindings.bind(ShortcutAction::ClearLog, ctrl_k);
+        assert_eq!(bindings.key_label(ShortcutAction::ClearLog), "Ctrl+K");
+        assert_eq!(
+            bindings.action(&press(KeyCode::Char('c'), KeyModifiers::NONE)),
+            None
+        );
+        assert_eq!(
+            bindings.action(&press(KeyCode::Char('k'), KeyModifiers::CONTROL)),
+            Some(ShortcutAction::ClearLog)
+        );
+
+        // Taking a key from another action leaves that one unbound
+        bindings.bind(
+            ShortcutAction::ClearLog,
+            KeyChord::plain(KeyCode::Char('x')),
+        );
+        assert_eq!(bindings.key_label(ShortcutAction::ToggleDisplayMode), "-");
+    }
+
+    #[test]
+    fn test_description_drops_key_prefix() {
+        assert_eq!(ShortcutAction::NewSession.description(), "New Session");
+        assert_eq!(
+            ShortcutAction::SendClipboard.description(),
+            "Send Clipboard Contents"
+        );
+    }
+}
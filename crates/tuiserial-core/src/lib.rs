@@ -9,19 +9,40 @@
 //! - `types`: Basic type definitions and enums (DisplayMode, TxMode, Parity, etc.)
 //! - `notification`: Notification system for user messages
 //! - `log`: Log entries and message log for serial communication
+//! - `line_ending`: Line-ending style detection for received data
 //! - `config`: Serial port configuration
+//! - `export`: Log export options (format, direction and scope filters)
+//! - `inspector`: Byte inspector decoding 2/4-byte windows in both byte orders
+//! - `keybindings`: Shortcut descriptions and the keys bound to them
+//! - `rate`: Rolling-window byte-rate tracking
+//! - `renderer`: `DisplayRenderer` trait for custom log display modes
+//! - `repeat`: Repeat-send scheduling (bounded and indefinite)
+//! - `settings`: Persisted user preferences (log layout, behaviour toggles)
 //! - `state`: Main application state management
+//! - `text_edit`: Char-boundary-safe cursor editing and the `TextInput` widget state
+//! - `timing`: High-resolution RX read timing and inter-read deltas
 //! - `i18n`: Internationalization support
 
 // Module declarations
 pub mod config;
 pub mod error;
 pub mod error_log;
+pub mod export;
 pub mod file_log;
+pub mod inspector;
+pub mod keybindings;
+pub mod line_ending;
 pub mod log;
 pub mod menu_def;
 pub mod notification;
+pub mod quality;
+pub mod rate;
+pub mod renderer;
+pub mod repeat;
+pub mod settings;
 pub mod state;
+pub mod text_edit;
+pub mod timing;
 pub mod types;
 
 // Re-exports for convenience
@@ -31,13 +52,37 @@ pub use error::{
     RecoveryStrategy, SerialErrorKind,
 };
 pub use error_log::{ErrorLog, ErrorLogEntry};
-pub use log::{LogDirection, LogEntry, MAX_LOG_LINES, MessageLog};
+pub use export::{Checkpoint, ExportDialog, ExportDialogField, ExportFilter, ExportFormat};
+pub use inspector::{ByteInspector, EndianReadout, InspectWidth};
+pub use keybindings::{KeyBindings, KeyChord, ShortcutAction};
+pub use line_ending::{LineEndingStats, detect_line_ending};
+pub use log::{
+    LogDirection, LogEntry, LogEventKind, LogLayout, MAX_LOG_LINES, MessageLog, TrafficTotals,
+    entry_is_printable,
+};
 pub use menu_def::{MENU_BAR, MenuAction, MenuBar};
 pub use notification::{Notification, NotificationLevel};
-pub use state::{AppState, PluginLoadStatus, PluginMetadataSimple};
+pub use quality::{
+    DEGRADED_ERROR_PERCENT, LinkQuality, POOR_ERROR_PERCENT, QUALITY_WINDOW, QualityWindow,
+    classify_quality,
+};
+pub use rate::{DEFAULT_RATE_WINDOW, RATE_BUCKETS, RateTracker};
+pub use renderer::{DisplayRenderer, NamedRenderer};
+pub use repeat::{MIN_REPEAT_INTERVAL, RepeatPrompt, RepeatPromptField, RepeatSend};
+pub use settings::{
+    AppSettings, CountDisplay, DEFAULT_IDLE_GAP_MS, DEFAULT_LARGE_SEND_THRESHOLD,
+    DEFAULT_RX_READ_BUFFER, DisconnectedRx, FramingMode, LogColumn, LogColumns, PasteAppend,
+};
+pub use state::{
+    AppState, ConfigLoad, ConfigSave, FALLBACK_CONFIG_FILE, PluginLoadStatus, PluginMetadataSimple,
+    StagedChange,
+};
+pub use text_edit::TextInput;
+pub use timing::{ChunkDelta, RX_TIMING_CAPACITY, RxChunkTime, RxTiming, chunk_deltas};
 pub use types::{
-    AppendMode, DisplayMode, FlowControl, FocusedField, Language, MenuState, Parity,
-    PluginLoadState, PluginModalMode, RegistryEntry, TxMode, convert_tx_input,
+    AppendMode, BytePad, ConnectionState, DisplayMode, FlowControl, FocusedField, Language,
+    LogSelection, LogView, MenuState, Parity, PendingSend, PluginLoadState, PluginModalMode,
+    RegistryEntry, SendOrigin, TxMode, convert_tx_input,
 };
 
 // Utility functions
@@ -0,0 +1,136 @@
+//! Line-ending detection for received data
+//!
+//! Counts LF, CR and CRLF terminators in RX data so the UI can show which
+//! style the device uses, and the user can match the TX append mode to it.
+
+use crate::types::AppendMode;
+
+/// Counts are halved once their sum reaches this, so the detected style
+/// follows a device that changes its line endings
+const DECAY_THRESHOLD: u32 = 256;
+
+/// Running counts of line terminators seen in RX data
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LineEndingStats {
+    pub lf: u32,
+    pub cr: u32,
+    pub crlf: u32,
+    /// The last chunk ended in CR; the next byte decides CR vs CRLF
+    pending_cr: bool,
+}
+
+impl LineEndingStats {
+    /// Count the terminators in the next chunk of RX data. A CR at the end
+    /// of `bytes` is held until the next chunk so a CRLF split across two
+    /// reads still counts once.
+    pub fn feed(&mut self, bytes: &[u8]) {
+        for &b in bytes {
+            if std::mem::take(&mut self.pending_cr) {
+                if b == b'\n' {
+                    self.crlf += 1;
+                    continue;
+                }
+                self.cr += 1;
+            }
+            match b {
+                b'\r' => self.pending_cr = true,
+                b'\n' => self.lf += 1,
+                _ => {}
+            }
+        }
+
+        while self.total() >= DECAY_THRESHOLD {
+            self.lf /= 2;
+            self.cr /= 2;
+            self.crlf /= 2;
+        }
+    }
+
+    /// Terminators counted so far
+    pub fn total(&self) -> u32 {
+        self.lf + self.cr + self.crlf
+    }
+
+    /// The style making up more than half of the terminators seen, if any
+    pub fn dominant(&self) -> Option<AppendMode> {
+        [
+            (AppendMode::LF, self.lf),
+            (AppendMode::CR, self.cr),
+            (AppendMode::CRLF, self.crlf),
+        ]
+        .into_iter()
+        .find(|&(_, count)| count * 2 > self.total())
+        .map(|(mode, _)| mode)
+    }
+
+    /// Terminators were seen but no style dominates
+    pub fn is_mixed(&self) -> bool {
+        self.total() > 0 && self.dominant().is_none()
+    }
+}
+
+/// Detect the dominant line-ending style in `bytes`: `LF`, `CR` or `CRLF`.
+/// Returns `None` when there are no terminators or no style makes up more
+/// than half of them (mixed).
+pub fn detect_line_ending(bytes: &[u8]) -> Option<AppendMode> {
+    let mut stats = LineEndingStats::default();
+    stats.feed(bytes);
+    // A trailing CR is complete at the end of a one-off sample
+    if stats.pending_cr {
+        stats.cr += 1;
+    }
+    stats.dominant()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_single_styles() {
+        assert_eq!(
+            detect_line_ending(b"OK\r\nREADY\r\n"),
+            Some(AppendMode::CRLF)
+        );
+        assert_eq!(detect_line_ending(b"OK\nREADY\n"), Some(AppendMode::LF));
+        assert_eq!(detect_line_ending(b"OK\rREADY\r"), Some(AppendMode::CR));
+        assert_eq!(detect_line_ending(b"no terminator"), None);
+        assert_eq!(detect_line_ending(b""), None);
+    }
+
+    #[test]
+    fn test_detect_dominant_and_mixed() {
+        // Blank CRLF lines and a stray LF still read as CRLF
+        assert_eq!(
+            detect_line_ending(b"a\r\n\r\nb\r\nc\n"),
+            Some(AppendMode::CRLF)
+        );
+        assert_eq!(detect_line_ending(b"a\r\nb\nc\r"), None);
+
+        let mut stats = LineEndingStats::default();
+        stats.feed(b"a\r\nb\n");
+        assert!(stats.is_mixed());
+        assert!(!LineEndingStats::default().is_mixed());
+    }
+
+    #[test]
+    fn test_crlf_split_across_chunks() {
+        let mut stats = LineEndingStats::default();
+        stats.feed(b"OK\r");
+        assert_eq!(stats.total(), 0);
+        stats.feed(b"\nREADY\r");
+        stats.feed(b"\n");
+        assert_eq!(stats.crlf, 2);
+        assert_eq!(stats.dominant(), Some(AppendMode::CRLF));
+    }
+
+    #[test]
+    fn test_counts_decay_to_follow_changes() {
+        let mut stats = LineEndingStats::default();
+        stats.feed(&b"x\r\n".repeat(200));
+        assert_eq!(stats.dominant(), Some(AppendMode::CRLF));
+        stats.feed(&b"x\n".repeat(300));
+        assert_eq!(stats.dominant(), Some(AppendMode::LF));
+        assert!(stats.total() < DECAY_THRESHOLD);
+    }
+}
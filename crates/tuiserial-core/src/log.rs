@@ -6,11 +6,26 @@
 use chrono::{DateTime, Local};
 use std::collections::VecDeque;
 
+use crate::types::{DisplayMode, TxMode};
+
+/// Kind of an inline event entry
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogEventKind {
+    Connect,
+    Disconnect,
+    Reconnect,
+    Error,
+    /// User-inserted marker or divider
+    Marker,
+}
+
 /// Direction of serial communication
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum LogDirection {
     Rx,
     Tx,
+    /// Inline event (not port traffic); `data` holds the UTF-8 description
+    Event(LogEventKind),
 }
 
 /// A single log entry representing a serial communication event
@@ -19,6 +34,11 @@ pub struct LogEntry {
     pub timestamp: DateTime<Local>,
     pub direction: LogDirection,
     pub data: Vec<u8>,
+    /// Sequence number assigned by `MessageLog` (starts at 1, 0 = unassigned)
+    pub seq: u64,
+    /// How a TX entry was typed in (ASCII or hex); `None` for RX, events
+    /// and TX whose origin is unknown
+    pub tx_mode: Option<TxMode>,
 }
 
 impl LogEntry {
@@ -28,19 +48,76 @@ impl LogEntry {
             timestamp: Local::now(),
             direction,
             data,
+            seq: 0,
+            tx_mode: None,
+        }
+    }
+
+    /// Mode to render this entry's data in: `global` unless `native_tx` is
+    /// set and the entry is TX with a known input mode, which then wins
+    pub fn display_mode(&self, global: DisplayMode, native_tx: bool) -> DisplayMode {
+        match (native_tx, self.direction, self.tx_mode) {
+            (true, LogDirection::Tx, Some(TxMode::Ascii)) => DisplayMode::Text,
+            (true, LogDirection::Tx, Some(TxMode::Hex)) => DisplayMode::Hex,
+            _ => global,
+        }
+    }
+
+    /// Event kind if this is an inline event rather than port traffic
+    pub fn event_kind(&self) -> Option<LogEventKind> {
+        match self.direction {
+            LogDirection::Event(kind) => Some(kind),
+            LogDirection::Rx | LogDirection::Tx => None,
         }
     }
+
+    /// Check whether this entry is an inline event
+    pub fn is_event(&self) -> bool {
+        self.event_kind().is_some()
+    }
+}
+
+/// Whether every byte of `entry` is printable ASCII or common whitespace
+/// (tab, CR, LF). Entries holding control or high-bit bytes are "binary";
+/// inline events are text and always count as printable.
+pub fn entry_is_printable(entry: &LogEntry) -> bool {
+    entry.is_event()
+        || entry
+            .data
+            .iter()
+            .all(|&b| matches!(b, 0x20..=0x7E | b'\t' | b'\r' | b'\n'))
 }
 
 /// Maximum number of log lines to keep in memory
 pub const MAX_LOG_LINES: usize = 10000;
 
+/// Port traffic since startup; unlike the log counters these are never
+/// reset, so they can back monotonic metrics
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct TrafficTotals {
+    pub rx_bytes: u64,
+    pub tx_bytes: u64,
+    pub rx_reads: u64,
+    pub tx_writes: u64,
+}
+
 /// Message log containing all serial communication events
 #[derive(Debug, Default, Clone)]
 pub struct MessageLog {
     pub entries: VecDeque<LogEntry>,
     pub rx_count: u64,
     pub tx_count: u64,
+    /// Cumulative RX/TX payload bytes, counted like `rx_count`/`tx_count`
+    pub rx_bytes: u64,
+    pub tx_bytes: u64,
+    /// Sequence number of the most recently pushed entry
+    pub last_seq: u64,
+    /// Traffic pushed since the log was created, kept by `clear`
+    pub totals: TrafficTotals,
+    /// RX/TX entries currently in `entries`, kept up to date on push and
+    /// eviction
+    retained_rx: usize,
+    retained_tx: usize,
 }
 
 impl MessageLog {
@@ -50,33 +127,313 @@ impl MessageLog {
             entries: VecDeque::with_capacity(MAX_LOG_LINES),
             rx_count: 0,
             tx_count: 0,
+            rx_bytes: 0,
+            tx_bytes: 0,
+            last_seq: 0,
+            totals: TrafficTotals::default(),
+            retained_rx: 0,
+            retained_tx: 0,
         }
     }
 
     /// Add a received data entry to the log
     pub fn push_rx(&mut self, data: Vec<u8>) {
+        self.rx_bytes += data.len() as u64;
+        self.totals.rx_bytes += data.len() as u64;
+        self.totals.rx_reads += 1;
         self.push_entry(LogEntry::new(LogDirection::Rx, data));
         self.rx_count += 1;
     }
 
     /// Add a transmitted data entry to the log
     pub fn push_tx(&mut self, data: Vec<u8>) {
+        self.tx_bytes += data.len() as u64;
+        self.totals.tx_bytes += data.len() as u64;
+        self.totals.tx_writes += 1;
         self.push_entry(LogEntry::new(LogDirection::Tx, data));
         self.tx_count += 1;
     }
 
+    /// Add a transmitted data entry, remembering the mode it was typed in
+    pub fn push_tx_as(&mut self, data: Vec<u8>, mode: TxMode) {
+        self.tx_bytes += data.len() as u64;
+        self.totals.tx_bytes += data.len() as u64;
+        self.totals.tx_writes += 1;
+        let mut entry = LogEntry::new(LogDirection::Tx, data);
+        entry.tx_mode = Some(mode);
+        self.push_entry(entry);
+        self.tx_count += 1;
+    }
+
+    /// Add an inline event entry; does not affect RX/TX counters
+    pub fn push_event(&mut self, kind: LogEventKind, text: impl Into<String>) {
+        let text: String = text.into();
+        self.push_entry(LogEntry::new(LogDirection::Event(kind), text.into_bytes()));
+    }
+
     /// Internal method to add an entry, maintaining size limit
-    fn push_entry(&mut self, entry: LogEntry) {
-        if self.entries.len() >= MAX_LOG_LINES {
-            self.entries.pop_front();
+    fn push_entry(&mut self, mut entry: LogEntry) {
+        self.last_seq += 1;
+        entry.seq = self.last_seq;
+        if self.entries.len() >= MAX_LOG_LINES
+            && let Some(evicted) = self.entries.pop_front()
+        {
+            self.count_retained(&evicted, false);
         }
+        self.count_retained(&entry, true);
         self.entries.push_back(entry);
     }
 
-    /// Clear all log entries and reset counters
+    fn count_retained(&mut self, entry: &LogEntry, added: bool) {
+        let counter = match entry.direction {
+            LogDirection::Rx => &mut self.retained_rx,
+            LogDirection::Tx => &mut self.retained_tx,
+            LogDirection::Event(_) => return,
+        };
+        if added {
+            *counter += 1;
+        } else {
+            *counter -= 1;
+        }
+    }
+
+    /// Number of RX and TX entries currently retained, as opposed to the
+    /// cumulative `rx_count`/`tx_count` which include evicted entries
+    pub fn retained_counts(&self) -> (usize, usize) {
+        (self.retained_rx, self.retained_tx)
+    }
+
+    /// Clear all log entries and reset the counters, except `totals`
     pub fn clear(&mut self) {
         self.entries.clear();
         self.rx_count = 0;
         self.tx_count = 0;
+        self.rx_bytes = 0;
+        self.tx_bytes = 0;
+        self.last_seq = 0;
+        self.retained_rx = 0;
+        self.retained_tx = 0;
+    }
+}
+
+/// Screen rows taken by the entries of a rendered log view.
+///
+/// Scroll positions, focus and selections count visible entries, while the
+/// view draws rows: an entry can take several rows (idle-gap separators,
+/// multi-line renderers). This maps between the two; the UI builds it in
+/// the same pass that renders the rows.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LogLayout {
+    /// First row of each visible entry, including any separator row drawn
+    /// above it
+    starts: Vec<usize>,
+    total_rows: usize,
+}
+
+impl LogLayout {
+    /// Layout of entries taking `rows` rows each, in order
+    pub fn from_rows(rows: impl IntoIterator<Item = usize>) -> Self {
+        let mut layout = Self::default();
+        for r in rows {
+            layout.push(r);
+        }
+        layout
+    }
+
+    /// Append the next visible entry, taking `rows` rows
+    pub fn push(&mut self, rows: usize) {
+        self.starts.push(self.total_rows);
+        self.total_rows += rows;
+    }
+
+    /// Number of visible entries
+    pub fn len(&self) -> usize {
+        self.starts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.starts.is_empty()
+    }
+
+    pub fn total_rows(&self) -> usize {
+        self.total_rows
+    }
+
+    /// First row of the entry at visible `position`, or the end of the
+    /// view if there is no such entry
+    pub fn start_of(&self, position: usize) -> usize {
+        self.starts
+            .get(position)
+            .copied()
+            .unwrap_or(self.total_rows)
+    }
+
+    /// Visible position of the entry drawn on `row`
+    pub fn position_at(&self, row: usize) -> Option<usize> {
+        if row >= self.total_rows {
+            return None;
+        }
+        Some(self.starts.partition_point(|&start| start <= row) - 1)
+    }
+
+    /// Last row that can be the top of a `viewport_rows` tall view
+    pub fn max_scroll_row(&self, viewport_rows: u16) -> usize {
+        self.total_rows
+            .saturating_sub(viewport_rows.max(1) as usize)
+    }
+
+    /// Last scroll position for a `viewport_rows` tall view: the first
+    /// entry starting on or after the last full page
+    pub fn max_scroll_position(&self, viewport_rows: u16) -> usize {
+        let max_row = self.max_scroll_row(viewport_rows);
+        self.starts
+            .partition_point(|&start| start < max_row)
+            .min(self.len().saturating_sub(1))
+    }
+
+    /// Row at the top of a `viewport_rows` tall view scrolled to entry
+    /// `scroll_offset`, or pinned to the bottom while following
+    pub fn top_row(&self, scroll_offset: u16, auto_scroll: bool, viewport_rows: u16) -> usize {
+        let max_row = self.max_scroll_row(viewport_rows);
+        if auto_scroll {
+            max_row
+        } else {
+            self.start_of(scroll_offset as usize).min(max_row)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_push_event_keeps_counters() {
+        let mut log = MessageLog::new();
+        log.push_rx(vec![1, 2]);
+        log.push_event(LogEventKind::Connect, "Connected");
+        log.push_tx(vec![3]);
+
+        assert_eq!(log.entries.len(), 3);
+        assert_eq!(log.rx_count, 1);
+        assert_eq!(log.tx_count, 1);
+        assert_eq!((log.rx_bytes, log.tx_bytes), (2, 1));
+
+        let event = &log.entries[1];
+        assert_eq!(event.event_kind(), Some(LogEventKind::Connect));
+        assert_eq!(event.data, b"Connected");
+        assert!(!log.entries[0].is_event());
+    }
+
+    #[test]
+    fn test_seq_assigned_in_order() {
+        let mut log = MessageLog::new();
+        log.push_rx(vec![1]);
+        log.push_event(LogEventKind::Marker, "--");
+        log.push_tx(vec![2]);
+        let seqs: Vec<u64> = log.entries.iter().map(|e| e.seq).collect();
+        assert_eq!(seqs, vec![1, 2, 3]);
+
+        log.clear();
+        log.push_rx(vec![1]);
+        assert_eq!(log.entries[0].seq, 1);
+    }
+
+    #[test]
+    fn test_retained_counts_diverge_after_eviction() {
+        let mut log = MessageLog::new();
+        log.push_tx(vec![0]);
+        log.push_event(LogEventKind::Marker, "start");
+        assert_eq!(log.retained_counts(), (0, 1));
+
+        for _ in 0..MAX_LOG_LINES {
+            log.push_rx(vec![1]);
+        }
+        // The TX entry and the marker were evicted
+        assert_eq!(log.entries.len(), MAX_LOG_LINES);
+        assert_eq!(log.retained_counts(), (MAX_LOG_LINES, 0));
+        assert_eq!(log.rx_count, MAX_LOG_LINES as u64);
+        assert_eq!(log.tx_count, 1);
+
+        for _ in 0..500 {
+            log.push_rx(vec![2]);
+        }
+        assert_eq!(log.rx_count, (MAX_LOG_LINES + 500) as u64);
+        assert_eq!(log.retained_counts().0, MAX_LOG_LINES);
+
+        log.clear();
+        assert_eq!(log.retained_counts(), (0, 0));
+    }
+
+    #[test]
+    fn test_native_tx_display_mode() {
+        let mut log = MessageLog::new();
+        log.push_tx_as(b"AT".to_vec(), TxMode::Ascii);
+        log.push_tx_as(vec![0x01], TxMode::Hex);
+        log.push_tx(vec![0x02]);
+        log.push_rx(b"OK".to_vec());
+        assert_eq!(log.tx_count, 3);
+
+        let modes = |global, native| -> Vec<DisplayMode> {
+            log.entries
+                .iter()
+                .map(|e| e.display_mode(global, native))
+                .collect()
+        };
+        use DisplayMode::{Hex, Text};
+        assert_eq!(modes(Hex, true), vec![Text, Hex, Hex, Hex]);
+        assert_eq!(modes(Text, true), vec![Text, Hex, Text, Text]);
+        assert_eq!(modes(Hex, false), vec![Hex; 4]);
+    }
+
+    #[test]
+    fn test_entry_is_printable_on_mixed_entries() {
+        let rx = |data: &[u8]| LogEntry::new(LogDirection::Rx, data.to_vec());
+        assert!(entry_is_printable(&rx(b"OK\r\n")));
+        assert!(entry_is_printable(&rx(b"a\tb ~")));
+        assert!(entry_is_printable(&rx(b"")));
+        assert!(!entry_is_printable(&rx(b"OK\x00")));
+        assert!(!entry_is_printable(&rx(&[0x1B, b'[', b'0', b'm'])));
+        assert!(!entry_is_printable(&rx(&[0x7F])));
+        assert!(!entry_is_printable(&rx("温度".as_bytes())));
+        assert!(!entry_is_printable(&LogEntry::new(
+            LogDirection::Tx,
+            vec![0xFF, 0x01]
+        )));
+
+        let event = LogEntry::new(
+            LogDirection::Event(LogEventKind::Error),
+            "失败 \x01".as_bytes().to_vec(),
+        );
+        assert!(entry_is_printable(&event));
+    }
+
+    #[test]
+    fn test_layout_maps_rows_to_entries() {
+        // Second entry has a gap row above it, third renders two rows
+        let layout = LogLayout::from_rows([1, 2, 2, 1]);
+        assert_eq!(layout.total_rows(), 6);
+        assert_eq!(layout.start_of(2), 3);
+        assert_eq!(layout.start_of(9), 6);
+        let positions: Vec<_> = (0..7).map(|row| layout.position_at(row)).collect();
+        assert_eq!(
+            positions,
+            vec![Some(0), Some(1), Some(1), Some(2), Some(2), Some(3), None]
+        );
+    }
+
+    #[test]
+    fn test_layout_scroll_limits() {
+        let layout = LogLayout::from_rows([1, 2, 2, 1]);
+        // Three-row view: the last page starts on row 3, entry 2
+        assert_eq!(layout.max_scroll_row(3), 3);
+        assert_eq!(layout.max_scroll_position(3), 2);
+        assert_eq!(layout.top_row(1, false, 3), 1);
+        assert_eq!(layout.top_row(3, false, 3), 3);
+        assert_eq!(layout.top_row(0, true, 3), 3);
+        // Taller than the content: nothing to scroll
+        assert_eq!(layout.max_scroll_position(10), 0);
+        assert_eq!(layout.top_row(2, false, 10), 0);
+        assert_eq!(LogLayout::default().max_scroll_position(5), 0);
     }
 }
@@ -8,12 +8,15 @@
 
 use rust_i18n::t;
 
+use crate::settings::LogColumn;
+
 /// Menu action that can be triggered
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum MenuAction {
     // File menu
     SaveConfig,
     LoadConfig,
+    ExportLog,
     Exit,
 
     // Session menu (for multi-session support)
@@ -27,11 +30,37 @@ pub enum MenuAction {
     ViewSplitHorizontal,
     ViewSplitVertical,
     ViewGrid2x2,
+    ViewDualLog,
     ViewNextPane,
     ViewPrevPane,
+    ToggleTimestampColumn,
+    ToggleDirectionColumn,
+    ToggleLengthColumn,
+    ToggleSeqColumn,
 
     // Settings menu
     ToggleLanguage,
+    ToggleLogEvents,
+    CycleCountDisplay,
+    ToggleIdleGaps,
+    ToggleConfigStaging,
+    ToggleNativeTxDisplay,
+    ToggleCompactConfig,
+    CyclePasteAppend,
+    ToggleBitOrder,
+    ToggleHighBitMask,
+    ToggleControlPictures,
+    ToggleTxFocusLock,
+    ToggleSendConfirmation,
+    ToggleQuitConfirmation,
+    ToggleRxChunkTiming,
+    CycleDisconnectedRx,
+    ToggleSelectionScrollLock,
+    ToggleAutoConnect,
+    ToggleClearLogOnConnect,
+    CycleFraming,
+    SetTxPrefix,
+    SetTxSuffix,
 
     // Plugins menu
     PluginsInstall,
@@ -46,17 +75,30 @@ pub enum MenuAction {
     ShowAbout,
     OpenConfigDir,
     OpenLogDir,
+    CopyDiagnostics,
 
     // Special
     Separator, // Not an action, just for display
 }
 
 impl MenuAction {
+    /// Log prefix column shown or hidden by this action
+    pub fn log_column(&self) -> Option<LogColumn> {
+        match self {
+            MenuAction::ToggleTimestampColumn => Some(LogColumn::Timestamp),
+            MenuAction::ToggleDirectionColumn => Some(LogColumn::Direction),
+            MenuAction::ToggleLengthColumn => Some(LogColumn::Length),
+            MenuAction::ToggleSeqColumn => Some(LogColumn::Seq),
+            _ => None,
+        }
+    }
+
     /// Get the translation key for this action
     pub fn label_key(&self) -> &'static str {
         match self {
             MenuAction::SaveConfig => "menu.file.save_config",
             MenuAction::LoadConfig => "menu.file.load_config",
+            MenuAction::ExportLog => "menu.file.export_log",
             MenuAction::Exit => "menu.file.exit",
             MenuAction::NewSession => "menu.session.new",
             MenuAction::DuplicateSession => "menu.session.duplicate",
@@ -66,13 +108,40 @@ impl MenuAction {
             MenuAction::ViewSplitHorizontal => "menu.view.split_h",
             MenuAction::ViewSplitVertical => "menu.view.split_v",
             MenuAction::ViewGrid2x2 => "menu.view.grid_2x2",
+            MenuAction::ViewDualLog => "menu.view.dual_log",
             MenuAction::ViewNextPane => "menu.view.next_pane",
             MenuAction::ViewPrevPane => "menu.view.prev_pane",
+            MenuAction::ToggleTimestampColumn => "menu.view.column_timestamp",
+            MenuAction::ToggleDirectionColumn => "menu.view.column_direction",
+            MenuAction::ToggleLengthColumn => "menu.view.column_length",
+            MenuAction::ToggleSeqColumn => "menu.view.column_seq",
             MenuAction::ToggleLanguage => "menu.settings.toggle_language",
+            MenuAction::ToggleLogEvents => "menu.settings.toggle_log_events",
+            MenuAction::CycleCountDisplay => "menu.settings.count_display",
+            MenuAction::ToggleIdleGaps => "menu.settings.idle_gaps",
+            MenuAction::ToggleConfigStaging => "menu.settings.config_staging",
+            MenuAction::ToggleNativeTxDisplay => "menu.settings.native_tx_display",
+            MenuAction::ToggleCompactConfig => "menu.settings.compact_config",
+            MenuAction::CyclePasteAppend => "menu.settings.paste_append",
+            MenuAction::ToggleBitOrder => "menu.settings.bit_order",
+            MenuAction::ToggleHighBitMask => "menu.settings.mask_high_bit",
+            MenuAction::ToggleControlPictures => "menu.settings.control_pictures",
+            MenuAction::ToggleTxFocusLock => "menu.settings.tx_focus_lock",
+            MenuAction::ToggleSendConfirmation => "menu.settings.confirm_large_sends",
+            MenuAction::ToggleQuitConfirmation => "menu.settings.confirm_quit",
+            MenuAction::ToggleRxChunkTiming => "menu.settings.rx_chunk_timing",
+            MenuAction::CycleDisconnectedRx => "menu.settings.disconnected_rx",
+            MenuAction::ToggleSelectionScrollLock => "menu.settings.selection_scroll_lock",
+            MenuAction::ToggleAutoConnect => "menu.settings.auto_connect",
+            MenuAction::ToggleClearLogOnConnect => "menu.settings.clear_log_on_connect",
+            MenuAction::CycleFraming => "menu.settings.framing",
+            MenuAction::SetTxPrefix => "menu.settings.tx_prefix",
+            MenuAction::SetTxSuffix => "menu.settings.tx_suffix",
             MenuAction::ShowShortcuts => "menu.help.shortcuts",
             MenuAction::ShowAbout => "menu.help.about",
             MenuAction::OpenConfigDir => "menu.help.open_config_dir",
             MenuAction::OpenLogDir => "menu.help.open_log_dir",
+            MenuAction::CopyDiagnostics => "menu.help.copy_diagnostics",
             MenuAction::PluginsInstall => "menu.plugins.install",
             MenuAction::PluginsCheckUpdate => "menu.plugins.check_update",
             MenuAction::PluginsUpdateAll => "menu.plugins.update_all",
@@ -149,6 +218,7 @@ impl MenuBar {
 const FILE_MENU_ITEMS: &[MenuAction] = &[
     MenuAction::SaveConfig,
     MenuAction::LoadConfig,
+    MenuAction::ExportLog,
     MenuAction::Separator,
     MenuAction::Exit,
 ];
@@ -166,12 +236,41 @@ const VIEW_MENU_ITEMS: &[MenuAction] = &[
     MenuAction::ViewSplitHorizontal,
     MenuAction::ViewSplitVertical,
     MenuAction::ViewGrid2x2,
+    MenuAction::ViewDualLog,
     MenuAction::Separator,
     MenuAction::ViewNextPane,
     MenuAction::ViewPrevPane,
+    MenuAction::Separator,
+    MenuAction::ToggleTimestampColumn,
+    MenuAction::ToggleDirectionColumn,
+    MenuAction::ToggleLengthColumn,
+    MenuAction::ToggleSeqColumn,
 ];
 
-const SETTINGS_MENU_ITEMS: &[MenuAction] = &[MenuAction::ToggleLanguage];
+const SETTINGS_MENU_ITEMS: &[MenuAction] = &[
+    MenuAction::ToggleLanguage,
+    MenuAction::ToggleLogEvents,
+    MenuAction::CycleCountDisplay,
+    MenuAction::ToggleIdleGaps,
+    MenuAction::ToggleConfigStaging,
+    MenuAction::ToggleNativeTxDisplay,
+    MenuAction::ToggleCompactConfig,
+    MenuAction::CyclePasteAppend,
+    MenuAction::ToggleBitOrder,
+    MenuAction::ToggleHighBitMask,
+    MenuAction::ToggleControlPictures,
+    MenuAction::ToggleTxFocusLock,
+    MenuAction::ToggleSendConfirmation,
+    MenuAction::ToggleQuitConfirmation,
+    MenuAction::ToggleRxChunkTiming,
+    MenuAction::CycleDisconnectedRx,
+    MenuAction::ToggleSelectionScrollLock,
+    MenuAction::ToggleAutoConnect,
+    MenuAction::ToggleClearLogOnConnect,
+    MenuAction::CycleFraming,
+    MenuAction::SetTxPrefix,
+    MenuAction::SetTxSuffix,
+];
 
 const PLUGINS_MENU_ITEMS: &[MenuAction] = &[
     MenuAction::PluginsManager,
@@ -189,6 +288,7 @@ const HELP_MENU_ITEMS: &[MenuAction] = &[
     MenuAction::Separator,
     MenuAction::OpenConfigDir,
     MenuAction::OpenLogDir,
+    MenuAction::CopyDiagnostics,
     MenuAction::Separator,
     MenuAction::ShowAbout,
 ];
@@ -264,18 +364,19 @@ mod tests {
     #[test]
     fn test_menu_bar_structure() {
         assert_eq!(MENU_BAR.menu_count(), 6);
-        assert_eq!(MENU_BAR.get_item_count(0), 4); // File: Save, Load, Sep, Exit
+        assert_eq!(MENU_BAR.get_item_count(0), 5); // File: Save, Load, Export, Sep, Exit
         assert_eq!(MENU_BAR.get_item_count(1), 5); // Session
-        assert_eq!(MENU_BAR.get_item_count(2), 7); // View
-        assert_eq!(MENU_BAR.get_item_count(3), 1); // Settings
+        assert_eq!(MENU_BAR.get_item_count(2), 13); // View: layouts, Sep, panes, Sep, log columns
+        assert_eq!(MENU_BAR.get_item_count(3), 22); // Settings: Language, LogEvents, Counts, Gaps, Staging, NativeTx, Compact, PasteAppend, BitOrder, HighBitMask, ControlPictures, TxFocusLock, SendConfirmation, QuitConfirmation, RxChunkTiming, DisconnectedRx, SelectionScrollLock, AutoConnect, ClearLogOnConnect, Framing, TxPrefix, TxSuffix
         assert_eq!(MENU_BAR.get_item_count(4), 8); // Plugins: Manager, Sep, Install, CheckUpdate, UpdateAll, Sep, List, Reload
-        assert_eq!(MENU_BAR.get_item_count(5), 6); // Help: Shortcuts, Sep, OpenConfigDir, OpenLogDir, Sep, About
+        assert_eq!(MENU_BAR.get_item_count(5), 7); // Help: Shortcuts, Sep, OpenConfigDir, OpenLogDir, CopyDiagnostics, Sep, About
     }
 
     #[test]
     fn test_menu_actions() {
         assert_eq!(MENU_BAR.get_action(0, 0), Some(MenuAction::SaveConfig));
-        assert_eq!(MENU_BAR.get_action(0, 3), Some(MenuAction::Exit));
+        assert_eq!(MENU_BAR.get_action(0, 2), Some(MenuAction::ExportLog));
+        assert_eq!(MENU_BAR.get_action(0, 4), Some(MenuAction::Exit));
         assert_eq!(MENU_BAR.get_action(5, 0), Some(MenuAction::ShowShortcuts));
     }
 
@@ -297,4 +398,19 @@ mod tests {
         assert_eq!(MENU_BAR.get_menu_label_key(1), Some("menu.session"));
         assert_eq!(MENU_BAR.get_menu_label_key(5), Some("menu.help"));
     }
+
+    #[test]
+    fn test_log_column_actions() {
+        assert_eq!(
+            MenuAction::ToggleSeqColumn.log_column(),
+            Some(LogColumn::Seq)
+        );
+        assert_eq!(MenuAction::ViewDualLog.log_column(), None);
+        for column in LogColumn::all() {
+            let action = VIEW_MENU_ITEMS
+                .iter()
+                .find(|a| a.log_column() == Some(column));
+            assert!(action.is_some(), "{column:?} has no menu item");
+        }
+    }
 }
@@ -0,0 +1,135 @@
+//! Connection quality from recent I/O outcomes
+//!
+//! Flaky USB adapters tend to fail intermittently rather than outright, so
+//! the share of failed reads and writes over the last few operations makes
+//! a better health signal than the consecutive-error counter used for
+//! auto-disconnect. Empty reads (timeouts) are neither a success nor an
+//! error and are not recorded.
+
+use std::collections::VecDeque;
+
+/// Reads and writes the error rate is computed over
+pub const QUALITY_WINDOW: usize = 100;
+
+/// Error rate (in percent) from which the link counts as degraded
+pub const DEGRADED_ERROR_PERCENT: usize = 2;
+
+/// Error rate (in percent) from which the link counts as poor
+pub const POOR_ERROR_PERCENT: usize = 10;
+
+/// Health of the connection over the window
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LinkQuality {
+    Good,
+    Degraded,
+    Poor,
+}
+
+/// Classify `errors` failures among `total` operations; `None` without any
+pub fn classify_quality(errors: usize, total: usize) -> Option<LinkQuality> {
+    if total == 0 {
+        return None;
+    }
+    let percent = errors * 100;
+    Some(if percent >= POOR_ERROR_PERCENT * total {
+        LinkQuality::Poor
+    } else if percent >= DEGRADED_ERROR_PERCENT * total {
+        LinkQuality::Degraded
+    } else {
+        LinkQuality::Good
+    })
+}
+
+/// Outcomes of the last [`QUALITY_WINDOW`] reads and writes
+#[derive(Debug, Clone, Default)]
+pub struct QualityWindow {
+    /// `true` for a success, oldest first
+    outcomes: VecDeque<bool>,
+    errors: usize,
+}
+
+impl QualityWindow {
+    /// Record one read or write; the oldest outcome leaves the window
+    pub fn record(&mut self, ok: bool) {
+        if self.outcomes.len() == QUALITY_WINDOW && self.outcomes.pop_front() == Some(false) {
+            self.errors -= 1;
+        }
+        self.outcomes.push_back(ok);
+        if !ok {
+            self.errors += 1;
+        }
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Failed operations in the window
+    pub fn errors(&self) -> usize {
+        self.errors
+    }
+
+    /// Operations in the window
+    pub fn len(&self) -> usize {
+        self.outcomes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.outcomes.is_empty()
+    }
+
+    /// Failed share of the window in percent, `None` while empty
+    pub fn error_percent(&self) -> Option<f64> {
+        (!self.is_empty()).then(|| self.errors as f64 * 100.0 / self.len() as f64)
+    }
+
+    /// Quality over the window, `None` while empty
+    pub fn quality(&self) -> Option<LinkQuality> {
+        classify_quality(self.errors, self.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify_thresholds() {
+        assert_eq!(classify_quality(0, 0), None);
+        assert_eq!(classify_quality(0, 100), Some(LinkQuality::Good));
+        assert_eq!(classify_quality(1, 100), Some(LinkQuality::Good));
+        assert_eq!(classify_quality(2, 100), Some(LinkQuality::Degraded));
+        assert_eq!(classify_quality(9, 100), Some(LinkQuality::Degraded));
+        assert_eq!(classify_quality(10, 100), Some(LinkQuality::Poor));
+        assert_eq!(classify_quality(3, 3), Some(LinkQuality::Poor));
+        // A single error early on weighs more than in a full window
+        assert_eq!(classify_quality(1, 20), Some(LinkQuality::Degraded));
+    }
+
+    #[test]
+    fn test_window_slides() {
+        let mut window = QualityWindow::default();
+        assert_eq!(window.quality(), None);
+        for _ in 0..10 {
+            window.record(false);
+        }
+        assert_eq!(window.quality(), Some(LinkQuality::Poor));
+        assert_eq!(window.error_percent(), Some(100.0));
+
+        // Successes push the errors out of the window
+        for _ in 0..QUALITY_WINDOW - 5 {
+            window.record(true);
+        }
+        assert_eq!(window.len(), QUALITY_WINDOW);
+        assert_eq!(window.errors(), 5);
+        assert_eq!(window.quality(), Some(LinkQuality::Degraded));
+        for _ in 0..5 {
+            window.record(true);
+        }
+        assert_eq!(window.errors(), 0);
+        assert_eq!(window.quality(), Some(LinkQuality::Good));
+
+        window.clear();
+        assert!(window.is_empty());
+    }
+}
@@ -0,0 +1,171 @@
+//! Byte-rate tracking over a rolling window
+//!
+//! Bytes are summed into fixed-width time buckets held in a ring buffer, so
+//! recording is O(1) and the rate never needs the individual reads. A
+//! bucket is reused once it falls out of the window; buckets that were not
+//! written to since then are simply ignored when summing, which is what
+//! makes the rate decay to zero when the line goes quiet.
+
+use std::time::{Duration, Instant};
+
+/// Buckets in the rolling window
+pub const RATE_BUCKETS: usize = 10;
+
+/// Default width of the rolling window
+pub const DEFAULT_RATE_WINDOW: Duration = Duration::from_secs(1);
+
+/// Byte rate over the last `window`, in [`RATE_BUCKETS`] buckets
+#[derive(Debug, Clone)]
+pub struct RateTracker {
+    bucket_width: Duration,
+    /// `(slot, bytes)` per bucket, slot `n` stored at `n % RATE_BUCKETS`
+    buckets: [(u64, u64); RATE_BUCKETS],
+    /// Start of slot 0, set by the first record
+    origin: Option<Instant>,
+    peak: f64,
+    total: u64,
+}
+
+impl Default for RateTracker {
+    fn default() -> Self {
+        Self::new(DEFAULT_RATE_WINDOW)
+    }
+}
+
+impl RateTracker {
+    /// Tracker averaging over `window` (at least one bucket per millisecond)
+    pub fn new(window: Duration) -> Self {
+        let bucket_width = (window / RATE_BUCKETS as u32).max(Duration::from_millis(1));
+        Self {
+            bucket_width,
+            buckets: [(0, 0); RATE_BUCKETS],
+            origin: None,
+            peak: 0.0,
+            total: 0,
+        }
+    }
+
+    /// Width of the rolling window
+    pub fn window(&self) -> Duration {
+        self.bucket_width * RATE_BUCKETS as u32
+    }
+
+    /// Record `bytes` transferred at `at`
+    pub fn record(&mut self, bytes: usize, at: Instant) {
+        let origin = *self.origin.get_or_insert(at);
+        let slot = self.slot(origin, at);
+        let bucket = &mut self.buckets[slot as usize % RATE_BUCKETS];
+        if bucket.0 != slot {
+            *bucket = (slot, 0);
+        }
+        bucket.1 += bytes as u64;
+        self.total += bytes as u64;
+        self.peak = self.peak.max(self.rate_at_slot(slot));
+    }
+
+    /// Bytes per second over the window ending now
+    pub fn bytes_per_sec(&self) -> f64 {
+        self.bytes_per_sec_at(Instant::now())
+    }
+
+    /// Bytes per second over the window ending at `now`
+    pub fn bytes_per_sec_at(&self, now: Instant) -> f64 {
+        match self.origin {
+            Some(origin) => self.rate_at_slot(self.slot(origin, now)),
+            None => 0.0,
+        }
+    }
+
+    /// Highest rate seen when recording, in bytes per second
+    pub fn peak(&self) -> f64 {
+        self.peak
+    }
+
+    /// Bytes recorded since creation or the last clear
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::new(self.window());
+    }
+
+    fn slot(&self, origin: Instant, at: Instant) -> u64 {
+        (at.saturating_duration_since(origin).as_nanos() / self.bucket_width.as_nanos()) as u64
+    }
+
+    /// Rate over the buckets up to and including `slot`
+    fn rate_at_slot(&self, slot: u64) -> f64 {
+        let oldest = slot.saturating_sub(RATE_BUCKETS as u64 - 1);
+        let bytes: u64 = self
+            .buckets
+            .iter()
+            .filter(|(s, _)| (oldest..=slot).contains(s))
+            .map(|(_, b)| b)
+            .sum();
+        bytes as f64 / self.window().as_secs_f64()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    #[test]
+    fn test_steady_rate() {
+        let start = Instant::now();
+        let mut rate = RateTracker::default();
+        // 100 bytes every 100 ms for 3 s: 1000 B/s
+        for i in 0..30 {
+            rate.record(100, start + ms(i * 100));
+        }
+        let now = start + ms(2950);
+        assert_eq!(rate.bytes_per_sec_at(now), 1000.0);
+        assert_eq!(rate.peak(), 1000.0);
+        assert_eq!(rate.total(), 3000);
+    }
+
+    #[test]
+    fn test_burst_sets_peak() {
+        let start = Instant::now();
+        let mut rate = RateTracker::default();
+        rate.record(10, start);
+        rate.record(5000, start + ms(500));
+        rate.record(10, start + ms(600));
+        assert_eq!(rate.bytes_per_sec_at(start + ms(650)), 5020.0);
+        // The burst leaves the window, the peak stays
+        assert_eq!(rate.bytes_per_sec_at(start + ms(1550)), 10.0);
+        assert_eq!(rate.peak(), 5020.0);
+    }
+
+    #[test]
+    fn test_decays_to_zero_when_idle() {
+        let start = Instant::now();
+        let mut rate = RateTracker::default();
+        assert_eq!(rate.bytes_per_sec_at(start), 0.0);
+        rate.record(400, start);
+        assert_eq!(rate.bytes_per_sec_at(start + ms(900)), 400.0);
+        assert_eq!(rate.bytes_per_sec_at(start + ms(1000)), 0.0);
+        assert_eq!(rate.bytes_per_sec_at(start + Duration::from_secs(60)), 0.0);
+    }
+
+    #[test]
+    fn test_window_eviction_reuses_buckets() {
+        let start = Instant::now();
+        let mut rate = RateTracker::new(ms(500));
+        assert_eq!(rate.window(), ms(500));
+        rate.record(100, start);
+        // Same ring position one window later: the old bytes are dropped
+        rate.record(7, start + ms(500));
+        assert_eq!(rate.bytes_per_sec_at(start + ms(500)), 14.0);
+
+        rate.clear();
+        assert_eq!(rate.total(), 0);
+        assert_eq!(rate.peak(), 0.0);
+        assert_eq!(rate.window(), ms(500));
+    }
+}
@@ -0,0 +1,33 @@
+//! Pluggable log rendering
+//!
+//! The log view renders entry data through a [`DisplayRenderer`]. The
+//! built-in HEX and TEXT modes are renderers too; embedders register their
+//! own (e.g. a protocol decoder) with `AppState::register_renderer`, and the
+//! display mode toggle cycles through them after the built-in modes.
+
+use ratatui::text::Line;
+
+use crate::log::LogEntry;
+
+/// Renders the data of one log entry for the log view
+pub trait DisplayRenderer {
+    /// Lines for `entry`'s data. The first line follows the entry's prefix
+    /// columns (timestamp, direction, ...); further lines appear below it.
+    /// Only called for RX/TX entries, never for inline events.
+    fn render(&self, entry: &LogEntry) -> Vec<Line<'static>>;
+}
+
+/// A registered custom renderer and the name shown for it in the log title
+pub struct NamedRenderer {
+    pub name: String,
+    pub renderer: Box<dyn DisplayRenderer>,
+}
+
+impl NamedRenderer {
+    pub fn new(name: impl Into<String>, renderer: impl DisplayRenderer + 'static) -> Self {
+        Self {
+            name: name.into(),
+            renderer: Box::new(renderer),
+        }
+    }
+}
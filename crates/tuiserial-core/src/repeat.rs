@@ -0,0 +1,257 @@
+//! Repeat-send scheduling
+//!
+//! This module tracks a payload that is re-sent on a fixed interval, either
+//! indefinitely or a bounded number of times. It only decides *when* a send
+//! is due; the actual write happens in the frontend's event loop.
+
+use std::time::{Duration, Instant};
+
+use crate::types::TxMode;
+
+/// Shortest interval accepted for repeated sends
+pub const MIN_REPEAT_INTERVAL: Duration = Duration::from_millis(10);
+
+/// A payload scheduled for repeated transmission
+#[derive(Debug, Clone)]
+pub struct RepeatSend {
+    pub payload: Vec<u8>,
+    pub interval: Duration,
+    /// Total number of sends, `None` for an indefinite repeat
+    pub total: Option<u32>,
+    /// Number of sends completed so far
+    pub sent: u32,
+    /// Mode the payload was encoded in, recorded on each send's log entry
+    pub mode: Option<TxMode>,
+    next_due: Instant,
+}
+
+impl RepeatSend {
+    /// Repeat until cancelled; the first send is due immediately
+    pub fn indefinite(payload: Vec<u8>, interval: Duration, now: Instant) -> Self {
+        Self {
+            payload,
+            interval: interval.max(MIN_REPEAT_INTERVAL),
+            total: None,
+            sent: 0,
+            mode: None,
+            next_due: now,
+        }
+    }
+
+    /// Repeat exactly `count` times; the first send is due immediately
+    pub fn bounded(payload: Vec<u8>, count: u32, interval: Duration, now: Instant) -> Self {
+        Self {
+            total: Some(count),
+            ..Self::indefinite(payload, interval, now)
+        }
+    }
+
+    /// Sends left before auto-stop, `None` for an indefinite repeat
+    pub fn remaining(&self) -> Option<u32> {
+        self.total.map(|t| t.saturating_sub(self.sent))
+    }
+
+    /// Check whether all bounded sends have completed
+    pub fn is_finished(&self) -> bool {
+        self.remaining() == Some(0)
+    }
+
+    /// Check whether the next send is due
+    pub fn is_due(&self, now: Instant) -> bool {
+        !self.is_finished() && now >= self.next_due
+    }
+
+    /// Time until the next send is due (zero if already due)
+    pub fn time_until_due(&self, now: Instant) -> Duration {
+        self.next_due.saturating_duration_since(now)
+    }
+
+    /// Record a completed send and schedule the next one
+    pub fn record_sent(&mut self, now: Instant) {
+        self.sent = self.sent.saturating_add(1);
+        self.next_due = now + self.interval;
+    }
+
+    /// Schedule the next send without counting this one (e.g. a plugin
+    /// suppressed it)
+    pub fn record_skipped(&mut self, now: Instant) {
+        self.next_due = now + self.interval;
+    }
+
+    /// Progress string, e.g. `37/100` or `37` for indefinite repeats
+    pub fn progress(&self) -> String {
+        match self.total {
+            Some(total) => format!("{}/{}", self.sent, total),
+            None => self.sent.to_string(),
+        }
+    }
+}
+
+/// Field being edited in the repeat prompt
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RepeatPromptField {
+    Count,
+    Interval,
+}
+
+/// Numeric prompt for starting a bounded repeat
+#[derive(Debug, Clone)]
+pub struct RepeatPrompt {
+    pub count: String,
+    pub interval_ms: String,
+    pub field: RepeatPromptField,
+}
+
+impl Default for RepeatPrompt {
+    fn default() -> Self {
+        Self {
+            count: "10".to_string(),
+            interval_ms: "1000".to_string(),
+            field: RepeatPromptField::Count,
+        }
+    }
+}
+
+impl RepeatPrompt {
+    /// Append a digit to the focused field (non-digits are ignored)
+    pub fn push_digit(&mut self, c: char) {
+        if !c.is_ascii_digit() {
+            return;
+        }
+        let field = self.focused_mut();
+        if field.len() < 9 {
+            field.push(c);
+        }
+    }
+
+    /// Remove the last character from the focused field
+    pub fn backspace(&mut self) {
+        self.focused_mut().pop();
+    }
+
+    /// Switch focus between count and interval
+    pub fn toggle_field(&mut self) {
+        self.field = match self.field {
+            RepeatPromptField::Count => RepeatPromptField::Interval,
+            RepeatPromptField::Interval => RepeatPromptField::Count,
+        };
+    }
+
+    /// Parse the prompt into `(count, interval)`. An empty or zero count
+    /// means repeat until stopped (`None`); returns `None` if the interval
+    /// is empty or zero.
+    pub fn parse(&self) -> Option<(Option<u32>, Duration)> {
+        let count = self.count.parse::<u32>().ok().filter(|&c| c > 0);
+        let ms: u64 = self.interval_ms.parse().ok().filter(|&ms| ms > 0)?;
+        Some((count, Duration::from_millis(ms)))
+    }
+
+    /// Schedule `payload`, encoded in `mode`, as the prompt describes;
+    /// the first send is due at `now`
+    pub fn build(&self, payload: Vec<u8>, mode: TxMode, now: Instant) -> Option<RepeatSend> {
+        let (count, interval) = self.parse()?;
+        let repeat = match count {
+            Some(count) => RepeatSend::bounded(payload, count, interval, now),
+            None => RepeatSend::indefinite(payload, interval, now),
+        };
+        Some(RepeatSend {
+            mode: Some(mode),
+            ..repeat
+        })
+    }
+
+    fn focused_mut(&mut self) -> &mut String {
+        match self.field {
+            RepeatPromptField::Count => &mut self.count,
+            RepeatPromptField::Interval => &mut self.interval_ms,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bounded_countdown_and_auto_stop() {
+        let start = Instant::now();
+        let interval = Duration::from_millis(100);
+        let mut repeat = RepeatSend::bounded(vec![0x41], 3, interval, start);
+
+        assert!(repeat.is_due(start));
+        assert_eq!(repeat.remaining(), Some(3));
+
+        repeat.record_sent(start);
+        assert_eq!(repeat.remaining(), Some(2));
+        assert!(!repeat.is_due(start));
+        assert!(repeat.is_due(start + interval));
+
+        repeat.record_sent(start + interval);
+        repeat.record_sent(start + interval * 2);
+        assert_eq!(repeat.remaining(), Some(0));
+        assert!(repeat.is_finished());
+        assert!(!repeat.is_due(start + interval * 10));
+        assert_eq!(repeat.progress(), "3/3");
+    }
+
+    #[test]
+    fn test_indefinite_never_finishes() {
+        let start = Instant::now();
+        let mut repeat = RepeatSend::indefinite(vec![0x41], Duration::from_millis(50), start);
+        for i in 0..1000 {
+            repeat.record_sent(start + Duration::from_millis(50 * i));
+        }
+        assert_eq!(repeat.remaining(), None);
+        assert!(!repeat.is_finished());
+        assert_eq!(repeat.progress(), "1000");
+    }
+
+    #[test]
+    fn test_interval_clamped() {
+        let repeat = RepeatSend::bounded(vec![], 1, Duration::ZERO, Instant::now());
+        assert_eq!(repeat.interval, MIN_REPEAT_INTERVAL);
+    }
+
+    #[test]
+    fn test_prompt_parse() {
+        let mut prompt = RepeatPrompt {
+            count: String::new(),
+            interval_ms: String::new(),
+            field: RepeatPromptField::Count,
+        };
+        assert!(prompt.parse().is_none());
+
+        prompt.push_digit('1');
+        prompt.push_digit('x');
+        prompt.push_digit('0');
+        prompt.toggle_field();
+        prompt.push_digit('2');
+        prompt.push_digit('5');
+        prompt.backspace();
+        assert_eq!(prompt.parse(), Some((Some(10), Duration::from_millis(2))));
+
+        // Zero or empty count repeats until stopped
+        prompt.count = "0".into();
+        assert_eq!(prompt.parse(), Some((None, Duration::from_millis(2))));
+        prompt.count.clear();
+        let repeat = prompt
+            .build(vec![0x41], TxMode::Ascii, Instant::now())
+            .unwrap();
+        assert_eq!(repeat.total, None);
+        assert_eq!(repeat.mode, Some(TxMode::Ascii));
+
+        prompt.interval_ms = "0".into();
+        assert!(prompt.parse().is_none());
+    }
+
+    #[test]
+    fn test_skipped_send_is_not_counted() {
+        let start = Instant::now();
+        let interval = Duration::from_millis(100);
+        let mut repeat = RepeatSend::bounded(vec![0x41], 2, interval, start);
+        repeat.record_skipped(start);
+        assert_eq!(repeat.remaining(), Some(2));
+        assert!(!repeat.is_due(start));
+        assert!(repeat.is_due(start + interval));
+    }
+}
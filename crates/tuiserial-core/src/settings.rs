@@ -0,0 +1,407 @@
+//! User preferences
+//!
+//! This module defines view and behaviour preferences that are persisted
+//! next to the serial configuration. Unlike `SerialConfig`, nothing here
+//! affects how the port is opened.
+
+use serde::{Deserialize, Serialize};
+
+/// Default size in bytes of a single serial port read
+pub const DEFAULT_RX_READ_BUFFER: usize = 256;
+
+/// Default idle time before a gap marker is drawn in the log
+pub const DEFAULT_IDLE_GAP_MS: u64 = 1000;
+
+/// Default payload size above which a send asks for confirmation
+pub const DEFAULT_LARGE_SEND_THRESHOLD: usize = 1024;
+
+/// Accepted range for `AppSettings::rx_read_buffer`
+const RX_READ_BUFFER_RANGE: (usize, usize) = (16, 64 * 1024);
+
+/// A component of the prefix rendered before each log entry's data
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LogColumn {
+    /// `[HH:MM:SS.mmm]`
+    Timestamp,
+    /// `< RX` / `> TX`
+    Direction,
+    /// `(   N B)`
+    Length,
+    /// `#N` — per-log sequence number
+    Seq,
+}
+
+impl LogColumn {
+    /// Get all available columns in their default order
+    pub fn all() -> Vec<LogColumn> {
+        vec![
+            LogColumn::Timestamp,
+            LogColumn::Direction,
+            LogColumn::Length,
+            LogColumn::Seq,
+        ]
+    }
+}
+
+/// Ordered selection of log prefix columns
+///
+/// The data itself is always rendered last and is not part of this list.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LogColumns(Vec<LogColumn>);
+
+impl Default for LogColumns {
+    fn default() -> Self {
+        Self(vec![
+            LogColumn::Timestamp,
+            LogColumn::Direction,
+            LogColumn::Length,
+        ])
+    }
+}
+
+impl LogColumns {
+    /// Create a column layout, dropping duplicates while keeping first occurrence order
+    pub fn new(columns: impl IntoIterator<Item = LogColumn>) -> Self {
+        let mut out = Vec::new();
+        for col in columns {
+            if !out.contains(&col) {
+                out.push(col);
+            }
+        }
+        Self(out)
+    }
+
+    /// Columns in display order
+    pub fn columns(&self) -> &[LogColumn] {
+        &self.0
+    }
+
+    /// Check whether a column is shown
+    pub fn contains(&self, column: LogColumn) -> bool {
+        self.0.contains(&column)
+    }
+
+    /// Show a hidden column (appended at the end) or hide a shown one
+    pub fn toggle(&mut self, column: LogColumn) {
+        if let Some(pos) = self.0.iter().position(|&c| c == column) {
+            self.0.remove(pos);
+        } else {
+            self.0.push(column);
+        }
+    }
+}
+
+/// Which RX/TX counts the status bar shows
+///
+/// The counters in `MessageLog` are cumulative since the last clear and keep
+/// growing after old entries are evicted at `MAX_LOG_LINES`, so they can
+/// exceed what is actually retained in the log.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum CountDisplay {
+    /// Cumulative count since the log was last cleared
+    #[default]
+    Total,
+    /// Entries currently retained in the log
+    Retained,
+    /// `N total / M shown`
+    Both,
+}
+
+impl CountDisplay {
+    /// Cycle to the next display option
+    pub fn next(self) -> Self {
+        match self {
+            CountDisplay::Total => CountDisplay::Retained,
+            CountDisplay::Retained => CountDisplay::Both,
+            CountDisplay::Both => CountDisplay::Total,
+        }
+    }
+}
+
+/// Where the append-mode terminator goes when clipboard content is sent
+/// directly (Ctrl+Shift+V)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum PasteAppend {
+    /// Send the content without a terminator
+    Never,
+    /// One terminator after the whole content
+    #[default]
+    Once,
+    /// Send each line followed by the terminator, replacing the pasted line
+    /// breaks (e.g. a multi-line script that needs CRLF per line)
+    EachLine,
+}
+
+impl PasteAppend {
+    /// Cycle to the next option
+    pub fn next(self) -> Self {
+        match self {
+            PasteAppend::Once => PasteAppend::EachLine,
+            PasteAppend::EachLine => PasteAppend::Never,
+            PasteAppend::Never => PasteAppend::Once,
+        }
+    }
+}
+
+/// What happens to RX data read while the connection is not `Connected`,
+/// e.g. bytes still buffered by the driver when a disconnect begins
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum DisconnectedRx {
+    /// Discard the data without notice
+    #[default]
+    Drop,
+    /// Keep the data outside the log and warn that it arrived late
+    BufferAndWarn,
+}
+
+impl DisconnectedRx {
+    /// Cycle to the next option
+    pub fn next(self) -> Self {
+        match self {
+            DisconnectedRx::Drop => DisconnectedRx::BufferAndWarn,
+            DisconnectedRx::BufferAndWarn => DisconnectedRx::Drop,
+        }
+    }
+}
+
+/// How the byte stream is split into log entries and framed on send
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum FramingMode {
+    /// One entry per read, TX data sent as is
+    #[default]
+    Raw,
+    /// SLIP (RFC 1055): one entry per decoded frame, TX data SLIP-encoded
+    Slip,
+}
+
+impl FramingMode {
+    /// Cycle to the next mode
+    pub fn next(self) -> Self {
+        match self {
+            FramingMode::Raw => FramingMode::Slip,
+            FramingMode::Slip => FramingMode::Raw,
+        }
+    }
+}
+
+/// Persisted user preferences
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AppSettings {
+    /// Prefix columns shown before each log entry
+    pub log_columns: LogColumns,
+    /// Also record connection changes and errors as inline log entries
+    pub log_events: bool,
+    /// Bytes requested per serial port read
+    pub rx_read_buffer: usize,
+    /// Re-enable auto-scroll when the log is cleared
+    pub follow_on_clear: bool,
+    /// Cumulative vs retained RX/TX counts in the status bar
+    pub count_display: CountDisplay,
+    /// Open the shortcuts overlay on the first launch; cleared once shown
+    pub show_help_on_first_run: bool,
+    /// Draw a gap marker between entries further apart than `idle_gap_ms`
+    pub show_idle_gaps: bool,
+    /// Minimum pause between consecutive entries that gets a gap marker
+    pub idle_gap_ms: u64,
+    /// Collect serial config edits as pending until explicitly applied
+    pub stage_config_changes: bool,
+    /// Render TX entries in the mode they were typed in (ASCII or hex)
+    /// instead of the global display mode
+    pub native_tx_display: bool,
+    /// Collapse data bits, parity, stop bits and flow control into one
+    /// summary line in the config panel until one of them is focused
+    pub compact_config: bool,
+    /// Terminator placement for clipboard sends
+    pub paste_append: PasteAppend,
+    /// Bytes sent before every TX input or clipboard payload
+    pub tx_prefix: Vec<u8>,
+    /// Bytes sent after every payload, before the append-mode terminator
+    pub tx_suffix: Vec<u8>,
+    /// Display each byte with its bit order reversed, for devices that
+    /// shift data out LSB-first when MSB-first is expected (or vice versa)
+    pub lsb_first: bool,
+    /// Clear bit 7 of each byte before display, for 7-bit data carrying
+    /// parity in the top bit
+    pub mask_high_bit: bool,
+    /// Show control bytes in text mode as Unicode control pictures
+    /// (`␍␊`) instead of `\xNN` escapes
+    pub control_pictures: bool,
+    /// Keep focus on the TX input once typing starts, ignoring clicks
+    /// elsewhere until released with Esc or Tab
+    pub tx_focus_lock: bool,
+    /// Ask before sending a payload larger than `large_send_threshold`
+    pub confirm_large_sends: bool,
+    /// Payload size in bytes above which a send needs confirmation
+    pub large_send_threshold: usize,
+    /// Ask before quitting while connected or while a checkpoint capture
+    /// is running (recording: `AppState::checkpoint` is set), offering to
+    /// save the log first
+    pub confirm_quit_when_active: bool,
+    /// Stamp each RX read with a high-resolution time for the timing
+    /// overlay (capped history, off by default to save memory)
+    pub rx_chunk_timing: bool,
+    /// Handling of RX data read while not connected
+    pub disconnected_rx: DisconnectedRx,
+    /// Stop following new data while a drag selection in the log is active
+    pub scroll_lock_on_select: bool,
+    /// Connect to the saved port on launch when it is present
+    pub auto_connect_on_start: bool,
+    /// Start each connection with an empty log instead of appending
+    pub clear_log_on_connect: bool,
+    /// Framing applied to RX and TX data
+    pub framing: FramingMode,
+}
+
+impl Default for AppSettings {
+    fn default() -> Self {
+        Self {
+            log_columns: LogColumns::default(),
+            log_events: false,
+            rx_read_buffer: DEFAULT_RX_READ_BUFFER,
+            follow_on_clear: true,
+            count_display: CountDisplay::default(),
+            show_help_on_first_run: true,
+            show_idle_gaps: false,
+            idle_gap_ms: DEFAULT_IDLE_GAP_MS,
+            stage_config_changes: false,
+            native_tx_display: false,
+            compact_config: false,
+            paste_append: PasteAppend::Once,
+            tx_prefix: Vec::new(),
+            tx_suffix: Vec::new(),
+            lsb_first: false,
+            mask_high_bit: false,
+            control_pictures: false,
+            tx_focus_lock: false,
+            confirm_large_sends: false,
+            large_send_threshold: DEFAULT_LARGE_SEND_THRESHOLD,
+            confirm_quit_when_active: true,
+            rx_chunk_timing: false,
+            disconnected_rx: DisconnectedRx::Drop,
+            scroll_lock_on_select: true,
+            auto_connect_on_start: false,
+            clear_log_on_connect: false,
+            framing: FramingMode::Raw,
+        }
+    }
+}
+
+impl AppSettings {
+    /// Whether sending `len` bytes needs confirmation first
+    pub fn needs_send_confirmation(&self, len: usize) -> bool {
+        self.confirm_large_sends && len > self.large_send_threshold
+    }
+
+    /// RX read buffer size clamped to a sane range
+    pub fn rx_read_buffer_size(&self) -> usize {
+        self.rx_read_buffer
+            .clamp(RX_READ_BUFFER_RANGE.0, RX_READ_BUFFER_RANGE.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_columns() {
+        let cols = LogColumns::default();
+        assert_eq!(
+            cols.columns(),
+            &[
+                LogColumn::Timestamp,
+                LogColumn::Direction,
+                LogColumn::Length
+            ]
+        );
+        assert!(!cols.contains(LogColumn::Seq));
+    }
+
+    #[test]
+    fn test_new_dedups() {
+        let cols = LogColumns::new([LogColumn::Seq, LogColumn::Length, LogColumn::Seq]);
+        assert_eq!(cols.columns(), &[LogColumn::Seq, LogColumn::Length]);
+    }
+
+    #[test]
+    fn test_toggle() {
+        let mut cols = LogColumns::default();
+        cols.toggle(LogColumn::Length);
+        assert!(!cols.contains(LogColumn::Length));
+        cols.toggle(LogColumn::Seq);
+        assert_eq!(
+            cols.columns(),
+            &[LogColumn::Timestamp, LogColumn::Direction, LogColumn::Seq]
+        );
+    }
+
+    #[test]
+    fn test_settings_missing_fields_use_defaults() {
+        let settings: AppSettings = serde_json::from_str("{}").unwrap();
+        assert_eq!(settings, AppSettings::default());
+    }
+
+    #[test]
+    fn test_rx_read_buffer_clamped() {
+        let mut settings = AppSettings::default();
+        assert_eq!(settings.rx_read_buffer_size(), DEFAULT_RX_READ_BUFFER);
+        settings.rx_read_buffer = 0;
+        assert_eq!(settings.rx_read_buffer_size(), 16);
+        settings.rx_read_buffer = usize::MAX;
+        assert_eq!(settings.rx_read_buffer_size(), 64 * 1024);
+    }
+
+    #[test]
+    fn test_count_display_cycles() {
+        let start = CountDisplay::default();
+        assert_eq!(start, CountDisplay::Total);
+        assert_eq!(start.next().next().next(), start);
+    }
+
+    #[test]
+    fn test_settings_round_trip() {
+        let settings = AppSettings {
+            log_columns: LogColumns::new([LogColumn::Seq, LogColumn::Timestamp]),
+            log_events: true,
+            rx_read_buffer: 4096,
+            follow_on_clear: false,
+            count_display: CountDisplay::Retained,
+            show_help_on_first_run: false,
+            show_idle_gaps: true,
+            idle_gap_ms: 250,
+            stage_config_changes: true,
+            native_tx_display: true,
+            compact_config: true,
+            paste_append: PasteAppend::EachLine,
+            tx_prefix: b"AT+".to_vec(),
+            tx_suffix: vec![0x03],
+            lsb_first: true,
+            mask_high_bit: true,
+            control_pictures: true,
+            tx_focus_lock: true,
+            confirm_large_sends: true,
+            large_send_threshold: 64,
+            confirm_quit_when_active: false,
+            rx_chunk_timing: true,
+            disconnected_rx: DisconnectedRx::BufferAndWarn,
+            scroll_lock_on_select: false,
+            auto_connect_on_start: true,
+            clear_log_on_connect: true,
+            framing: FramingMode::Slip,
+        };
+        let json = serde_json::to_string(&settings).unwrap();
+        let back: AppSettings = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, settings);
+    }
+
+    #[test]
+    fn test_send_confirmation_threshold() {
+        let mut settings = AppSettings::default();
+        assert!(!settings.needs_send_confirmation(1_000_000));
+
+        settings.confirm_large_sends = true;
+        assert!(!settings.needs_send_confirmation(DEFAULT_LARGE_SEND_THRESHOLD));
+        assert!(settings.needs_send_confirmation(DEFAULT_LARGE_SEND_THRESHOLD + 1));
+    }
+}
@@ -4,34 +4,83 @@
 //! runtime data, UI state, and configuration for the tuiserial application.
 
 use ratatui::widgets::ListState;
+use rust_i18n::t;
+use serde::{Deserialize, Serialize};
 use serde_json;
+use std::borrow::Cow;
 use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
 
 use crate::config::SerialConfig;
 use crate::error::{AppError, CoreError, ErrorSeverity};
 use crate::error_log::ErrorLog;
-use crate::log::MessageLog;
+use crate::export::{Checkpoint, ExportDialog, ExportFilter};
+use crate::inspector::ByteInspector;
+use crate::keybindings::KeyBindings;
+use crate::line_ending::LineEndingStats;
+use crate::log::{LogDirection, LogEntry, LogEventKind, LogLayout, MessageLog, entry_is_printable};
 use crate::notification::Notification;
+use crate::quality::QualityWindow;
+use crate::rate::RateTracker;
+use crate::renderer::{DisplayRenderer, NamedRenderer};
+use crate::repeat::{RepeatPrompt, RepeatSend};
+use crate::settings::{AppSettings, DisconnectedRx};
+use crate::text_edit::TextInput;
+use crate::timing::{ChunkDelta, RxTiming};
 use crate::types::{
-    AppendMode, DisplayMode, FlowControl, FocusedField, Language, MenuState, Parity,
-    PluginLoadState, PluginModalMode, RegistryEntry, TxMode,
+    AppendMode, BytePad, ConnectionState, DisplayMode, FlowControl, FocusedField, Language,
+    LogSelection, LogView, MenuState, Parity, PendingSend, PluginLoadState, PluginModalMode,
+    RegistryEntry, TxMode,
 };
 
 /// Main application state
 pub struct AppState {
     // Serial configuration
     pub config: SerialConfig,
+    /// Last applied config while `settings.stage_config_changes` is on;
+    /// `config` then holds the pending edits shown in the config panel
+    pub applied_config: Option<SerialConfig>,
     pub message_log: MessageLog,
+    /// Line terminators seen in RX data since connecting or clearing the log
+    pub rx_line_endings: LineEndingStats,
+    /// Recent read/write outcomes since connecting, for the quality bar
+    pub link_quality: QualityWindow,
+    /// RX byte rate since connecting
+    pub rx_rate: RateTracker,
+    /// TX byte rate since connecting
+    pub tx_rate: RateTracker,
     pub display_mode: DisplayMode,
-    pub is_connected: bool,
+    pub connection: ConnectionState,
     pub config_locked: bool,
 
+    // User preferences (persisted alongside `config`)
+    pub settings: AppSettings,
+    /// What the config file held at the last load or save
+    pub saved_snapshot: ConfigFile,
+
     // Available ports
     pub ports: Vec<String>,
 
     // Scroll state
+    /// Top of the log view, as a position among the visible entries; see
+    /// [`LogLayout`] for the rows they take on screen
     pub scroll_offset: u16,
     pub auto_scroll: bool,
+    /// Entries selected by dragging in the log view
+    pub log_selection: Option<LogSelection>,
+    /// Most recent checkpoint, the start of "export since checkpoint"
+    pub checkpoint: Option<Checkpoint>,
+    /// Hide log entries whose bytes are all printable ASCII
+    pub only_binary: bool,
+    /// Second view of the same log with independent display settings,
+    /// shown side by side with the main one
+    pub dual_view: Option<LogView>,
+    /// Display modes registered by embedders, cycled after HEX and TEXT
+    pub custom_renderers: Vec<NamedRenderer>,
+    /// Index into `custom_renderers` of the mode in use, `None` for the
+    /// built-in `display_mode`
+    pub custom_view: Option<usize>,
 
     // UI State for dropdowns
     pub port_list_state: ListState,
@@ -47,13 +96,41 @@ pub struct AppState {
     pub stop_bits_state: ListState,
 
     // TX Input state
-    pub tx_input: String,
+    pub tx_input: TextInput,
     pub tx_mode: TxMode,
     pub tx_append_mode: AppendMode,
-    pub tx_cursor: usize,
+    /// Typing started with `tx_focus_lock` on; stray clicks no longer move
+    /// focus away from the TX input
+    pub tx_lock_engaged: bool,
     pub append_mode_options: Vec<AppendMode>,
     pub append_mode_state: ListState,
 
+    // Repeat send
+    /// Active repeat schedule, if any
+    pub repeat_send: Option<RepeatSend>,
+    /// Count/interval prompt shown before starting a bounded repeat
+    pub repeat_prompt: Option<RepeatPrompt>,
+    /// Format/filter dialog shown before exporting the log
+    pub export_dialog: Option<ExportDialog>,
+    /// Large payload waiting for the user to confirm or cancel sending
+    pub pending_send: Option<PendingSend>,
+    /// Quit was requested while connected or recording; waiting for the
+    /// user to save, quit or cancel
+    pub quit_prompt: bool,
+
+    // Byte inspector overlay for the focused log entry
+    pub byte_inspector: Option<ByteInspector>,
+    /// Clickable 00–FF grid sending single bytes, while open
+    pub byte_pad: Option<BytePad>,
+    /// Arrival times of RX reads, recorded while `settings.rx_chunk_timing`
+    /// is on
+    pub rx_timing: RxTiming,
+    /// Inter-read timing overlay for the focused log entry
+    pub show_rx_timing: bool,
+    /// RX bytes read while not connected when `settings.disconnected_rx`
+    /// is `BufferAndWarn`; logged as a marked entry on the next connect
+    pub late_rx: Vec<u8>,
+
     // UI Focus
     pub focused_field: FocusedField,
 
@@ -70,6 +147,8 @@ pub struct AppState {
 
     // Help overlay
     pub show_shortcuts_help: bool,
+    /// Keys shown for each shortcut in the help overlay and hint bar
+    pub key_bindings: KeyBindings,
 
     // Plugin management modal
     pub show_plugin_modal: bool,
@@ -99,6 +178,56 @@ pub struct AppState {
     pub error_log: ErrorLog,
 }
 
+/// On-disk layout of `config.json`
+///
+/// The serial fields stay at the top level so files written before
+/// `settings` existed still load.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ConfigFile {
+    #[serde(flatten)]
+    serial: SerialConfig,
+    #[serde(default)]
+    settings: AppSettings,
+}
+
+/// File name used in the per-user data directory when the config
+/// directory is not writable
+pub const FALLBACK_CONFIG_FILE: &str = "tuiserial-config.json";
+
+/// Most late RX bytes kept in `AppState::late_rx`; older ones are dropped
+const LATE_RX_CAPACITY: usize = 64 * 1024;
+
+/// Outcome of loading the saved configuration
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigLoad {
+    /// Configuration read from this file
+    Loaded(PathBuf),
+    /// No saved configuration yet
+    Missing,
+    /// A file exists but could not be read or parsed; nothing was applied
+    Corrupt { path: PathBuf, reason: String },
+}
+
+/// Where the configuration was written
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigSave {
+    /// Written to the normal config location
+    Primary(PathBuf),
+    /// The config directory was not writable (`reason`); written to `path`
+    /// under the per-user state (or data) directory's `tuiserial` folder
+    /// instead
+    Fallback { path: PathBuf, reason: String },
+}
+
+/// A serial config field whose pending value differs from the applied one
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StagedChange {
+    /// i18n key of the field label
+    pub label: &'static str,
+    pub applied: String,
+    pub pending: String,
+}
+
 /// Lightweight per-plugin status for the plugin modal UI.
 #[derive(Debug, Clone)]
 pub struct PluginLoadStatus {
@@ -137,13 +266,26 @@ impl Default for AppState {
 
         Self {
             config: SerialConfig::default(),
+            applied_config: None,
             message_log: MessageLog::new(),
+            rx_line_endings: LineEndingStats::default(),
+            link_quality: QualityWindow::default(),
+            rx_rate: RateTracker::default(),
+            tx_rate: RateTracker::default(),
             display_mode: DisplayMode::Hex,
-            is_connected: false,
+            connection: ConnectionState::Disconnected,
             config_locked: false,
+            settings: AppSettings::default(),
+            saved_snapshot: ConfigFile::default(),
             ports: Vec::new(),
             scroll_offset: 0,
+            only_binary: false,
+            dual_view: None,
+            custom_renderers: Vec::new(),
+            custom_view: None,
             auto_scroll: true,
+            log_selection: None,
+            checkpoint: None,
             port_list_state: ListState::default().with_selected(Some(0)),
             baud_rate_state: ListState::default().with_selected(Some(4)), // 9600
             parity_state: ListState::default().with_selected(Some(0)),    // None
@@ -155,12 +297,22 @@ impl Default for AppState {
             flow_control_options,
             data_bits_options,
             stop_bits_options,
-            tx_input: String::new(),
+            tx_input: TextInput::new(),
             tx_mode: TxMode::Ascii,
             tx_append_mode: AppendMode::None,
-            tx_cursor: 0,
+            tx_lock_engaged: false,
             append_mode_options,
             append_mode_state: ListState::default().with_selected(Some(0)),
+            repeat_send: None,
+            repeat_prompt: None,
+            export_dialog: None,
+            pending_send: None,
+            quit_prompt: false,
+            byte_inspector: None,
+            byte_pad: None,
+            rx_timing: RxTiming::default(),
+            show_rx_timing: false,
+            late_rx: Vec::new(),
             focused_field: FocusedField::Port,
             notifications: VecDeque::new(),
             debug_mode: false,
@@ -168,6 +320,7 @@ impl Default for AppState {
             menu_state: MenuState::None,
             language: Language::English,
             show_shortcuts_help: false,
+            key_bindings: KeyBindings::default(),
             show_plugin_modal: false,
             plugin_modal_mode: PluginModalMode::Local,
             plugin_statuses: Vec::new(),
@@ -207,6 +360,480 @@ impl AppState {
         !self.config_locked
     }
 
+    /// Whether the config panel shows the frame settings (data bits,
+    /// parity, stop bits, flow control) as a single summary line: compact
+    /// mode is on and none of those fields has focus
+    pub fn config_collapsed(&self) -> bool {
+        self.settings.compact_config
+            && !matches!(
+                self.focused_field,
+                FocusedField::DataBits
+                    | FocusedField::Parity
+                    | FocusedField::StopBits
+                    | FocusedField::FlowControl
+            )
+    }
+
+    /// Whether the port is open
+    pub fn is_connected(&self) -> bool {
+        self.connection.is_connected()
+    }
+
+    /// Enter `Connecting` while the port is being opened; the config is
+    /// locked so it cannot change underneath the attempt
+    pub fn begin_connecting(&mut self) {
+        self.connection = ConnectionState::Connecting;
+        self.lock_config();
+    }
+
+    /// Mark the port as connected: lock the config and record the event.
+    /// With `clear_log_on_connect` the log is cleared first, so the connect
+    /// event (when events are logged) opens the new log.
+    pub fn mark_connected(&mut self) {
+        self.connection = ConnectionState::Connected;
+        if self.settings.clear_log_on_connect {
+            self.clear_log();
+        }
+        self.rx_line_endings = LineEndingStats::default();
+        self.link_quality.clear();
+        self.rx_rate.clear();
+        self.tx_rate.clear();
+        self.lock_config();
+        let text = t!(
+            "event.connected",
+            config = self.active_config().format_display()
+        )
+        .to_string();
+        self.log_event(LogEventKind::Connect, text);
+        self.flush_late_rx();
+    }
+
+    /// Mark the port as disconnected: unlock the config and record the event
+    /// along with an optional reason (e.g. "emergency stop")
+    pub fn mark_disconnected(&mut self, reason: Option<&str>) {
+        self.connection = ConnectionState::Disconnected;
+        self.stop_all_activity();
+        self.unlock_config();
+        let mut text = t!("event.disconnected", port = &self.active_config().port).to_string();
+        if let Some(reason) = reason {
+            text.push_str(&format!(" ({reason})"));
+        }
+        self.log_event(LogEventKind::Disconnect, text);
+    }
+
+    /// A connect attempt failed: unlock the config and show the reason
+    pub fn mark_connect_failed(&mut self, reason: impl Into<String>) {
+        self.connection = ConnectionState::Error(reason.into());
+        self.unlock_config();
+    }
+
+    /// A connect attempt was abandoned before it finished: back to
+    /// `Disconnected` with the config unlocked, nothing logged
+    pub fn cancel_connecting(&mut self) {
+        self.connection = ConnectionState::Disconnected;
+        self.unlock_config();
+    }
+
+    /// The port was reopened after a baud step: connected again, keeping
+    /// the log and counters (the step itself is already marked in the log)
+    pub fn mark_reconnected(&mut self) {
+        self.connection = ConnectionState::Connected;
+        self.lock_config();
+        self.flush_late_rx();
+    }
+
+    /// Log the bytes kept in `late_rx` as one RX entry after a marker
+    /// saying they arrived while disconnected (recorded even when
+    /// `settings.log_events` is off), so they reach the log and exports
+    fn flush_late_rx(&mut self) {
+        if self.late_rx.is_empty() {
+            return;
+        }
+        let data = std::mem::take(&mut self.late_rx);
+        self.message_log.push_event(
+            LogEventKind::Marker,
+            t!("event.late_rx", count = data.len()).to_string(),
+        );
+        self.message_log.push_rx(data);
+        if self.auto_scroll {
+            let lines_count = self.message_log.entries.len() as u16;
+            self.scroll_offset = lines_count.saturating_sub(1);
+        }
+    }
+
+    /// An open connection was lost (e.g. repeated read errors): record the
+    /// disconnect like `mark_disconnected`, but stay in the error state
+    pub fn mark_connection_lost(&mut self, reason: &str) {
+        self.mark_disconnected(Some(reason));
+        self.connection = ConnectionState::Error(reason.to_string());
+    }
+
+    // Log append
+
+    /// Whether RX data may enter the log. Data read in any other state
+    /// (e.g. while an async disconnect is still closing the port) arrives
+    /// after the disconnect marker and would look like late traffic.
+    pub fn accepts_rx(&self) -> bool {
+        self.is_connected()
+    }
+
+    /// Entry point for data read from the port at `read_at`: append it to
+    /// the log while connected, otherwise handle it per
+    /// `settings.disconnected_rx`. Take `read_at` before plugins and
+    /// deframing so their cost does not show up in the read timing.
+    pub fn receive_rx(&mut self, data: Vec<u8>, read_at: Instant) {
+        if self.accepts_rx() {
+            self.append_rx_at(data, read_at);
+            return;
+        }
+        match self.settings.disconnected_rx {
+            DisconnectedRx::Drop => {
+                log::debug!("Dropped {} RX bytes read while not connected", data.len());
+            }
+            DisconnectedRx::BufferAndWarn => {
+                // Warn once per disconnected period, not for every read
+                if self.late_rx.is_empty() {
+                    self.add_warning(t!("notify.late_rx").to_string());
+                }
+                self.late_rx.extend_from_slice(&data);
+                let excess = self.late_rx.len().saturating_sub(LATE_RX_CAPACITY);
+                self.late_rx.drain(..excess);
+            }
+        }
+    }
+
+    /// Append received data to the log and follow it if auto-scroll is on.
+    ///
+    /// This is the whole per-chunk RX path after plugins; keep it free of
+    /// I/O so it can be benchmarked in isolation.
+    pub fn append_rx(&mut self, data: Vec<u8>) {
+        self.append_rx_at(data, Instant::now());
+    }
+
+    /// [`append_rx`](Self::append_rx) for data read at `now`, so rate and
+    /// timing records use the read time rather than the time of logging
+    pub fn append_rx_at(&mut self, data: Vec<u8>, now: Instant) {
+        self.rx_line_endings.feed(&data);
+        let len = data.len();
+        self.message_log.push_rx(data);
+        self.rx_rate.record(len, now);
+        if self.settings.rx_chunk_timing {
+            self.rx_timing.record(self.message_log.last_seq, len, now);
+        }
+        if self.auto_scroll {
+            let lines_count = self.message_log.entries.len() as u16;
+            self.scroll_offset = lines_count.saturating_sub(1);
+        }
+    }
+
+    // Log view filter
+
+    /// Whether `entry` passes the log view filter
+    pub fn entry_visible(&self, entry: &LogEntry) -> bool {
+        !self.only_binary || !entry_is_printable(entry)
+    }
+
+    /// Indices into `message_log.entries` of the entries shown in the log
+    /// view, in order. Scroll positions index into this list.
+    pub fn visible_entry_indices(&self) -> Vec<usize> {
+        self.message_log
+            .entries
+            .iter()
+            .enumerate()
+            .filter(|(_, entry)| self.entry_visible(entry))
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// Entries an export with `filter` writes, in log order
+    pub fn export_entries(&self, filter: &ExportFilter) -> Vec<&LogEntry> {
+        self.message_log
+            .entries
+            .iter()
+            .filter(|entry| !filter.only_matching || self.entry_visible(entry))
+            .filter(|entry| filter.matches(entry))
+            .collect()
+    }
+
+    /// Insert a numbered checkpoint marker into the log (recorded even
+    /// when `settings.log_events` is off) and make it the start of
+    /// checkpoint exports
+    pub fn set_checkpoint(&mut self) -> Checkpoint {
+        let number = self.checkpoint.map_or(1, |c| c.number + 1);
+        self.message_log.push_event(
+            LogEventKind::Marker,
+            t!("event.checkpoint", number = number).to_string(),
+        );
+        let checkpoint = Checkpoint {
+            number,
+            seq: self.message_log.last_seq,
+        };
+        self.checkpoint = Some(checkpoint);
+        checkpoint
+    }
+
+    /// Number of entries shown in the log view
+    pub fn visible_entry_count(&self) -> usize {
+        if !self.only_binary {
+            return self.message_log.entries.len();
+        }
+        self.message_log
+            .entries
+            .iter()
+            .filter(|entry| self.entry_visible(entry))
+            .count()
+    }
+
+    /// Show only binary entries, or everything again. The focused entry
+    /// stays in view when it is still visible, otherwise the view moves
+    /// to the nearest visible entry after it.
+    pub fn toggle_only_binary(&mut self) {
+        let focused = self.focused_entry_index();
+        self.only_binary = !self.only_binary;
+        if self.auto_scroll {
+            return;
+        }
+        let visible = self.visible_entry_indices();
+        let pos = focused
+            .and_then(|idx| visible.iter().position(|&i| i >= idx))
+            .unwrap_or(visible.len().saturating_sub(1));
+        self.scroll_offset = pos as u16;
+    }
+
+    /// Move the log focus to the next (`forward`) or previous visible data
+    /// entry containing non-printable bytes, leaving follow mode. Returns
+    /// `false` if there is none.
+    pub fn jump_to_binary_entry(&mut self, forward: bool) -> bool {
+        self.move_focus_where(forward, |entry| !entry_is_printable(entry))
+    }
+
+    // Focused log entry
+
+    /// Index of the data entry the log view is focused on: the newest one
+    /// while following, otherwise the one at the top of the scrolled view.
+    /// Event entries are skipped in favour of the nearest earlier data entry.
+    pub fn focused_entry_index(&self) -> Option<usize> {
+        let visible = self.visible_entry_indices();
+        let pos = self.focused_position(&visible)?;
+        Some(visible[pos])
+    }
+
+    /// Position in `visible` of the focused data entry
+    fn focused_position(&self, visible: &[usize]) -> Option<usize> {
+        let last = visible.len().checked_sub(1)?;
+        let start = if self.auto_scroll {
+            last
+        } else {
+            (self.scroll_offset as usize).min(last)
+        };
+        let entries = &self.message_log.entries;
+        (0..=start).rev().find(|&p| !entries[visible[p]].is_event())
+    }
+
+    /// The data entry the log view is focused on (see `focused_entry_index`)
+    pub fn focused_entry(&self) -> Option<&LogEntry> {
+        self.focused_entry_index()
+            .map(|i| &self.message_log.entries[i])
+    }
+
+    /// Data entries inside the mouse drag selection that the log view
+    /// shows, oldest first; empty without one. Inline events are skipped.
+    pub fn selected_entries(&self) -> Vec<&LogEntry> {
+        let Some(selection) = self.log_selection else {
+            return Vec::new();
+        };
+        self.message_log
+            .entries
+            .iter()
+            .filter(|entry| selection.contains(entry.seq))
+            .filter(|entry| !entry.is_event() && self.entry_visible(entry))
+            .collect()
+    }
+
+    /// Bytes currently selected in the log: the data of the entries in the
+    /// drag selection while there is one, else the byte inspector's window
+    /// while it is open, else the whole focused entry. `None` unless one of
+    /// those applies (the focused entry counts only while the log area has
+    /// focus).
+    pub fn selected_bytes(&self) -> Option<Cow<'_, [u8]>> {
+        if self.log_selection.is_some() {
+            return match self.selected_entries().as_slice() {
+                [] => None,
+                [entry] => Some(Cow::Borrowed(&entry.data)),
+                entries => Some(Cow::Owned(
+                    entries
+                        .iter()
+                        .flat_map(|e| e.data.iter().copied())
+                        .collect(),
+                )),
+            };
+        }
+        let entry = self.focused_entry()?;
+        match &self.byte_inspector {
+            Some(inspector) => Some(Cow::Borrowed(
+                inspector.window(&entry.data).unwrap_or(&entry.data),
+            )),
+            None if self.focused_field == FocusedField::LogArea => Some(Cow::Borrowed(&entry.data)),
+            None => None,
+        }
+    }
+
+    /// Length of [`selected_bytes`](Self::selected_bytes) without joining
+    /// a multi-entry selection
+    pub fn selected_byte_count(&self) -> Option<usize> {
+        if self.log_selection.is_some() {
+            let entries = self.selected_entries();
+            return (!entries.is_empty()).then(|| entries.iter().map(|e| e.data.len()).sum());
+        }
+        self.selected_bytes().map(|bytes| bytes.len())
+    }
+
+    /// Read timing of the focused entry and up to `count - 1` reads after
+    /// it; empty unless the focused entry is an RX read with timing recorded
+    pub fn focused_rx_timing(&self, count: usize) -> Vec<ChunkDelta> {
+        match self.focused_entry() {
+            Some(entry) if entry.direction == LogDirection::Rx => {
+                self.rx_timing.deltas_from(entry.seq, count)
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    /// Move the log focus to the previous (`forward == false`) or next data
+    /// entry, leaving follow mode. Returns `false` if there is none.
+    pub fn move_entry_focus(&mut self, forward: bool) -> bool {
+        self.move_focus_where(forward, |_| true)
+    }
+
+    /// Move the focus to the nearest visible data entry in the given
+    /// direction that matches `pred`
+    fn move_focus_where(&mut self, forward: bool, pred: impl Fn(&LogEntry) -> bool) -> bool {
+        let visible = self.visible_entry_indices();
+        let Some(current) = self.focused_position(&visible) else {
+            return false;
+        };
+        let entries = &self.message_log.entries;
+        let matches = |p: &usize| {
+            let entry = &entries[visible[*p]];
+            !entry.is_event() && pred(entry)
+        };
+        let target = if forward {
+            (current + 1..visible.len()).find(matches)
+        } else {
+            (0..current).rev().find(matches)
+        };
+        match target {
+            Some(p) => {
+                self.auto_scroll = false;
+                self.scroll_offset = p as u16;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Fit the scroll position to a log viewport of `viewport_lines` rows
+    /// laid out as `layout`: re-pin to the bottom while following,
+    /// otherwise clamp so the view never starts past the last full page.
+    /// Call after the log area changes size.
+    pub fn fit_scroll_to_viewport(&mut self, layout: &LogLayout, viewport_lines: u16) {
+        let max_scroll = layout.max_scroll_position(viewport_lines) as u16;
+        self.scroll_offset = if self.auto_scroll {
+            max_scroll
+        } else {
+            self.scroll_offset.min(max_scroll)
+        };
+    }
+
+    /// Visible position of the entry drawn `row_in_view` rows below the
+    /// top of a `viewport_lines` tall log view laid out as `layout`
+    pub fn log_position_at_row(
+        &self,
+        layout: &LogLayout,
+        viewport_lines: u16,
+        row_in_view: usize,
+    ) -> Option<usize> {
+        let top = layout.top_row(self.scroll_offset, self.auto_scroll, viewport_lines);
+        layout.position_at(top + row_in_view)
+    }
+
+    /// `seq` of the entry at visible position `position`
+    fn visible_seq(&self, position: usize) -> Option<u64> {
+        let index = *self.visible_entry_indices().get(position)?;
+        Some(self.message_log.entries[index].seq)
+    }
+
+    /// Start a drag selection at visible position `position`. With
+    /// `settings.scroll_lock_on_select` following stops so incoming data
+    /// cannot shift the entries being selected.
+    pub fn begin_log_selection(&mut self, position: usize) {
+        let Some(seq) = self.visible_seq(position) else {
+            return;
+        };
+        let resume_follow = self.auto_scroll;
+        if self.settings.scroll_lock_on_select {
+            self.auto_scroll = false;
+        }
+        self.log_selection = Some(LogSelection {
+            anchor: seq,
+            head: seq,
+            resume_follow,
+        });
+    }
+
+    /// Move the end of the drag selection to visible position `position`
+    pub fn extend_log_selection(&mut self, position: usize) {
+        let Some(seq) = self.visible_seq(position) else {
+            return;
+        };
+        if let Some(selection) = &mut self.log_selection {
+            selection.head = seq;
+        }
+    }
+
+    /// Drop the drag selection; following resumes if it was on when the
+    /// selection started
+    pub fn clear_log_selection(&mut self) {
+        if let Some(selection) = self.log_selection.take()
+            && selection.resume_follow
+        {
+            self.auto_scroll = true;
+        }
+    }
+
+    /// Clear the message log and reset the scroll state to match.
+    ///
+    /// Every "clear log" path (keyboard, mouse, menu) must go through here so
+    /// a stale `scroll_offset` never outlives the entries it pointed into.
+    pub fn clear_log(&mut self) {
+        self.message_log.clear();
+        self.rx_line_endings = LineEndingStats::default();
+        self.rx_timing.clear();
+        self.scroll_offset = 0;
+        self.byte_inspector = None;
+        self.log_selection = None;
+        self.checkpoint = None;
+        if self.settings.follow_on_clear {
+            self.auto_scroll = true;
+        }
+    }
+
+    // Inline log events
+
+    /// Insert an event entry into the message log when `settings.log_events`
+    /// is enabled. Returns `true` if the event was recorded.
+    pub fn log_event(&mut self, kind: LogEventKind, text: impl Into<String>) -> bool {
+        if !self.settings.log_events {
+            return false;
+        }
+        self.message_log.push_event(kind, text);
+        if self.auto_scroll {
+            let lines_count = self.message_log.entries.len() as u16;
+            self.scroll_offset = lines_count.saturating_sub(1);
+        }
+        true
+    }
+
     // Notification management
 
     /// Add a notification to the queue
@@ -251,6 +878,9 @@ impl AppState {
         let severity = error.severity();
         let msg = error.to_user_message();
         self.error_log.push(error);
+        if severity != ErrorSeverity::Info {
+            self.log_event(LogEventKind::Error, msg.clone());
+        }
 
         match severity {
             ErrorSeverity::Info => {
@@ -314,6 +944,33 @@ impl AppState {
         }
     }
 
+    /// Step the baud rate to the next (`forward`) or previous option for a
+    /// reconnect at the new rate, bypassing the config lock, and mark the
+    /// change in the log. The event is recorded whatever `log_events` says,
+    /// so captures at both rates can be told apart. Returns the old and new
+    /// rates, or `None` if no baud rate is selected.
+    pub fn step_baud_for_reconnect(&mut self, forward: bool) -> Option<(u32, u32)> {
+        let selected = self.baud_rate_state.selected()?;
+        let len = self.baud_rate_options.len();
+        let next = if forward {
+            (selected + 1) % len
+        } else {
+            (selected + len - 1) % len
+        };
+        let old = self.active_config().baud_rate;
+        let new = self.baud_rate_options[next];
+        self.baud_rate_state.select(Some(next));
+        self.config.baud_rate = new;
+        if let Some(applied) = self.applied_config.as_mut() {
+            applied.baud_rate = new;
+        }
+        self.message_log.push_event(
+            LogEventKind::Reconnect,
+            t!("event.baud_changed", old = old, new = new).to_string(),
+        );
+        Some((old, new))
+    }
+
     // Parity management
 
     /// Toggle parity setting
@@ -402,12 +1059,12 @@ impl AppState {
 
     /// Toggle transmission mode, converting existing input when switching
     pub fn toggle_tx_mode(&mut self) {
-        self.tx_input = crate::types::convert_tx_input(&self.tx_input, self.tx_mode);
+        let converted = crate::types::convert_tx_input(self.tx_input.as_str(), self.tx_mode);
+        self.tx_input.set_text(converted);
         self.tx_mode = match self.tx_mode {
             TxMode::Hex => TxMode::Ascii,
             TxMode::Ascii => TxMode::Hex,
         };
-        self.tx_cursor = self.tx_input.chars().count();
     }
 
     /// Cycle to next append mode
@@ -435,15 +1092,92 @@ impl AppState {
     // Display mode management
 
     /// Toggle display mode
+    ///
+    /// Cycles HEX → TEXT → each registered custom renderer → HEX.
     pub fn toggle_display_mode(&mut self) {
-        self.display_mode = match self.display_mode {
-            DisplayMode::Hex => DisplayMode::Text,
-            DisplayMode::Text => DisplayMode::Hex,
+        self.custom_view = match (self.custom_view, self.display_mode) {
+            (None, DisplayMode::Text) if !self.custom_renderers.is_empty() => Some(0),
+            (Some(i), _) if i + 1 < self.custom_renderers.len() => Some(i + 1),
+            (Some(_), _) => {
+                self.display_mode = DisplayMode::Hex;
+                None
+            }
+            (None, _) => {
+                self.display_mode = self.display_mode.toggled();
+                None
+            }
+        };
+    }
+
+    /// Register a custom display mode; the display mode toggle reaches it
+    /// after the built-in modes
+    pub fn register_renderer(
+        &mut self,
+        name: impl Into<String>,
+        renderer: impl DisplayRenderer + 'static,
+    ) {
+        self.custom_renderers
+            .push(NamedRenderer::new(name, renderer));
+    }
+
+    /// The custom renderer in use by the main log view, if any
+    pub fn active_custom_renderer(&self) -> Option<&NamedRenderer> {
+        self.custom_view.and_then(|i| self.custom_renderers.get(i))
+    }
+
+    /// Settings of the main log view
+    pub fn primary_view(&self) -> LogView {
+        LogView {
+            display_mode: self.display_mode,
+            custom_renderer: self.custom_view,
+            native_tx_display: self.settings.native_tx_display,
+        }
+    }
+
+    /// Show or hide the second log view. It opens in the other display
+    /// mode so the two interpretations differ from the start. Returns
+    /// whether the dual view is now shown.
+    pub fn toggle_dual_view(&mut self) -> bool {
+        self.dual_view = match self.dual_view {
+            Some(_) => None,
+            None => Some(LogView {
+                display_mode: self.display_mode.toggled(),
+                custom_renderer: None,
+                ..self.primary_view()
+            }),
         };
+        self.dual_view.is_some()
+    }
+
+    /// Toggle the second view's display mode; returns the new mode, or
+    /// `None` if the dual view is not shown
+    pub fn toggle_secondary_display_mode(&mut self) -> Option<DisplayMode> {
+        let view = self.dual_view.as_mut()?;
+        view.display_mode = view.display_mode.toggled();
+        Some(view.display_mode)
     }
 
     // Focus management
 
+    /// Engage the TX focus lock if the setting is on (called on typing)
+    pub fn engage_tx_lock(&mut self) {
+        if self.settings.tx_focus_lock {
+            self.tx_lock_engaged = true;
+        }
+    }
+
+    /// Release the TX focus lock; returns whether it was engaged
+    pub fn release_tx_lock(&mut self) -> bool {
+        std::mem::take(&mut self.tx_lock_engaged)
+    }
+
+    /// Whether focus is held on the TX input against clicks elsewhere
+    pub fn tx_focus_locked(&self) -> bool {
+        self.settings.tx_focus_lock
+            && self.tx_lock_engaged
+            && self.focused_field == FocusedField::TxInput
+    }
+
     /// Focus next field
     pub fn focus_next_field(&mut self) {
         self.focused_field = match self.focused_field {
@@ -472,65 +1206,388 @@ impl AppState {
         };
     }
 
+    // Repeat send
+
+    /// Start a repeat schedule, replacing any running one
+    pub fn start_repeat(&mut self, repeat: RepeatSend) {
+        self.repeat_send = Some(repeat);
+    }
+
+    /// Stop the running repeat, returning it so callers can report progress
+    pub fn cancel_repeat(&mut self) -> Option<RepeatSend> {
+        self.repeat_send.take()
+    }
+
+    /// Disarm every scheduled transmission (running repeat, pending repeat
+    /// prompt, send awaiting confirmation) so nothing resumes on the next
+    /// connect. Every scheduler must be reset here. Returns `true` if
+    /// anything was stopped.
+    pub fn stop_all_activity(&mut self) -> bool {
+        let repeat = self.repeat_send.take().is_some();
+        let prompt = self.repeat_prompt.take().is_some();
+        let pending = self.pending_send.take().is_some();
+        repeat || prompt || pending
+    }
+
+    /// Whether any transmission is scheduled
+    pub fn has_scheduled_activity(&self) -> bool {
+        self.repeat_send.is_some() || self.repeat_prompt.is_some() || self.pending_send.is_some()
+    }
+
+    // Quit
+
+    /// Whether a checkpoint capture is running, i.e. entries are being
+    /// collected for the next "export since checkpoint"
+    pub fn is_recording(&self) -> bool {
+        self.checkpoint.is_some()
+    }
+
+    /// Whether quitting now should ask first: `confirm_quit_when_active`
+    /// is on and the port is connected or a capture is running
+    pub fn quit_needs_confirmation(&self) -> bool {
+        self.settings.confirm_quit_when_active && (self.is_connected() || self.is_recording())
+    }
+
+    /// Handle a quit key. Returns `true` if the application should exit
+    /// now; otherwise the quit prompt is opened. A second quit while the
+    /// prompt is open always exits.
+    pub fn request_quit(&mut self) -> bool {
+        if self.quit_prompt || !self.quit_needs_confirmation() {
+            return true;
+        }
+        self.quit_prompt = true;
+        false
+    }
+
+    /// Payload to send now, if a repeat is running and due
+    pub fn due_repeat_payload(&self, now: Instant) -> Option<Vec<u8>> {
+        self.repeat_send
+            .as_ref()
+            .filter(|r| r.is_due(now))
+            .map(|r| r.payload.clone())
+    }
+
+    /// Record a completed repeat send. When a bounded repeat reaches zero it
+    /// is removed and returned so the caller can show a summary.
+    pub fn record_repeat_sent(&mut self, now: Instant) -> Option<RepeatSend> {
+        let repeat = self.repeat_send.as_mut()?;
+        repeat.record_sent(now);
+        if repeat.is_finished() {
+            self.repeat_send.take()
+        } else {
+            None
+        }
+    }
+
+    /// Reschedule the running repeat after a send that did not go out
+    /// (suppressed by a plugin) without counting it
+    pub fn record_repeat_skipped(&mut self, now: Instant) {
+        if let Some(repeat) = self.repeat_send.as_mut() {
+            repeat.record_skipped(now);
+        }
+    }
+
     // Configuration persistence
 
-    /// Save configuration to file
-    pub fn save_config(&self) -> Result<(), CoreError> {
-        let config_dir = dirs::config_dir().ok_or(CoreError::ConfigDirNotFound)?;
-        let app_config_dir = config_dir.join("tuiserial");
-        std::fs::create_dir_all(&app_config_dir)?;
+    /// Location of `config.json`, if the platform has a config directory
+    pub fn config_path() -> Option<PathBuf> {
+        dirs::config_dir().map(|dir| dir.join("tuiserial").join("config.json"))
+    }
+
+    /// Whether a saved configuration exists (false on the very first launch)
+    pub fn config_file_exists() -> bool {
+        Self::config_path().is_some_and(|path| path.exists())
+            || Self::fallback_config_path().is_some_and(|path| path.exists())
+    }
+
+    /// Open the shortcuts overlay if this is the first launch and the user
+    /// has not opted out, then clear the flag so it is shown only once.
+    /// Returns `true` if the overlay was opened and settings should be saved.
+    pub fn show_first_run_help(&mut self, first_run: bool) -> bool {
+        if !first_run || !self.settings.show_help_on_first_run {
+            return false;
+        }
+        self.show_shortcuts_help = true;
+        self.settings.show_help_on_first_run = false;
+        true
+    }
+
+    /// Location used when the config directory is not writable: the
+    /// per-user state directory, or the data directory on platforms
+    /// without one
+    pub fn fallback_config_path() -> Option<PathBuf> {
+        dirs::state_dir()
+            .or_else(dirs::data_dir)
+            .map(|dir| dir.join("tuiserial").join(FALLBACK_CONFIG_FILE))
+    }
+
+    /// Save configuration, falling back to the per-user data directory if
+    /// the config directory cannot be written
+    pub fn save_config(&mut self) -> Result<ConfigSave, CoreError> {
+        self.save_config_with(Self::config_path(), Self::fallback_config_path())
+    }
 
-        let config_path = app_config_dir.join("config.json");
-        let json = serde_json::to_string_pretty(&self.config)?;
+    /// Save to `primary`, or to `fallback` if that fails
+    fn save_config_with(
+        &mut self,
+        primary: Option<PathBuf>,
+        fallback: Option<PathBuf>,
+    ) -> Result<ConfigSave, CoreError> {
+        let primary_error = match &primary {
+            Some(path) => match self.save_config_to(path) {
+                Ok(()) => return Ok(ConfigSave::Primary(path.clone())),
+                Err(e) => e.to_string(),
+            },
+            None => CoreError::ConfigDirNotFound.to_string(),
+        };
 
-        std::fs::write(&config_path, json)?;
+        let Some(fallback) = fallback else {
+            return Err(CoreError::ConfigDirNotFound);
+        };
+        match self.save_config_to(&fallback) {
+            Ok(()) => Ok(ConfigSave::Fallback {
+                path: fallback,
+                reason: primary_error,
+            }),
+            Err(e) => Err(CoreError::ConfigNotWritable {
+                primary: primary.map_or_else(
+                    || "config directory".to_string(),
+                    |p| p.display().to_string(),
+                ),
+                primary_error,
+                fallback: fallback.display().to_string(),
+                fallback_error: e.to_string(),
+            }),
+        }
+    }
+
+    /// Save configuration to `path`, creating its directory if needed
+    pub fn save_config_to(&mut self, path: &Path) -> Result<(), CoreError> {
+        if let Some(dir) = path.parent()
+            && !dir.as_os_str().is_empty()
+        {
+            std::fs::create_dir_all(dir)?;
+        }
+
+        let file = self.persisted_state();
+        let json = serde_json::to_string_pretty(&file)?;
+
+        std::fs::write(path, json)?;
+        self.saved_snapshot = file;
 
         Ok(())
     }
 
-    /// Load configuration from file, return default if not found or error
-    pub fn load_config(&mut self) {
-        if let Some(config_dir) = dirs::config_dir() {
-            let config_path = config_dir.join("tuiserial").join("config.json");
-            if let Ok(json) = std::fs::read_to_string(&config_path)
-                && let Ok(config) = serde_json::from_str::<SerialConfig>(&json)
-            {
-                // Update UI states to match loaded config
-                if let Some(idx) = self
-                    .baud_rate_options
-                    .iter()
-                    .position(|&b| b == config.baud_rate)
-                {
-                    self.baud_rate_state.select(Some(idx));
-                }
-                if let Some(idx) = self.parity_options.iter().position(|&p| p == config.parity) {
-                    self.parity_state.select(Some(idx));
-                }
-                if let Some(idx) = self
-                    .flow_control_options
-                    .iter()
-                    .position(|&f| f == config.flow_control)
-                {
-                    self.flow_control_state.select(Some(idx));
-                }
-                if let Some(idx) = self
-                    .data_bits_options
-                    .iter()
-                    .position(|&d| d == config.data_bits)
-                {
-                    self.data_bits_state.select(Some(idx));
-                }
-                if let Some(idx) = self
-                    .stop_bits_options
-                    .iter()
-                    .position(|&s| s == config.stop_bits)
-                {
-                    self.stop_bits_state.select(Some(idx));
+    /// What saving now would write
+    fn persisted_state(&self) -> ConfigFile {
+        ConfigFile {
+            serial: self.active_config().clone(),
+            settings: self.settings.clone(),
+        }
+    }
+
+    /// Whether the config or settings differ from the last load or save
+    pub fn has_unsaved_changes(&self) -> bool {
+        *self.active_config() != self.saved_snapshot.serial
+            || self.settings != self.saved_snapshot.settings
+    }
+
+    /// Take the current config and settings as the saved baseline, e.g.
+    /// once startup has filled in defaults and picked a detected port
+    pub fn mark_saved(&mut self) {
+        self.saved_snapshot = self.persisted_state();
+    }
+
+    /// Load the saved configuration from the config directory or the per-user
+    /// fallback location, whichever was written last. A corrupt file falls
+    /// through to the other one. On anything but `Loaded` the current
+    /// configuration is left untouched.
+    pub fn load_config(&mut self) -> ConfigLoad {
+        self.load_config_with(Self::config_path(), Self::fallback_config_path())
+    }
+
+    /// Load the newer readable file of `primary` and `fallback`; the primary
+    /// wins ties. Reports the first corrupt file if neither loads.
+    fn load_config_with(
+        &mut self,
+        primary: Option<PathBuf>,
+        fallback: Option<PathBuf>,
+    ) -> ConfigLoad {
+        let mut candidates: Vec<PathBuf> = primary.into_iter().chain(fallback).collect();
+        // Newest first; files without a readable mtime go last
+        candidates.sort_by_key(|path| {
+            std::cmp::Reverse(std::fs::metadata(path).and_then(|m| m.modified()).ok())
+        });
+        let mut corrupt = None;
+        for path in candidates {
+            match self.load_config_from(&path) {
+                ConfigLoad::Missing => continue,
+                outcome @ ConfigLoad::Corrupt { .. } => {
+                    corrupt.get_or_insert(outcome);
                 }
-                // Move config assignment to end after all borrows
-                self.config = config;
+                outcome => return outcome,
             }
         }
+        corrupt.unwrap_or(ConfigLoad::Missing)
+    }
+
+    /// Load configuration from `path`
+    pub fn load_config_from(&mut self, path: &Path) -> ConfigLoad {
+        let corrupt = |reason: String| ConfigLoad::Corrupt {
+            path: path.to_path_buf(),
+            reason,
+        };
+        let json = match std::fs::read_to_string(path) {
+            Ok(json) => json,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return ConfigLoad::Missing,
+            Err(e) => return corrupt(e.to_string()),
+        };
+        let file = match serde_json::from_str::<ConfigFile>(&json) {
+            Ok(file) => file,
+            Err(e) => return corrupt(e.to_string()),
+        };
+
+        self.saved_snapshot = file.clone();
+        self.config = file.serial;
+        self.settings = file.settings;
+        self.sync_config_states();
+        self.applied_config = self
+            .settings
+            .stage_config_changes
+            .then(|| self.config.clone());
+        ConfigLoad::Loaded(path.to_path_buf())
+    }
+
+    /// Point the option list selections at the values in `config`
+    fn sync_config_states(&mut self) {
+        if let Some(idx) = self
+            .baud_rate_options
+            .iter()
+            .position(|&b| b == self.config.baud_rate)
+        {
+            self.baud_rate_state.select(Some(idx));
+        }
+        if let Some(idx) = self
+            .parity_options
+            .iter()
+            .position(|&p| p == self.config.parity)
+        {
+            self.parity_state.select(Some(idx));
+        }
+        if let Some(idx) = self
+            .flow_control_options
+            .iter()
+            .position(|&f| f == self.config.flow_control)
+        {
+            self.flow_control_state.select(Some(idx));
+        }
+        if let Some(idx) = self
+            .data_bits_options
+            .iter()
+            .position(|&d| d == self.config.data_bits)
+        {
+            self.data_bits_state.select(Some(idx));
+        }
+        if let Some(idx) = self
+            .stop_bits_options
+            .iter()
+            .position(|&s| s == self.config.stop_bits)
+        {
+            self.stop_bits_state.select(Some(idx));
+        }
+        if let Some(idx) = self.ports.iter().position(|p| *p == self.config.port) {
+            self.port_list_state.select(Some(idx));
+        }
+    }
+
+    // Staged configuration
+
+    /// The config used to connect: the applied config while staging,
+    /// otherwise the config being edited
+    pub fn active_config(&self) -> &SerialConfig {
+        self.applied_config.as_ref().unwrap_or(&self.config)
+    }
+
+    /// Turn config staging on or off. Turning it off keeps the pending
+    /// edits, which then take effect directly.
+    pub fn set_config_staging(&mut self, enabled: bool) {
+        self.settings.stage_config_changes = enabled;
+        self.applied_config = enabled.then(|| self.config.clone());
+    }
+
+    /// Whether pending edits differ from the applied config
+    pub fn has_staged_changes(&self) -> bool {
+        self.applied_config
+            .as_ref()
+            .is_some_and(|applied| *applied != self.config)
+    }
+
+    /// Fields whose pending value differs from the applied config
+    pub fn staged_changes(&self) -> Vec<StagedChange> {
+        let Some(applied) = &self.applied_config else {
+            return Vec::new();
+        };
+        let pending = &self.config;
+        let fields: [(&'static str, String, String); 6] = [
+            ("label.port", applied.port.clone(), pending.port.clone()),
+            (
+                "label.baud_rate",
+                applied.baud_rate.to_string(),
+                pending.baud_rate.to_string(),
+            ),
+            (
+                "label.data_bits",
+                applied.data_bits.to_string(),
+                pending.data_bits.to_string(),
+            ),
+            (
+                "label.parity",
+                format!("{:?}", applied.parity),
+                format!("{:?}", pending.parity),
+            ),
+            (
+                "label.stop_bits",
+                applied.stop_bits.to_string(),
+                pending.stop_bits.to_string(),
+            ),
+            (
+                "label.flow_control",
+                format!("{:?}", applied.flow_control),
+                format!("{:?}", pending.flow_control),
+            ),
+        ];
+        fields
+            .into_iter()
+            .filter(|(_, a, p)| a != p)
+            .map(|(label, applied, pending)| StagedChange {
+                label,
+                applied,
+                pending,
+            })
+            .collect()
+    }
+
+    /// Make the pending edits the applied config. Returns `false` if there
+    /// was nothing to apply or the config is locked.
+    pub fn apply_staged_config(&mut self) -> bool {
+        if !self.has_staged_changes() || !self.can_modify_config() {
+            return false;
+        }
+        self.applied_config = Some(self.config.clone());
+        true
+    }
+
+    /// Discard the pending edits. Returns `false` if there was nothing to
+    /// revert or the config is locked.
+    pub fn revert_staged_config(&mut self) -> bool {
+        if !self.has_staged_changes() || !self.can_modify_config() {
+            return false;
+        }
+        if let Some(applied) = self.applied_config.clone() {
+            self.config = applied;
+            self.sync_config_states();
+        }
+        true
     }
 
     // Language management
@@ -558,4 +1615,1321 @@ impl AppState {
     pub fn hide_shortcuts_help(&mut self) {
         self.show_shortcuts_help = false;
     }
+
+    // Diagnostics
+
+    /// Plain-text summary of the app state for bug reports: version,
+    /// platform, config, settings, ports and counts. Captured data, the TX
+    /// input and the TX prefix/suffix bytes (only their lengths) are left
+    /// out.
+    pub fn diagnostics_report(&self) -> String {
+        use std::fmt::Write;
+
+        let log = &self.message_log;
+        let mut report = String::new();
+        let _ = writeln!(report, "tuiserial diagnostics");
+        let _ = writeln!(report, "version: {}", env!("CARGO_PKG_VERSION"));
+        let _ = writeln!(
+            report,
+            "os: {} ({})",
+            std::env::consts::OS,
+            std::env::consts::ARCH
+        );
+        let _ = writeln!(report, "language: {}", self.language.code());
+        let _ = writeln!(report, "connection: {:?}", self.connection);
+        let config = self.active_config();
+        let _ = writeln!(
+            report,
+            "config: {}, flow control {:?}",
+            config.format_display(),
+            config.flow_control
+        );
+        let _ = writeln!(report, "staged changes: {}", self.staged_changes().len());
+        let _ = writeln!(
+            report,
+            "display: {:?}, tx mode: {:?}, append: {}",
+            self.display_mode,
+            self.tx_mode,
+            self.tx_append_mode.name()
+        );
+        let _ = writeln!(
+            report,
+            "ports ({}): {}",
+            self.ports.len(),
+            self.ports.join(", ")
+        );
+        let _ = writeln!(
+            report,
+            "log: {} entries, RX {} ({} bytes), TX {} ({} bytes)",
+            log.entries.len(),
+            log.rx_count,
+            log.rx_bytes,
+            log.tx_count,
+            log.tx_bytes
+        );
+        let _ = writeln!(
+            report,
+            "errors: {} logged, {} notifications shown",
+            self.error_log.len(),
+            self.notifications.len()
+        );
+        let _ = writeln!(
+            report,
+            "plugins: {} loaded, {} failed, {} total",
+            self.plugin_loaded_count, self.plugin_error_count, self.plugin_total_count
+        );
+        let _ = writeln!(report, "settings:");
+        if let Ok(serde_json::Value::Object(settings)) = serde_json::to_value(&self.settings) {
+            for (key, value) in settings {
+                match value {
+                    serde_json::Value::Array(bytes) if key == "tx_prefix" || key == "tx_suffix" => {
+                        let _ = writeln!(report, "  {key} = {} bytes", bytes.len());
+                    }
+                    value => {
+                        let _ = writeln!(report, "  {key} = {value}");
+                    }
+                }
+            }
+        }
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn events(app: &AppState) -> Vec<(LogEventKind, String)> {
+        app.message_log
+            .entries
+            .iter()
+            .filter_map(|e| {
+                e.event_kind()
+                    .map(|k| (k, String::from_utf8_lossy(&e.data).to_string()))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_append_rx_follows_when_auto_scroll() {
+        let mut app = AppState::default();
+        app.append_rx(vec![1]);
+        app.append_rx(vec![2]);
+        assert_eq!(app.message_log.rx_count, 2);
+        assert_eq!(app.scroll_offset, 1);
+
+        app.auto_scroll = false;
+        app.append_rx(vec![3]);
+        assert_eq!(app.scroll_offset, 1);
+    }
+
+    #[test]
+    fn test_append_rx_timing() {
+        // Sustained append at full log capacity with injected read times:
+        // eviction keeps the log bounded and the rate and timing records
+        // follow the read clock, not the wall clock. The wall-clock bound is
+        // deliberately generous; it only catches a quadratic append path.
+        let mut app = AppState::default();
+        app.settings.rx_chunk_timing = true;
+        let chunk = vec![0x55u8; crate::settings::DEFAULT_RX_READ_BUFFER];
+        let chunks = 200_000u32;
+
+        let start = Instant::now();
+        let step = Duration::from_micros(100);
+        for i in 0..chunks {
+            app.append_rx_at(chunk.clone(), start + step * i);
+        }
+        let elapsed = start.elapsed();
+        assert!(
+            elapsed < Duration::from_secs(10),
+            "append path too slow: {chunks} chunks in {elapsed:?}"
+        );
+        let end = start + step * (chunks - 1);
+
+        assert_eq!(app.message_log.rx_count, chunks as u64);
+        assert_eq!(app.message_log.entries.len(), crate::log::MAX_LOG_LINES);
+        // 256 B every 100 µs over the last second
+        let rate = app.rx_rate.bytes_per_sec_at(end);
+        assert!((rate - 2_560_000.0).abs() < 5_000.0, "{rate}");
+        let last_seq = app.message_log.last_seq;
+        let deltas = app.rx_timing.deltas_from(last_seq - 9, 10);
+        assert_eq!(deltas.len(), 10);
+        assert!(deltas.iter().all(|d| d.delta == Some(step)));
+    }
+
+    #[test]
+    fn test_connect_disconnect_events_when_enabled() {
+        let mut app = AppState::default();
+        app.settings.log_events = true;
+        app.config.port = "/dev/ttyUSB0".into();
+
+        app.mark_connected();
+        app.message_log.push_rx(vec![0x41]);
+        app.mark_disconnected(None);
+
+        let ev = events(&app);
+        assert_eq!(ev.len(), 2);
+        assert_eq!(ev[0].0, LogEventKind::Connect);
+        assert!(ev[0].1.contains("/dev/ttyUSB0"));
+        assert_eq!(ev[1].0, LogEventKind::Disconnect);
+        assert!(ev[1].1.contains("/dev/ttyUSB0"));
+
+        // Events sit in the timeline between traffic entries
+        let dirs: Vec<LogDirection> = app
+            .message_log
+            .entries
+            .iter()
+            .map(|e| e.direction)
+            .collect();
+        assert_eq!(
+            dirs,
+            vec![
+                LogDirection::Event(LogEventKind::Connect),
+                LogDirection::Rx,
+                LogDirection::Event(LogEventKind::Disconnect),
+            ]
+        );
+        assert_eq!(app.message_log.rx_count, 1);
+    }
+
+    #[test]
+    fn test_disconnect_reason_included() {
+        let mut app = AppState::default();
+        app.settings.log_events = true;
+        app.mark_disconnected(Some("emergency stop"));
+        assert!(events(&app)[0].1.ends_with("(emergency stop)"));
+    }
+
+    #[test]
+    fn test_no_events_when_disabled() {
+        let mut app = AppState::default();
+        assert!(!app.settings.log_events);
+
+        app.mark_connected();
+        assert!(app.is_connected());
+        assert!(!app.can_modify_config());
+        app.mark_disconnected(None);
+        assert!(!app.is_connected());
+        assert!(app.can_modify_config());
+
+        assert!(app.message_log.entries.is_empty());
+    }
+
+    #[test]
+    fn test_errors_logged_as_events() {
+        let mut app = AppState::default();
+        app.settings.log_events = true;
+        app.record_error(AppError::Serial {
+            kind: crate::error::SerialErrorKind::NotConnected,
+            ctx: crate::error::ErrorContext::new(
+                "serial",
+                "read",
+                crate::error::RecoveryStrategy::Skip,
+            ),
+        });
+        let ev = events(&app);
+        assert_eq!(ev.len(), 1);
+        assert_eq!(ev[0].0, LogEventKind::Error);
+    }
+
+    #[test]
+    fn test_bounded_repeat_auto_stops() {
+        let mut app = AppState::default();
+        let start = Instant::now();
+        let interval = Duration::from_millis(100);
+        app.start_repeat(RepeatSend::bounded(b"AT".to_vec(), 3, interval, start));
+
+        let mut now = start;
+        let mut sends = 0;
+        let mut finished = None;
+        while let Some(payload) = app.due_repeat_payload(now) {
+            assert_eq!(payload, b"AT");
+            sends += 1;
+            finished = app.record_repeat_sent(now);
+            now += interval;
+        }
+
+        assert_eq!(sends, 3);
+        assert!(app.repeat_send.is_none());
+        assert_eq!(finished.unwrap().progress(), "3/3");
+    }
+
+    #[test]
+    fn test_bounded_repeat_not_due_between_intervals() {
+        let mut app = AppState::default();
+        let start = Instant::now();
+        app.start_repeat(RepeatSend::bounded(
+            vec![1],
+            5,
+            Duration::from_millis(100),
+            start,
+        ));
+
+        assert!(app.due_repeat_payload(start).is_some());
+        assert!(app.record_repeat_sent(start).is_none());
+        assert!(
+            app.due_repeat_payload(start + Duration::from_millis(50))
+                .is_none()
+        );
+        assert_eq!(app.repeat_send.as_ref().unwrap().remaining(), Some(4));
+    }
+
+    #[test]
+    fn test_bounded_repeat_cancel_mid_run() {
+        let mut app = AppState::default();
+        let start = Instant::now();
+        let interval = Duration::from_millis(10);
+        app.start_repeat(RepeatSend::bounded(vec![1], 100, interval, start));
+
+        let mut now = start;
+        for _ in 0..37 {
+            assert!(app.due_repeat_payload(now).is_some());
+            app.record_repeat_sent(now);
+            now += interval;
+        }
+
+        let cancelled = app.cancel_repeat().unwrap();
+        assert_eq!(cancelled.progress(), "37/100");
+        assert!(app.due_repeat_payload(now).is_none());
+        assert!(app.record_repeat_sent(now).is_none());
+    }
+
+    #[test]
+    fn test_focused_entry_skips_events() {
+        let mut app = AppState::default();
+        assert!(app.focused_entry().is_none());
+
+        app.append_rx(vec![1]);
+        app.mark_connected();
+        app.append_rx(vec![2]);
+        app.log_event(LogEventKind::Marker, "mark");
+        // Following: newest data entry, skipping the trailing marker
+        assert_eq!(app.focused_entry().unwrap().data, vec![2]);
+
+        assert!(app.move_entry_focus(false));
+        assert!(!app.auto_scroll);
+        assert_eq!(app.focused_entry().unwrap().data, vec![1]);
+        assert!(!app.move_entry_focus(false));
+
+        // Moving forward jumps over the connect event
+        assert!(app.move_entry_focus(true));
+        assert_eq!(app.focused_entry().unwrap().data, vec![2]);
+        assert!(!app.move_entry_focus(true));
+    }
+
+    #[test]
+    fn test_clear_log_resets_scroll_state() {
+        let mut app = AppState::default();
+        for i in 0..50 {
+            app.append_rx(vec![i]);
+        }
+        app.auto_scroll = false;
+        app.scroll_offset = 42;
+        app.byte_inspector = Some(ByteInspector::default());
+
+        app.clear_log();
+        assert!(app.message_log.entries.is_empty());
+        assert_eq!(app.scroll_offset, 0);
+        assert!(app.auto_scroll);
+        assert!(app.byte_inspector.is_none());
+        assert!(app.focused_entry().is_none());
+
+        // New data is followed from the top again
+        app.append_rx(vec![1]);
+        assert_eq!(app.scroll_offset, 0);
+    }
+
+    #[test]
+    fn test_clear_log_respects_follow_setting() {
+        let mut app = AppState::default();
+        app.settings.follow_on_clear = false;
+        app.append_rx(vec![1]);
+        app.auto_scroll = false;
+        app.scroll_offset = 5;
+
+        app.clear_log();
+        assert_eq!(app.scroll_offset, 0);
+        assert!(!app.auto_scroll);
+    }
+
+    #[test]
+    fn test_first_run_shows_help_once() {
+        let mut app = AppState::default();
+        assert!(app.show_first_run_help(true));
+        assert!(app.show_shortcuts_help);
+        assert!(!app.settings.show_help_on_first_run);
+
+        // Dismissed as usual, and not shown again
+        app.hide_shortcuts_help();
+        assert!(!app.show_first_run_help(true));
+        assert!(!app.show_shortcuts_help);
+    }
+
+    #[test]
+    fn test_first_run_help_skipped_with_existing_config_or_opt_out() {
+        let mut app = AppState::default();
+        assert!(!app.show_first_run_help(false));
+        assert!(!app.show_shortcuts_help);
+        assert!(app.settings.show_help_on_first_run);
+
+        app.settings.show_help_on_first_run = false;
+        assert!(!app.show_first_run_help(true));
+        assert!(!app.show_shortcuts_help);
+    }
+
+    #[test]
+    fn test_first_run_flag_persists() {
+        let mut app = AppState::default();
+        app.show_first_run_help(true);
+        let json = serde_json::to_string(&ConfigFile {
+            serial: app.config.clone(),
+            settings: app.settings.clone(),
+        })
+        .unwrap();
+        let file: ConfigFile = serde_json::from_str(&json).unwrap();
+        assert!(!file.settings.show_help_on_first_run);
+    }
+
+    #[test]
+    fn test_load_config_missing_vs_corrupt() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.json");
+        let mut app = AppState::default();
+        assert_eq!(app.load_config_from(&path), ConfigLoad::Missing);
+
+        std::fs::write(&path, "{ \"port\": ").unwrap();
+        app.config.baud_rate = 9600;
+        match app.load_config_from(&path) {
+            ConfigLoad::Corrupt { path: p, reason } => {
+                assert_eq!(p, path);
+                assert!(!reason.is_empty());
+            }
+            other => panic!("expected Corrupt, got {other:?}"),
+        }
+        // Nothing from the broken file is applied
+        assert_eq!(app.config.baud_rate, 9600);
+    }
+
+    #[test]
+    fn test_load_config_prefers_newer_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let primary = dir.path().join("config.json");
+        let fallback = dir.path().join(FALLBACK_CONFIG_FILE);
+
+        let mut app = AppState::default();
+        app.config.baud_rate = 9600;
+        app.save_config_to(&primary).unwrap();
+        app.config.baud_rate = 57600;
+        app.save_config_to(&fallback).unwrap();
+        let older = std::time::SystemTime::now() - Duration::from_secs(3600);
+        std::fs::File::options()
+            .write(true)
+            .open(&primary)
+            .unwrap()
+            .set_modified(older)
+            .unwrap();
+
+        // A stale primary does not shadow a newer fallback
+        let mut loaded = AppState::default();
+        assert_eq!(
+            loaded.load_config_with(Some(primary.clone()), Some(fallback.clone())),
+            ConfigLoad::Loaded(fallback.clone())
+        );
+        assert_eq!(loaded.config.baud_rate, 57600);
+
+        // And the other way round
+        std::fs::File::options()
+            .write(true)
+            .open(&fallback)
+            .unwrap()
+            .set_modified(older - Duration::from_secs(3600))
+            .unwrap();
+        let mut loaded = AppState::default();
+        assert_eq!(
+            loaded.load_config_with(Some(primary.clone()), Some(fallback)),
+            ConfigLoad::Loaded(primary)
+        );
+        assert_eq!(loaded.config.baud_rate, 9600);
+    }
+
+    #[test]
+    fn test_load_config_falls_through_corrupt_primary() {
+        let dir = tempfile::tempdir().unwrap();
+        let primary = dir.path().join("config.json");
+        let fallback = dir.path().join(FALLBACK_CONFIG_FILE);
+
+        let mut app = AppState::default();
+        app.config.baud_rate = 57600;
+        app.save_config_to(&fallback).unwrap();
+        // Written after the fallback, so it is tried first
+        std::fs::write(&primary, "{ not json").unwrap();
+        let older = std::time::SystemTime::now() - Duration::from_secs(3600);
+        std::fs::File::options()
+            .write(true)
+            .open(&fallback)
+            .unwrap()
+            .set_modified(older)
+            .unwrap();
+
+        let mut loaded = AppState::default();
+        assert_eq!(
+            loaded.load_config_with(Some(primary.clone()), Some(fallback.clone())),
+            ConfigLoad::Loaded(fallback)
+        );
+        assert_eq!(loaded.config.baud_rate, 57600);
+
+        // With nothing else to load, the corrupt file is reported
+        let mut loaded = AppState::default();
+        match loaded.load_config_with(Some(primary.clone()), None) {
+            ConfigLoad::Corrupt { path, .. } => assert_eq!(path, primary),
+            other => panic!("expected Corrupt, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_save_config_falls_back_when_primary_unwritable() {
+        let dir = tempfile::tempdir().unwrap();
+        // A file where the config directory should be makes the primary fail
+        let blocker = dir.path().join("blocker");
+        std::fs::write(&blocker, "").unwrap();
+        let primary = blocker.join("config.json");
+        let fallback = dir.path().join("state").join(FALLBACK_CONFIG_FILE);
+
+        let mut app = AppState::default();
+        app.config.baud_rate = 9600;
+        match app.save_config_with(Some(primary.clone()), Some(fallback.clone())) {
+            Ok(ConfigSave::Fallback { path, reason }) => {
+                assert_eq!(path, fallback);
+                assert!(!reason.is_empty());
+            }
+            other => panic!("expected Fallback, got {other:?}"),
+        }
+
+        let mut loaded = AppState::default();
+        assert_eq!(
+            loaded.load_config_from(&fallback),
+            ConfigLoad::Loaded(fallback.clone())
+        );
+        assert_eq!(loaded.config.baud_rate, 9600);
+
+        // Both locations blocked reports each path
+        let blocked_fallback = blocker.join(FALLBACK_CONFIG_FILE);
+        match app.save_config_with(Some(primary), Some(blocked_fallback)) {
+            Err(e @ CoreError::ConfigNotWritable { .. }) => {
+                assert!(matches!(
+                    crate::ConfigErrorKind::from(e),
+                    crate::ConfigErrorKind::NotWritable(_)
+                ));
+            }
+            other => panic!("expected ConfigNotWritable, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_fallback_config_path_is_per_user() {
+        if let Some(path) = AppState::fallback_config_path() {
+            assert!(path.is_absolute());
+            assert!(path.ends_with(Path::new("tuiserial").join(FALLBACK_CONFIG_FILE)));
+        }
+    }
+
+    #[test]
+    fn test_save_then_load_config_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nested").join("config.json");
+        let mut app = AppState::default();
+        app.config.baud_rate = 9600;
+        app.settings.follow_on_clear = false;
+        app.save_config_to(&path).unwrap();
+
+        let mut loaded = AppState::default();
+        assert_eq!(loaded.load_config_from(&path), ConfigLoad::Loaded(path));
+        assert_eq!(loaded.config.baud_rate, 9600);
+        assert!(!loaded.settings.follow_on_clear);
+    }
+
+    #[test]
+    fn test_save_config_to_unwritable_dir_fails() {
+        let dir = tempfile::tempdir().unwrap();
+        // A regular file where the config directory should be
+        let blocker = dir.path().join("tuiserial");
+        std::fs::write(&blocker, "").unwrap();
+        let mut app = AppState::default();
+        app.config.baud_rate = 115200;
+        assert!(app.save_config_to(&blocker.join("config.json")).is_err());
+        // A failed save leaves the changes marked as unsaved
+        assert!(app.has_unsaved_changes());
+    }
+
+    #[test]
+    fn test_staged_config_apply_and_revert() {
+        let mut app = AppState::default();
+        app.set_config_staging(true);
+        assert!(!app.has_staged_changes());
+
+        app.config.baud_rate = 115200;
+        assert_eq!(app.active_config().baud_rate, 9600);
+        let changes = app.staged_changes();
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].label, "label.baud_rate");
+        assert_eq!(changes[0].applied, "9600");
+        assert_eq!(changes[0].pending, "115200");
+
+        assert!(app.apply_staged_config());
+        assert_eq!(app.active_config().baud_rate, 115200);
+        assert!(!app.apply_staged_config());
+
+        app.config.baud_rate = 57600;
+        assert!(app.revert_staged_config());
+        assert_eq!(app.config.baud_rate, 115200);
+        assert!(app.staged_changes().is_empty());
+    }
+
+    #[test]
+    fn test_staged_config_refused_while_locked() {
+        let mut app = AppState::default();
+        app.set_config_staging(true);
+        app.config.data_bits = 7;
+        app.lock_config();
+        assert!(!app.apply_staged_config());
+        assert!(!app.revert_staged_config());
+        assert_eq!(app.active_config().data_bits, 8);
+        assert_eq!(app.config.data_bits, 7);
+    }
+
+    #[test]
+    fn test_disabling_staging_keeps_pending_edits() {
+        let mut app = AppState::default();
+        assert_eq!(app.active_config(), &app.config);
+        app.set_config_staging(true);
+        app.config.port = "/dev/ttyUSB1".into();
+        app.set_config_staging(false);
+        assert_eq!(app.active_config().port, "/dev/ttyUSB1");
+        assert!(app.staged_changes().is_empty());
+    }
+
+    fn one_row_each(app: &AppState) -> LogLayout {
+        LogLayout::from_rows(std::iter::repeat_n(1, app.visible_entry_count()))
+    }
+
+    #[test]
+    fn test_fit_scroll_repins_when_following() {
+        let mut app = AppState::default();
+        for i in 0..50 {
+            app.append_rx(vec![i]);
+        }
+        let layout = one_row_each(&app);
+        app.fit_scroll_to_viewport(&layout, 20);
+        assert_eq!(app.scroll_offset, 30);
+        app.fit_scroll_to_viewport(&layout, 10);
+        assert_eq!(app.scroll_offset, 40);
+        app.fit_scroll_to_viewport(&layout, 80);
+        assert_eq!(app.scroll_offset, 0);
+    }
+
+    #[test]
+    fn test_fit_scroll_clamps_when_scrolled_back() {
+        let mut app = AppState::default();
+        for i in 0..50 {
+            app.append_rx(vec![i]);
+        }
+        let layout = one_row_each(&app);
+        app.auto_scroll = false;
+        app.scroll_offset = 35;
+        app.fit_scroll_to_viewport(&layout, 10);
+        assert_eq!(app.scroll_offset, 35);
+        app.fit_scroll_to_viewport(&layout, 20);
+        assert_eq!(app.scroll_offset, 30);
+        // Growing again does not move the view back down
+        app.fit_scroll_to_viewport(&layout, 10);
+        assert_eq!(app.scroll_offset, 30);
+        assert!(!app.auto_scroll);
+        // A zero-height viewport is treated as one row
+        app.fit_scroll_to_viewport(&layout, 0);
+        assert_eq!(app.scroll_offset, 30);
+    }
+
+    #[test]
+    fn test_fit_scroll_counts_rows_not_entries() {
+        let mut app = AppState::default();
+        for i in 0..10 {
+            app.append_rx(vec![i]);
+        }
+        // Every entry after the first has a gap row above it: 19 rows
+        let layout = LogLayout::from_rows((0..10).map(|i| if i == 0 { 1 } else { 2 }));
+        app.fit_scroll_to_viewport(&layout, 5);
+        // Rows 14..19 are the last page; entry 7 starts on row 13, entry 8 on 15
+        assert_eq!(app.scroll_offset, 8);
+        assert_eq!(app.log_position_at_row(&layout, 5, 0), Some(7));
+        assert_eq!(app.log_position_at_row(&layout, 5, 4), Some(9));
+
+        app.auto_scroll = false;
+        app.scroll_offset = 2;
+        assert_eq!(app.log_position_at_row(&layout, 5, 0), Some(2));
+        assert_eq!(app.log_position_at_row(&layout, 5, 2), Some(3));
+    }
+
+    fn mixed_log() -> AppState {
+        let mut app = AppState::default();
+        for data in [&b"boot\r\n"[..], &[0x02, 0x10], b"ready", &[0xFF], b"idle"] {
+            app.append_rx(data.to_vec());
+        }
+        app
+    }
+
+    #[test]
+    fn test_only_binary_filter_counts_and_scroll() {
+        let mut app = mixed_log();
+        assert_eq!(app.visible_entry_count(), 5);
+        app.toggle_only_binary();
+        assert_eq!(app.visible_entry_indices(), vec![1, 3]);
+        assert_eq!(app.visible_entry_count(), 2);
+        assert_eq!(app.focused_entry().unwrap().data, vec![0xFF]);
+
+        app.auto_scroll = false;
+        app.scroll_offset = 9;
+        app.fit_scroll_to_viewport(&one_row_each(&app), 1);
+        assert_eq!(app.scroll_offset, 1);
+        app.scroll_offset = 0;
+        assert_eq!(app.focused_entry().unwrap().data, vec![0x02, 0x10]);
+    }
+
+    #[test]
+    fn test_toggle_only_binary_keeps_focus_near() {
+        let mut app = mixed_log();
+        app.auto_scroll = false;
+        app.scroll_offset = 2; // "ready"
+        app.toggle_only_binary();
+        // "ready" is hidden; the next binary entry takes its place
+        assert_eq!(app.scroll_offset, 1);
+        assert_eq!(app.focused_entry().unwrap().data, vec![0xFF]);
+        app.toggle_only_binary();
+        assert_eq!(app.scroll_offset, 3);
+    }
+
+    #[test]
+    fn test_jump_to_binary_entry() {
+        let mut app = mixed_log();
+        app.auto_scroll = false;
+        app.scroll_offset = 0;
+        assert!(app.jump_to_binary_entry(true));
+        assert_eq!(app.scroll_offset, 1);
+        assert!(app.jump_to_binary_entry(true));
+        assert_eq!(app.scroll_offset, 3);
+        assert!(!app.jump_to_binary_entry(true));
+        assert!(app.jump_to_binary_entry(false));
+        assert_eq!(app.scroll_offset, 1);
+
+        // From follow mode only earlier entries are candidates
+        app.auto_scroll = true;
+        assert!(!app.jump_to_binary_entry(true));
+        assert!(app.jump_to_binary_entry(false));
+        assert!(!app.auto_scroll);
+        assert_eq!(app.scroll_offset, 3);
+    }
+
+    #[test]
+    fn test_selection_locks_scroll_until_cleared() {
+        let mut app = AppState::default();
+        for i in 0..30 {
+            app.append_rx(vec![i]);
+        }
+        app.fit_scroll_to_viewport(&one_row_each(&app), 10);
+        app.begin_log_selection(22);
+        app.extend_log_selection(25);
+        assert!(!app.auto_scroll);
+        // Stored as entry seqs, which start at 1
+        assert_eq!(app.log_selection.unwrap().range(), 23..=26);
+
+        // New data no longer moves the view
+        app.append_rx(vec![0xFF]);
+        assert_eq!(app.scroll_offset, 20);
+
+        app.clear_log_selection();
+        assert!(app.auto_scroll);
+        assert_eq!(app.log_selection, None);
+
+        // Scrolled back before selecting: clearing keeps the position
+        app.auto_scroll = false;
+        app.begin_log_selection(3);
+        app.clear_log_selection();
+        assert!(!app.auto_scroll);
+    }
+
+    #[test]
+    fn test_selection_without_scroll_lock() {
+        let mut app = AppState::default();
+        app.settings.scroll_lock_on_select = false;
+        for i in 0..8 {
+            app.append_rx(vec![i]);
+        }
+        app.begin_log_selection(5);
+        assert!(app.auto_scroll);
+        app.extend_log_selection(1);
+        assert_eq!(app.log_selection.unwrap().range(), 2..=6);
+        // Past the last entry nothing changes
+        app.extend_log_selection(8);
+        assert_eq!(app.log_selection.unwrap().range(), 2..=6);
+    }
+
+    #[test]
+    fn test_selection_stays_on_entries_across_filter_and_eviction() {
+        let mut app = mixed_log();
+        // Positions 1..=3: [0x02, 0x10], "ready" and [0xFF]
+        app.begin_log_selection(1);
+        app.extend_log_selection(3);
+        let selected = |app: &AppState| -> Vec<Vec<u8>> {
+            app.selected_entries()
+                .iter()
+                .map(|e| e.data.clone())
+                .collect()
+        };
+        assert_eq!(selected(&app).len(), 3);
+
+        // Hiding text entries shifts positions but not the selection
+        app.toggle_only_binary();
+        assert_eq!(selected(&app), [vec![0x02, 0x10], vec![0xFF]]);
+        app.toggle_only_binary();
+
+        // Older entries dropping out of the log leave it in place too
+        app.message_log.entries.pop_front();
+        assert_eq!(selected(&app).len(), 3);
+        app.message_log.entries.pop_front();
+        assert_eq!(selected(&app).len(), 2);
+    }
+
+    #[test]
+    fn test_clear_log_on_connect() {
+        let mut app = AppState::default();
+        app.settings.log_events = true;
+        app.append_rx(b"old".to_vec());
+        app.mark_connected();
+        app.mark_disconnected(None);
+        // Off by default: earlier entries are kept
+        assert_eq!(app.message_log.entries.len(), 3);
+
+        app.settings.clear_log_on_connect = true;
+        app.mark_connected();
+        assert_eq!(app.message_log.entries.len(), 1);
+        assert_eq!(
+            app.message_log.entries[0].event_kind(),
+            Some(LogEventKind::Connect)
+        );
+        assert_eq!(app.message_log.rx_count, 0);
+    }
+
+    #[test]
+    fn test_export_since_checkpoint() {
+        let mut app = AppState::default();
+        app.append_rx(vec![1]);
+        app.message_log.push_tx(vec![2]);
+        let first = app.set_checkpoint();
+        assert_eq!(first.number, 1);
+        assert_eq!(
+            app.message_log.entries.back().unwrap().event_kind(),
+            Some(LogEventKind::Marker)
+        );
+
+        app.append_rx(vec![3]);
+        app.message_log.push_tx(vec![4]);
+        let exported: Vec<&[u8]> = app
+            .export_entries(&first.filter())
+            .iter()
+            .map(|e| e.data.as_slice())
+            .collect();
+        assert_eq!(exported, vec![&[3][..], &[4][..]]);
+
+        let second = app.set_checkpoint();
+        assert_eq!(second.number, 2);
+        assert!(app.export_entries(&second.filter()).is_empty());
+
+        app.clear_log();
+        assert_eq!(app.checkpoint, None);
+    }
+
+    #[test]
+    fn test_connection_state_connect_success() {
+        let mut app = AppState::default();
+        assert_eq!(app.connection, ConnectionState::Disconnected);
+        app.begin_connecting();
+        assert_eq!(app.connection, ConnectionState::Connecting);
+        assert!(!app.is_connected());
+        assert!(!app.can_modify_config());
+        app.mark_connected();
+        assert_eq!(app.connection, ConnectionState::Connected);
+        assert!(app.is_connected());
+    }
+
+    #[test]
+    fn test_connection_state_connect_failure() {
+        let mut app = AppState::default();
+        app.begin_connecting();
+        app.mark_connect_failed("Permission denied");
+        assert_eq!(
+            app.connection,
+            ConnectionState::Error("Permission denied".into())
+        );
+        assert!(!app.is_connected());
+        assert!(app.can_modify_config());
+
+        // A later attempt starts over from the error state
+        app.begin_connecting();
+        app.mark_connected();
+        assert!(app.is_connected());
+    }
+
+    #[test]
+    fn test_connection_state_disconnect() {
+        let mut app = AppState::default();
+        app.settings.log_events = true;
+        app.begin_connecting();
+        app.mark_connected();
+        app.mark_disconnected(None);
+        assert_eq!(app.connection, ConnectionState::Disconnected);
+        assert!(app.can_modify_config());
+
+        app.begin_connecting();
+        app.mark_connected();
+        app.mark_connection_lost("too many read errors");
+        assert_eq!(
+            app.connection,
+            ConnectionState::Error("too many read errors".into())
+        );
+        assert!(app.can_modify_config());
+        assert_eq!(events(&app).last().unwrap().0, LogEventKind::Disconnect);
+    }
+
+    #[test]
+    fn test_connection_state_cancel_and_reconnect() {
+        let mut app = AppState::default();
+        app.begin_connecting();
+        app.cancel_connecting();
+        assert_eq!(app.connection, ConnectionState::Disconnected);
+        assert!(app.can_modify_config());
+
+        app.begin_connecting();
+        app.mark_connected();
+        app.append_rx(vec![1]);
+        app.step_baud_for_reconnect(true);
+        app.begin_connecting();
+        app.mark_reconnected();
+        assert!(app.is_connected());
+        assert!(!app.can_modify_config());
+        assert_eq!(app.message_log.rx_count, 1);
+    }
+
+    #[test]
+    fn test_rx_after_disconnect_not_logged() {
+        let mut app = AppState::default();
+        app.begin_connecting();
+        app.mark_connected();
+        app.receive_rx(vec![1], Instant::now());
+        assert_eq!(app.message_log.rx_count, 1);
+
+        app.mark_disconnected(None);
+        let entries = app.message_log.entries.len();
+        app.receive_rx(vec![2, 3], Instant::now());
+        assert_eq!(app.message_log.entries.len(), entries);
+        assert_eq!(app.message_log.rx_count, 1);
+        assert!(app.late_rx.is_empty());
+
+        // Nor while a reconnect is still opening the port
+        app.begin_connecting();
+        app.receive_rx(vec![4], Instant::now());
+        assert_eq!(app.message_log.entries.len(), entries);
+    }
+
+    #[test]
+    fn test_late_rx_buffered_with_warning() {
+        let mut app = AppState::default();
+        app.settings.disconnected_rx = DisconnectedRx::BufferAndWarn;
+        app.receive_rx(vec![1, 2], Instant::now());
+        app.receive_rx(vec![3], Instant::now());
+        assert!(app.message_log.entries.is_empty());
+        assert_eq!(app.late_rx, vec![1, 2, 3]);
+        // One warning per disconnected period
+        assert_eq!(app.notifications.len(), 1);
+
+        // Connecting logs the kept bytes after a marker, even without events
+        app.settings.log_events = false;
+        app.mark_connected();
+        assert!(app.late_rx.is_empty());
+        let entries: Vec<_> = app.message_log.entries.iter().collect();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].event_kind(), Some(LogEventKind::Marker));
+        assert!(String::from_utf8_lossy(&entries[0].data).contains('3'));
+        assert_eq!(
+            (entries[1].direction, entries[1].data.as_slice()),
+            (LogDirection::Rx, [1, 2, 3].as_slice())
+        );
+
+        // The next disconnected period warns again
+        app.mark_disconnected(None);
+        app.receive_rx(vec![4], Instant::now());
+        assert_eq!(app.notifications.len(), 2);
+    }
+
+    #[test]
+    fn test_config_collapsed_expands_on_focus() {
+        let mut app = AppState::default();
+        assert!(!app.config_collapsed());
+
+        app.settings.compact_config = true;
+        app.focused_field = FocusedField::Port;
+        assert!(app.config_collapsed());
+        app.focused_field = FocusedField::TxInput;
+        assert!(app.config_collapsed());
+        for field in [
+            FocusedField::DataBits,
+            FocusedField::Parity,
+            FocusedField::StopBits,
+            FocusedField::FlowControl,
+        ] {
+            app.focused_field = field;
+            assert!(!app.config_collapsed());
+        }
+    }
+
+    #[test]
+    fn test_unsaved_changes_tracking() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.json");
+        let mut app = AppState::default();
+        assert!(!app.has_unsaved_changes());
+
+        app.config.baud_rate = 115200;
+        assert!(app.has_unsaved_changes());
+        app.save_config_to(&path).unwrap();
+        assert!(!app.has_unsaved_changes());
+
+        // View preferences count too, and changing back clears the marker
+        app.settings.show_idle_gaps = !app.settings.show_idle_gaps;
+        assert!(app.has_unsaved_changes());
+        app.settings.show_idle_gaps = !app.settings.show_idle_gaps;
+        assert!(!app.has_unsaved_changes());
+
+        app.config.stop_bits = 2;
+        let mut loaded = AppState::default();
+        loaded.load_config_from(&path);
+        assert!(!loaded.has_unsaved_changes());
+        assert_eq!(loaded.config.baud_rate, 115200);
+    }
+
+    #[test]
+    fn test_startup_port_choice_is_not_unsaved() {
+        let mut app = AppState::default();
+        // As startup does when no port was saved
+        app.config.port = "/dev/ttyUSB0".to_string();
+        assert!(app.has_unsaved_changes());
+        app.mark_saved();
+        assert!(!app.has_unsaved_changes());
+        app.config.port = "/dev/ttyUSB1".to_string();
+        assert!(app.has_unsaved_changes());
+    }
+
+    #[test]
+    fn test_dual_view_independent_of_main_view() {
+        let mut app = AppState::default();
+        assert_eq!(app.toggle_secondary_display_mode(), None);
+
+        assert!(app.toggle_dual_view());
+        let secondary = app.dual_view.unwrap();
+        assert_eq!(secondary.display_mode, app.display_mode.toggled());
+
+        // Changing one view leaves the other alone
+        app.toggle_display_mode();
+        assert_eq!(app.dual_view, Some(secondary));
+        assert_eq!(app.toggle_secondary_display_mode(), Some(DisplayMode::Hex));
+        assert_eq!(app.primary_view().display_mode, app.display_mode);
+
+        assert!(!app.toggle_dual_view());
+        assert_eq!(app.dual_view, None);
+    }
+
+    #[test]
+    fn test_stop_all_activity_disarms_schedulers() {
+        let now = Instant::now();
+        let mut app = AppState::default();
+        app.mark_connected();
+        app.start_repeat(RepeatSend::indefinite(
+            vec![0x55],
+            Duration::from_millis(100),
+            now,
+        ));
+        app.repeat_prompt = Some(RepeatPrompt::default());
+        assert!(app.has_scheduled_activity());
+
+        assert!(app.stop_all_activity());
+        assert!(!app.has_scheduled_activity());
+        assert!(!app.stop_all_activity());
+
+        // Reconnecting does not bring the repeat back
+        app.mark_disconnected(None);
+        app.mark_connected();
+        assert_eq!(app.due_repeat_payload(now + Duration::from_secs(10)), None);
+    }
+
+    #[test]
+    fn test_quit_confirmation_when_active() {
+        let mut app = AppState::default();
+        assert!(app.settings.confirm_quit_when_active);
+
+        // Idle: quit right away
+        assert!(!app.quit_needs_confirmation());
+        assert!(app.request_quit());
+        assert!(!app.quit_prompt);
+
+        // Connected: the first quit asks, the second one exits
+        app.mark_connected();
+        assert!(app.quit_needs_confirmation());
+        assert!(!app.request_quit());
+        assert!(app.quit_prompt);
+        assert!(app.request_quit());
+
+        // Recording while disconnected also asks
+        app.quit_prompt = false;
+        app.mark_disconnected(None);
+        assert!(!app.quit_needs_confirmation());
+        app.set_checkpoint();
+        assert!(app.is_recording());
+        assert!(app.quit_needs_confirmation());
+
+        // Setting off: never asks
+        app.settings.confirm_quit_when_active = false;
+        assert!(!app.quit_needs_confirmation());
+        assert!(app.request_quit());
+        assert!(!app.quit_prompt);
+    }
+
+    #[test]
+    fn test_disconnect_stops_bounded_repeat() {
+        let now = Instant::now();
+        let mut app = AppState::default();
+        app.mark_connected();
+        app.start_repeat(RepeatSend::bounded(
+            vec![1],
+            5,
+            Duration::from_millis(10),
+            now,
+        ));
+        app.mark_disconnected(None);
+        assert!(!app.has_scheduled_activity());
+
+        app.start_repeat(RepeatSend::bounded(
+            vec![1],
+            5,
+            Duration::from_millis(10),
+            now,
+        ));
+        app.mark_connection_lost("gone");
+        assert!(!app.has_scheduled_activity());
+    }
+
+    struct UpperRenderer;
+
+    impl DisplayRenderer for UpperRenderer {
+        fn render(&self, entry: &LogEntry) -> Vec<ratatui::text::Line<'static>> {
+            vec![String::from_utf8_lossy(&entry.data).to_uppercase().into()]
+        }
+    }
+
+    #[test]
+    fn test_display_mode_cycle_includes_custom_renderers() {
+        let mut app = AppState::default();
+        app.toggle_display_mode();
+        app.toggle_display_mode();
+        // Without custom renderers only the built-in modes alternate
+        assert_eq!(
+            (app.display_mode, app.custom_view),
+            (DisplayMode::Hex, None)
+        );
+
+        app.register_renderer("upper", UpperRenderer);
+        app.register_renderer("upper2", UpperRenderer);
+        app.toggle_display_mode();
+        assert_eq!(
+            (app.display_mode, app.custom_view),
+            (DisplayMode::Text, None)
+        );
+        app.toggle_display_mode();
+        assert_eq!(app.active_custom_renderer().unwrap().name, "upper");
+        assert_eq!(app.primary_view().custom_renderer, Some(0));
+        app.toggle_display_mode();
+        assert_eq!(app.active_custom_renderer().unwrap().name, "upper2");
+        app.toggle_display_mode();
+        assert_eq!(
+            (app.display_mode, app.custom_view),
+            (DisplayMode::Hex, None)
+        );
+        assert!(app.active_custom_renderer().is_none());
+
+        let entry = LogEntry::new(crate::log::LogDirection::Rx, b"ok".to_vec());
+        let lines = app.custom_renderers[0].renderer.render(&entry);
+        assert_eq!(lines[0].spans[0].content, "OK");
+    }
+
+    #[test]
+    fn test_rx_line_endings_track_received_data() {
+        let mut app = AppState::default();
+        app.append_rx(b"OK\r".to_vec());
+        app.append_rx(b"\nREADY\r\n".to_vec());
+        assert_eq!(app.rx_line_endings.dominant(), Some(AppendMode::CRLF));
+
+        app.clear_log();
+        assert_eq!(app.rx_line_endings.total(), 0);
+        app.append_rx(b"OK\n".to_vec());
+        assert_eq!(app.rx_line_endings.dominant(), Some(AppendMode::LF));
+    }
+
+    #[test]
+    fn test_selected_bytes_follow_focus_and_inspector() {
+        let mut app = AppState::default();
+        app.message_log.push_rx(vec![1, 2, 3, 4, 5, 6]);
+        app.focused_field = FocusedField::TxInput;
+        assert_eq!(app.selected_bytes(), None);
+
+        app.focused_field = FocusedField::LogArea;
+        assert_eq!(
+            app.selected_bytes().as_deref(),
+            Some(&[1, 2, 3, 4, 5, 6][..])
+        );
+
+        app.byte_inspector = Some(ByteInspector {
+            offset: 1,
+            ..Default::default()
+        });
+        assert_eq!(app.selected_byte_count(), Some(2));
+    }
+
+    #[test]
+    fn test_selected_bytes_come_from_drag_selection() {
+        let mut app = AppState::default();
+        app.message_log.push_rx(vec![1, 2]);
+        app.message_log
+            .push_event(LogEventKind::Marker, "mark".to_string());
+        app.message_log.push_tx(vec![3]);
+        app.message_log.push_rx(vec![4, 5, 6]);
+        app.focused_field = FocusedField::LogArea;
+
+        // The selection wins over the focused entry; events add nothing
+        app.begin_log_selection(0);
+        app.extend_log_selection(2);
+        assert_eq!(app.selected_bytes().as_deref(), Some(&[1, 2, 3][..]));
+        assert_eq!(app.selected_byte_count(), Some(3));
+
+        // Only the marker selected
+        app.clear_log_selection();
+        app.begin_log_selection(1);
+        assert_eq!(app.selected_bytes(), None);
+        assert_eq!(app.selected_byte_count(), None);
+
+        app.clear_log_selection();
+        assert_eq!(app.selected_byte_count(), Some(3));
+    }
+
+    #[test]
+    fn test_rx_timing_recorded_only_when_enabled() {
+        let mut app = AppState::default();
+        app.append_rx(b"A".to_vec());
+        assert!(app.rx_timing.is_empty());
+
+        app.settings.rx_chunk_timing = true;
+        app.append_rx(b"BC".to_vec());
+        app.append_rx(b"D".to_vec());
+        let timing = app.focused_rx_timing(4);
+        assert_eq!(timing.len(), 1);
+        assert_eq!((timing[0].seq, timing[0].len), (3, 1));
+        assert!(timing[0].delta.is_some());
+
+        app.clear_log();
+        assert!(app.rx_timing.is_empty());
+    }
+
+    #[test]
+    fn test_received_rx_timed_at_read() {
+        let mut app = AppState::default();
+        app.settings.rx_chunk_timing = true;
+        app.mark_connected();
+        let read_at = Instant::now();
+        app.receive_rx(b"A".to_vec(), read_at);
+        app.receive_rx(b"B".to_vec(), read_at + Duration::from_millis(50));
+        let timing = app.focused_rx_timing(1);
+        assert_eq!(timing[0].delta, Some(Duration::from_millis(50)));
+    }
+
+    #[test]
+    fn test_step_baud_for_reconnect_marks_log() {
+        let mut app = AppState::default();
+        app.config.port = "/dev/ttyUSB0".into();
+        app.mark_connected();
+        let old = app.config.baud_rate;
+
+        let (from, to) = app.step_baud_for_reconnect(true).unwrap();
+        assert_eq!(from, old);
+        assert_ne!(to, old);
+        assert_eq!(app.config.baud_rate, to);
+
+        let event = app.message_log.entries.back().unwrap();
+        assert_eq!(event.event_kind(), Some(LogEventKind::Reconnect));
+        let text = String::from_utf8_lossy(&event.data);
+        assert!(text.contains(&format!("{from} → {to}")), "{text}");
+
+        // Stepping back returns to the original rate
+        assert_eq!(app.step_baud_for_reconnect(false), Some((to, from)));
+    }
+
+    #[test]
+    fn test_step_baud_updates_staged_config() {
+        let mut app = AppState::default();
+        app.set_config_staging(true);
+        let (_, to) = app.step_baud_for_reconnect(false).unwrap();
+        assert_eq!(app.active_config().baud_rate, to);
+        assert!(!app.has_staged_changes());
+    }
+
+    #[test]
+    fn test_diagnostics_report() {
+        let mut app = AppState {
+            ports: vec!["/dev/ttyUSB0".into(), "/dev/ttyUSB1".into()],
+            ..Default::default()
+        };
+        app.config.port = "/dev/ttyUSB0".into();
+        app.config.baud_rate = 115200;
+        app.settings.control_pictures = true;
+        app.message_log.push_rx(b"secret-token".to_vec());
+        app.message_log.push_tx(b"password=hunter2".to_vec());
+        app.tx_input = "unsent draft".into();
+        app.settings.tx_prefix = b"AT+PIN=".to_vec();
+        app.settings.tx_suffix = b"4321".to_vec();
+
+        let report = app.diagnostics_report();
+        for expected in [
+            &format!("version: {}", env!("CARGO_PKG_VERSION")),
+            &format!("os: {}", std::env::consts::OS),
+            "connection: Disconnected",
+            "config: /dev/ttyUSB0 @ 115200 bps, 8-N-1",
+            "ports (2): /dev/ttyUSB0, /dev/ttyUSB1",
+            "log: 2 entries, RX 1 (12 bytes), TX 1 (16 bytes)",
+            "  control_pictures = true",
+            "  lsb_first = false",
+            "  tx_prefix = 7 bytes",
+            "  tx_suffix = 4 bytes",
+        ] {
+            assert!(
+                report.contains(expected),
+                "missing {expected:?} in\n{report}"
+            );
+        }
+        // No captured data, TX input or prefix/suffix bytes
+        for leaked in [
+            "secret-token",
+            "hunter2",
+            "unsent draft",
+            "[65,84",
+            "[52,51",
+        ] {
+            assert!(!report.contains(leaked), "{leaked:?} leaked into\n{report}");
+        }
+    }
 }
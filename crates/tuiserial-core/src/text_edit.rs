@@ -0,0 +1,464 @@
+//! Char-boundary-safe text editing helpers
+//!
+//! Cursors for text inputs are tracked as **char** indices. `String`'s
+//! `insert`/`remove` take **byte** indices, so every edit goes through these
+//! helpers to convert between the two and never split a multi-byte character.
+//! [`TextInput`] bundles a string with its cursor for the TX input, the
+//! session rename overlay and other single-line inputs.
+
+use std::fmt;
+
+use crate::display_width;
+
+/// Convert a char index into a byte index, clamped to the end of the string
+pub fn char_to_byte_index(s: &str, char_idx: usize) -> usize {
+    s.char_indices()
+        .nth(char_idx)
+        .map(|(i, _)| i)
+        .unwrap_or(s.len())
+}
+
+/// Number of chars in `s` (the maximum valid cursor position)
+pub fn char_len(s: &str) -> usize {
+    s.chars().count()
+}
+
+/// Insert `c` at the cursor and advance the cursor past it
+pub fn insert_char(s: &mut String, cursor: &mut usize, c: char) {
+    *cursor = (*cursor).min(char_len(s));
+    s.insert(char_to_byte_index(s, *cursor), c);
+    *cursor += 1;
+}
+
+/// Insert `text` at the cursor and advance the cursor past it
+pub fn insert_str(s: &mut String, cursor: &mut usize, text: &str) {
+    *cursor = (*cursor).min(char_len(s));
+    s.insert_str(char_to_byte_index(s, *cursor), text);
+    *cursor += char_len(text);
+}
+
+/// Delete the char before the cursor (Backspace). Returns `false` at the start.
+pub fn delete_before(s: &mut String, cursor: &mut usize) -> bool {
+    *cursor = (*cursor).min(char_len(s));
+    if *cursor == 0 {
+        return false;
+    }
+    *cursor -= 1;
+    s.remove(char_to_byte_index(s, *cursor));
+    true
+}
+
+/// Delete the char under the cursor (Delete). Returns `false` at the end.
+pub fn delete_at(s: &mut String, cursor: usize) -> bool {
+    if cursor >= char_len(s) {
+        return false;
+    }
+    s.remove(char_to_byte_index(s, cursor));
+    true
+}
+
+/// Char index whose left edge is closest to terminal column `col`, taking
+/// double-width characters into account (for mouse positioning)
+pub fn char_index_at_column(s: &str, col: usize) -> usize {
+    let mut width = 0;
+    for (i, c) in s.chars().enumerate() {
+        let w = display_width(c.encode_utf8(&mut [0; 4]));
+        if col < width + w.div_ceil(2) {
+            return i;
+        }
+        width += w;
+    }
+    char_len(s)
+}
+
+/// A single-line text input: the text and a char-index cursor that every
+/// edit keeps within `0..=char_len`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TextInput {
+    text: String,
+    cursor: usize,
+}
+
+impl TextInput {
+    /// Empty input
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Input holding `text` with the cursor at the end
+    pub fn from_text(text: impl Into<String>) -> Self {
+        let text = text.into();
+        let cursor = char_len(&text);
+        Self { text, cursor }
+    }
+
+    /// Same input with the cursor moved to `cursor` (clamped)
+    pub fn with_cursor(mut self, cursor: usize) -> Self {
+        self.set_cursor(cursor);
+        self
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    /// Cursor position as a char index
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// Number of chars in the input
+    pub fn char_len(&self) -> usize {
+        char_len(&self.text)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    /// Move the cursor to char index `cursor`, clamped to the end
+    pub fn set_cursor(&mut self, cursor: usize) {
+        self.cursor = cursor.min(self.char_len());
+    }
+
+    /// Replace the text, moving the cursor to the end
+    pub fn set_text(&mut self, text: impl Into<String>) {
+        *self = Self::from_text(text);
+    }
+
+    pub fn clear(&mut self) {
+        self.text.clear();
+        self.cursor = 0;
+    }
+
+    /// Take the text out, leaving the input empty
+    pub fn take(&mut self) -> String {
+        self.cursor = 0;
+        std::mem::take(&mut self.text)
+    }
+
+    // Editing
+
+    /// Insert `c` at the cursor and move past it
+    pub fn insert_char(&mut self, c: char) {
+        insert_char(&mut self.text, &mut self.cursor, c);
+    }
+
+    /// Insert `text` at the cursor and move past it
+    pub fn insert_str(&mut self, text: &str) {
+        insert_str(&mut self.text, &mut self.cursor, text);
+    }
+
+    /// Delete the char before the cursor (Backspace). Returns `false` at
+    /// the start.
+    pub fn delete_before(&mut self) -> bool {
+        delete_before(&mut self.text, &mut self.cursor)
+    }
+
+    /// Delete the char under the cursor (Delete). Returns `false` at the end.
+    pub fn delete_after(&mut self) -> bool {
+        delete_at(&mut self.text, self.cursor)
+    }
+
+    // Cursor movement
+
+    pub fn move_left(&mut self) {
+        self.cursor = self.cursor.saturating_sub(1);
+    }
+
+    pub fn move_right(&mut self) {
+        self.set_cursor(self.cursor + 1);
+    }
+
+    pub fn move_home(&mut self) {
+        self.cursor = 0;
+    }
+
+    pub fn move_end(&mut self) {
+        self.cursor = self.char_len();
+    }
+
+    /// Put the cursor at terminal column `col`, e.g. for a mouse click
+    pub fn move_to_column(&mut self, col: usize) {
+        self.cursor = char_index_at_column(&self.text, col);
+    }
+
+    // Rendering
+
+    /// Text left of the cursor
+    pub fn before_cursor(&self) -> &str {
+        &self.text[..char_to_byte_index(&self.text, self.cursor)]
+    }
+
+    /// Terminal column of the cursor
+    pub fn cursor_column(&self) -> usize {
+        display_width(self.before_cursor())
+    }
+
+    /// Text with `marker` drawn at the cursor (for block-cursor overlays)
+    pub fn with_cursor_marker(&self, marker: char) -> String {
+        let mut text = self.text.clone();
+        let mut cursor = self.cursor;
+        insert_char(&mut text, &mut cursor, marker);
+        text
+    }
+
+    /// The part of the text that fits in `width` columns, scrolled so the
+    /// cursor stays visible, and the cursor's column within it
+    pub fn visible_window(&self, width: usize) -> (String, usize) {
+        let cursor_x = self.cursor_column();
+        let text_width = display_width(&self.text);
+        if text_width <= width {
+            return (self.text.clone(), cursor_x);
+        }
+
+        // Keep the cursor in the last column once it passes the right edge
+        let scroll = if cursor_x < width {
+            0
+        } else {
+            cursor_x + 1 - width
+        };
+
+        // Skip whole chars up to the scroll column (a wide char straddling
+        // it is skipped too), then take what fits
+        let mut skipped = 0;
+        let mut visible = String::new();
+        let mut used = 0;
+        for c in self.text.chars() {
+            let w = display_width(c.encode_utf8(&mut [0; 4]));
+            if skipped < scroll {
+                skipped += w;
+                continue;
+            }
+            if used + w > width {
+                break;
+            }
+            visible.push(c);
+            used += w;
+        }
+        (visible, cursor_x.saturating_sub(skipped))
+    }
+}
+
+impl fmt::Display for TextInput {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.text)
+    }
+}
+
+impl From<&str> for TextInput {
+    fn from(text: &str) -> Self {
+        Self::from_text(text)
+    }
+}
+
+impl From<String> for TextInput {
+    fn from(text: String) -> Self {
+        Self::from_text(text)
+    }
+}
+
+impl PartialEq<str> for TextInput {
+    fn eq(&self, other: &str) -> bool {
+        self.text == other
+    }
+}
+
+impl PartialEq<&str> for TextInput {
+    fn eq(&self, other: &&str) -> bool {
+        self.text == *other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_char_to_byte_index() {
+        let s = "a你b";
+        assert_eq!(char_to_byte_index(s, 0), 0);
+        assert_eq!(char_to_byte_index(s, 1), 1);
+        assert_eq!(char_to_byte_index(s, 2), 4);
+        assert_eq!(char_to_byte_index(s, 3), 5);
+        assert_eq!(char_to_byte_index(s, 99), 5);
+    }
+
+    #[test]
+    fn test_insert_around_multibyte() {
+        let mut s = String::new();
+        let mut cursor = 0;
+        insert_char(&mut s, &mut cursor, '你');
+        insert_char(&mut s, &mut cursor, '好');
+        assert_eq!((s.as_str(), cursor), ("你好", 2));
+
+        // Insert between the two CJK chars
+        cursor = 1;
+        insert_char(&mut s, &mut cursor, 'x');
+        assert_eq!((s.as_str(), cursor), ("你x好", 2));
+
+        insert_str(&mut s, &mut cursor, "é🙂");
+        assert_eq!((s.as_str(), cursor), ("你xé🙂好", 4));
+    }
+
+    #[test]
+    fn test_delete_around_multibyte() {
+        let mut s = "你x好🙂".to_string();
+        let mut cursor = 4;
+        assert!(delete_before(&mut s, &mut cursor));
+        assert_eq!((s.as_str(), cursor), ("你x好", 3));
+
+        cursor = 1;
+        assert!(delete_at(&mut s, cursor));
+        assert_eq!(s, "你好");
+        assert!(delete_before(&mut s, &mut cursor));
+        assert_eq!((s.as_str(), cursor), ("好", 0));
+
+        assert!(!delete_before(&mut s, &mut cursor));
+        assert!(delete_at(&mut s, 0));
+        assert!(!delete_at(&mut s, 0));
+        assert!(s.is_empty());
+    }
+
+    #[test]
+    fn test_stale_cursor_is_clamped() {
+        let mut s = "日本".to_string();
+        let mut cursor = 10;
+        insert_char(&mut s, &mut cursor, '語');
+        assert_eq!((s.as_str(), cursor), ("日本語", 3));
+
+        cursor = 10;
+        assert!(delete_before(&mut s, &mut cursor));
+        assert_eq!((s.as_str(), cursor), ("日本", 2));
+    }
+
+    #[test]
+    fn test_char_index_at_column() {
+        // "a你b": a=col 0, 你=cols 1-2, b=col 3
+        let s = "a你b";
+        assert_eq!(char_index_at_column(s, 0), 0);
+        assert_eq!(char_index_at_column(s, 1), 1);
+        assert_eq!(char_index_at_column(s, 2), 2);
+        assert_eq!(char_index_at_column(s, 3), 2);
+        assert_eq!(char_index_at_column(s, 4), 3);
+        assert_eq!(char_index_at_column(s, 50), 3);
+    }
+
+    #[test]
+    fn test_text_input_editing() {
+        let mut input = TextInput::new();
+        for c in "a你b".chars() {
+            input.insert_char(c);
+        }
+        assert_eq!((input.as_str(), input.cursor()), ("a你b", 3));
+
+        input.move_left();
+        input.move_left();
+        input.insert_str("好🙂");
+        assert_eq!((input.as_str(), input.cursor()), ("a好🙂你b", 3));
+
+        assert!(input.delete_before());
+        assert_eq!((input.as_str(), input.cursor()), ("a好你b", 2));
+        assert!(input.delete_after());
+        assert_eq!((input.as_str(), input.cursor()), ("a好b", 2));
+
+        input.move_home();
+        assert!(!input.delete_before());
+        assert!(input.delete_after());
+        assert_eq!((input.as_str(), input.cursor()), ("好b", 0));
+
+        input.move_end();
+        assert!(!input.delete_after());
+        assert!(input.delete_before());
+        assert_eq!((input.as_str(), input.cursor()), ("好", 1));
+
+        assert_eq!(input.take(), "好");
+        assert!(input.is_empty());
+        assert_eq!(input.cursor(), 0);
+    }
+
+    #[test]
+    fn test_text_input_cursor_is_clamped() {
+        let mut input = TextInput::from_text("日本");
+        assert_eq!(input.cursor(), 2);
+        input.move_right();
+        assert_eq!(input.cursor(), 2);
+
+        input.set_cursor(10);
+        assert_eq!(input.cursor(), 2);
+        input.move_home();
+        input.move_left();
+        assert_eq!(input.cursor(), 0);
+
+        assert_eq!(TextInput::from("語").with_cursor(7).cursor(), 1);
+
+        input.set_text("x");
+        assert_eq!((input.as_str(), input.cursor()), ("x", 1));
+        input.clear();
+        assert_eq!(input, TextInput::new());
+        assert_eq!(input, "");
+    }
+
+    #[test]
+    fn test_text_input_columns() {
+        // "a你b": a=col 0, 你=cols 1-2, b=col 3
+        let mut input = TextInput::from_text("a你b");
+        assert_eq!(input.cursor_column(), 4);
+        input.set_cursor(2);
+        assert_eq!(input.before_cursor(), "a你");
+        assert_eq!(input.cursor_column(), 3);
+
+        input.move_to_column(1);
+        assert_eq!(input.cursor(), 1);
+        input.move_to_column(50);
+        assert_eq!(input.cursor(), 3);
+    }
+
+    #[test]
+    fn test_text_input_cursor_marker() {
+        let input = TextInput::from_text("串口1");
+        assert_eq!(
+            input.clone().with_cursor(0).with_cursor_marker('█'),
+            "█串口1"
+        );
+        assert_eq!(
+            input.clone().with_cursor(1).with_cursor_marker('█'),
+            "串█口1"
+        );
+        assert_eq!(
+            input.clone().with_cursor(2).with_cursor_marker('█'),
+            "串口█1"
+        );
+        assert_eq!(input.with_cursor_marker('█'), "串口1█");
+        // Out-of-range cursor clamps to the end instead of panicking
+        assert_eq!(
+            TextInput::from("串口")
+                .with_cursor(9)
+                .with_cursor_marker('█'),
+            "串口█"
+        );
+    }
+
+    #[test]
+    fn test_text_input_visible_window() {
+        // Fits: shown whole
+        let input = TextInput::from_text("AT");
+        assert_eq!(input.visible_window(10), ("AT".to_string(), 2));
+
+        // Cursor near the start: no scrolling
+        let input = TextInput::from_text("0123456789").with_cursor(2);
+        assert_eq!(input.visible_window(4), ("0123".to_string(), 2));
+
+        // Cursor at the end: scrolled so it sits in the last column
+        let input = TextInput::from_text("0123456789");
+        assert_eq!(input.visible_window(4), ("789".to_string(), 3));
+
+        // Wide chars are never cut in half, and the cursor column counts
+        // from the first shown char
+        let input = TextInput::from_text("你好世界");
+        assert_eq!(input.visible_window(5), ("世界".to_string(), 4));
+
+        let input = TextInput::from_text("a你好世界").with_cursor(4);
+        assert_eq!(input.visible_window(4), ("世界".to_string(), 2));
+    }
+}
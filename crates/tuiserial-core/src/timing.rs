@@ -0,0 +1,186 @@
+//! High-resolution RX chunk timing
+//!
+//! Log timestamps have millisecond resolution and carry wall-clock jitter.
+//! When `rx_chunk_timing` is on, each RX read is also stamped with an
+//! `Instant` and its position in the byte stream, so the timing overlay can
+//! show microsecond gaps between reads. The history is capped since a busy
+//! port produces thousands of reads per second.
+
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+/// Most recent RX reads whose timing is kept
+pub const RX_TIMING_CAPACITY: usize = 4096;
+
+/// Arrival of one RX read
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RxChunkTime {
+    /// Sequence number of the log entry holding the read's bytes
+    pub seq: u64,
+    /// Offset of the read's first byte in the RX stream since timing began
+    pub offset: u64,
+    pub len: usize,
+    pub at: Instant,
+}
+
+/// Gap between one RX read and the one before it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChunkDelta {
+    pub seq: u64,
+    pub offset: u64,
+    pub len: usize,
+    /// Time since the previous read; `None` for the first one recorded
+    pub delta: Option<Duration>,
+}
+
+impl ChunkDelta {
+    /// Gap spread over the bytes of this read, i.e. the average time per
+    /// byte if the line was busy the whole gap
+    pub fn per_byte(&self) -> Option<Duration> {
+        let len = u32::try_from(self.len).ok().filter(|&n| n > 0)?;
+        self.delta.map(|d| d / len)
+    }
+}
+
+/// Capped history of RX read arrivals
+#[derive(Debug, Clone, Default)]
+pub struct RxTiming {
+    chunks: VecDeque<RxChunkTime>,
+    /// RX bytes recorded so far, the offset of the next read
+    next_offset: u64,
+}
+
+impl RxTiming {
+    /// Record a read of `len` bytes stored in log entry `seq`, arriving at
+    /// `at`. The oldest record is dropped beyond [`RX_TIMING_CAPACITY`].
+    pub fn record(&mut self, seq: u64, len: usize, at: Instant) {
+        if self.chunks.len() == RX_TIMING_CAPACITY {
+            self.chunks.pop_front();
+        }
+        self.chunks.push_back(RxChunkTime {
+            seq,
+            offset: self.next_offset,
+            len,
+            at,
+        });
+        self.next_offset += len as u64;
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    pub fn len(&self) -> usize {
+        self.chunks.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.chunks.is_empty()
+    }
+
+    /// Deltas for up to `count` reads starting at log entry `seq`; empty if
+    /// that entry's timing was not recorded
+    pub fn deltas_from(&self, seq: u64, count: usize) -> Vec<ChunkDelta> {
+        let Some(start) = self.chunks.iter().position(|c| c.seq == seq) else {
+            return Vec::new();
+        };
+        let end = (start + count).min(self.chunks.len());
+        let window: Vec<RxChunkTime> = self
+            .chunks
+            .range(start.saturating_sub(1)..end)
+            .copied()
+            .collect();
+        let mut deltas = chunk_deltas(&window);
+        if start > 0 {
+            // The first record only provided the base for the focused read
+            deltas.remove(0);
+        }
+        deltas
+    }
+}
+
+/// Time between each read and the one before it
+pub fn chunk_deltas(chunks: &[RxChunkTime]) -> Vec<ChunkDelta> {
+    let mut previous: Option<Instant> = None;
+    chunks
+        .iter()
+        .map(|c| {
+            let delta = previous.map(|p| c.at.saturating_duration_since(p));
+            previous = Some(c.at);
+            ChunkDelta {
+                seq: c.seq,
+                offset: c.offset,
+                len: c.len,
+                delta,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn us(n: u64) -> Duration {
+        Duration::from_micros(n)
+    }
+
+    fn recorded(gaps_us: &[u64]) -> (RxTiming, Instant) {
+        let start = Instant::now();
+        let mut timing = RxTiming::default();
+        let mut at = start;
+        for (i, &gap) in gaps_us.iter().enumerate() {
+            at += us(gap);
+            timing.record(i as u64 + 1, 4, at);
+        }
+        (timing, start)
+    }
+
+    #[test]
+    fn test_chunk_deltas() {
+        let (timing, _) = recorded(&[0, 250, 1000]);
+        let deltas = timing.deltas_from(1, 10);
+        assert_eq!(deltas.len(), 3);
+        assert_eq!(deltas[0].delta, None);
+        assert_eq!(deltas[1].delta, Some(us(250)));
+        assert_eq!(deltas[2].delta, Some(us(1000)));
+        assert_eq!(deltas[2].per_byte(), Some(us(250)));
+        assert_eq!(
+            deltas.iter().map(|d| d.offset).collect::<Vec<_>>(),
+            vec![0, 4, 8]
+        );
+    }
+
+    #[test]
+    fn test_deltas_from_middle_keep_previous_gap() {
+        let (timing, _) = recorded(&[0, 100, 300, 700]);
+        let deltas = timing.deltas_from(3, 2);
+        assert_eq!(deltas.len(), 2);
+        assert_eq!((deltas[0].seq, deltas[0].delta), (3, Some(us(300))));
+        assert_eq!((deltas[1].seq, deltas[1].delta), (4, Some(us(700))));
+        assert!(timing.deltas_from(99, 2).is_empty());
+    }
+
+    #[test]
+    fn test_per_byte_empty_read() {
+        let delta = ChunkDelta {
+            seq: 1,
+            offset: 0,
+            len: 0,
+            delta: Some(us(10)),
+        };
+        assert_eq!(delta.per_byte(), None);
+    }
+
+    #[test]
+    fn test_capacity() {
+        let mut timing = RxTiming::default();
+        let at = Instant::now();
+        for seq in 0..RX_TIMING_CAPACITY as u64 + 10 {
+            timing.record(seq, 1, at);
+        }
+        assert_eq!(timing.len(), RX_TIMING_CAPACITY);
+        assert!(timing.deltas_from(0, 1).is_empty());
+        assert_eq!(timing.deltas_from(10, 1)[0].offset, 10);
+    }
+}
@@ -4,27 +4,124 @@
 //! the application, including display modes, transmission modes, parity settings, etc.
 
 use std::borrow::Cow;
+use std::time::Duration;
 
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
 use rust_i18n::t;
 use serde::{Deserialize, Serialize};
 
 /// Display mode for serial data
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum DisplayMode {
     Hex,
     Text,
 }
 
-/// Transmission mode for sending data
+impl DisplayMode {
+    /// The other mode
+    pub fn toggled(self) -> Self {
+        match self {
+            DisplayMode::Hex => DisplayMode::Text,
+            DisplayMode::Text => DisplayMode::Hex,
+        }
+    }
+}
+
+/// Where a payload awaiting send confirmation came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SendOrigin {
+    /// The TX input box; cleared once the payload is sent
+    Input,
+    /// The clipboard (Ctrl+Shift+V)
+    Clipboard,
+    /// The repeat prompt; starts a repeat of `count` sends (`None` until
+    /// stopped) every `interval` once confirmed
+    Repeat {
+        count: Option<u32>,
+        interval: Duration,
+    },
+}
+
+/// An encoded payload held back until the user confirms sending it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PendingSend {
+    pub data: Vec<u8>,
+    pub origin: SendOrigin,
+    /// Mode `data` was encoded in, recorded on its log entry
+    pub mode: TxMode,
+}
+
+/// Rendering settings of one log view. The dual view shows the same log
+/// twice, each with its own settings.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LogView {
+    pub display_mode: DisplayMode,
+    /// Index of the custom renderer used instead of `display_mode`
+    pub custom_renderer: Option<usize>,
+    /// Render TX entries in the mode they were entered
+    pub native_tx_display: bool,
+}
+
+/// Log entries selected by dragging with the mouse, as the `seq` of the
+/// entries at each end so the selection stays on the same entries when
+/// older ones are evicted or the view filter changes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LogSelection {
+    /// `seq` of the entry where the drag started
+    pub anchor: u64,
+    /// `seq` of the entry under the mouse now
+    pub head: u64,
+    /// `auto_scroll` when the selection started, restored when it is cleared
+    pub resume_follow: bool,
+}
+
+impl LogSelection {
+    /// Selected `seq` values, first to last
+    pub fn range(&self) -> std::ops::RangeInclusive<u64> {
+        self.anchor.min(self.head)..=self.anchor.max(self.head)
+    }
+
+    /// Whether the entry with `seq` is selected
+    pub fn contains(&self, seq: u64) -> bool {
+        self.range().contains(&seq)
+    }
+}
+
+/// Byte pad overlay: a 00–FF grid where clicking a cell sends that byte
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct BytePad {
+    /// Byte sent by the most recent click, highlighted in the grid
+    pub last_sent: Option<u8>,
+}
+
+/// Serial connection lifecycle
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum ConnectionState {
+    #[default]
+    Disconnected,
+    /// Port is being opened
+    Connecting,
+    Connected,
+    /// Connecting failed or the connection was lost; holds the reason
+    Error(String),
+}
+
+impl ConnectionState {
+    /// Whether the port is open
+    pub fn is_connected(&self) -> bool {
+        matches!(self, ConnectionState::Connected)
+    }
+}
+
+/// Transmission mode for sending data
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum TxMode {
     Hex,
     Ascii,
 }
 
 /// Data append options for transmission
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum AppendMode {
     None, // 无追加
     LF,   // \n (0x0A)
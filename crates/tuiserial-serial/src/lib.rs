@@ -9,8 +9,16 @@ use std::time::Duration;
 use thiserror::Error;
 use tuiserial_core::{FlowControl, Parity, SerialConfig, SerialErrorKind};
 
+mod slip;
+mod token;
+
 // Re-exports
 pub use serialport;
+pub use slip::{
+    SLIP_END, SLIP_ESC, SLIP_ESC_END, SLIP_ESC_ESC, SLIP_MAX_FRAME, SlipDecoder, slip_decode,
+    slip_encode,
+};
+pub use token::{Endian, IntType, TokenError, expand_tx_tokens};
 pub use tokio;
 
 #[derive(Error, Debug)]
@@ -29,6 +37,9 @@ pub enum SerialError {
 
     #[error("Port is not connected")]
     NotConnected,
+
+    #[error("Invalid TX token: {0}")]
+    Token(#[from] TokenError),
 }
 
 impl From<SerialError> for SerialErrorKind {
@@ -39,6 +50,7 @@ impl From<SerialError> for SerialErrorKind {
             SerialError::InvalidHexLength => SerialErrorKind::InvalidHexLength,
             SerialError::ParseHex(e) => SerialErrorKind::ParseHex(e.to_string()),
             SerialError::NotConnected => SerialErrorKind::NotConnected,
+            SerialError::Token(e) => SerialErrorKind::InvalidToken(e.to_string()),
         }
     }
 }
@@ -51,6 +63,31 @@ pub fn list_ports() -> Vec<String> {
     }
 }
 
+/// A detected serial port and the serial number of its USB adapter
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PortInfo {
+    pub name: String,
+    /// `None` for non-USB ports and adapters that report none
+    pub usb_serial: Option<String>,
+}
+
+/// List all available serial ports with their USB serial numbers
+pub fn list_port_info() -> Vec<PortInfo> {
+    match serialport::available_ports() {
+        Ok(ports) => ports
+            .into_iter()
+            .map(|p| PortInfo {
+                usb_serial: match p.port_type {
+                    serialport::SerialPortType::UsbPort(usb) => usb.serial_number,
+                    _ => None,
+                },
+                name: p.port_name,
+            })
+            .collect(),
+        Err(_) => Vec::new(),
+    }
+}
+
 /// Open a serial port with the given configuration
 pub fn open_port(config: &SerialConfig) -> Result<Box<dyn SerialPort>, SerialError> {
     serialport::new(&config.port, config.baud_rate)
@@ -83,7 +120,15 @@ pub fn open_port(config: &SerialConfig) -> Result<Box<dyn SerialPort>, SerialErr
 
 /// Read data from the serial port
 pub fn read_data(port: &mut dyn SerialPort) -> Result<Vec<u8>, SerialError> {
-    let mut buf = vec![0u8; 256];
+    read_data_with_capacity(port, tuiserial_core::DEFAULT_RX_READ_BUFFER)
+}
+
+/// Read up to `capacity` bytes from the serial port in a single call
+pub fn read_data_with_capacity(
+    port: &mut dyn SerialPort,
+    capacity: usize,
+) -> Result<Vec<u8>, SerialError> {
+    let mut buf = vec![0u8; capacity.max(1)];
     match port.read(buf.as_mut_slice()) {
         Ok(n) if n > 0 => {
             buf.truncate(n);
@@ -163,6 +208,53 @@ pub fn bytes_to_string(bytes: &[u8]) -> String {
         .collect()
 }
 
+/// Convert bytes to string, showing control bytes as Unicode control
+/// pictures (`␍`, `␊`, `␀`, ...) instead of `\xNN`. Bytes above 0x7F
+/// have no picture and are still escaped.
+///
+/// # Example
+/// ```
+/// use tuiserial_serial::bytes_to_control_pictures;
+/// let s = bytes_to_control_pictures(b"OK\r\n");
+/// assert_eq!(s, "OK␍␊");
+/// ```
+pub fn bytes_to_control_pictures(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .map(|&b| match b {
+            0x00..=0x1F => char::from_u32(0x2400 + u32::from(b))
+                .unwrap_or(char::REPLACEMENT_CHARACTER)
+                .to_string(),
+            0x7F => "␡".to_string(),
+            32..127 => (b as char).to_string(),
+            _ => format!("\\x{:02X}", b),
+        })
+        .collect()
+}
+
+/// Reverse the bit order within a byte (MSB-first ↔ LSB-first)
+///
+/// # Example
+/// ```
+/// use tuiserial_serial::reverse_bits;
+/// assert_eq!(reverse_bits(0b0000_0001), 0b1000_0000);
+/// ```
+pub fn reverse_bits(byte: u8) -> u8 {
+    byte.reverse_bits()
+}
+
+/// Bytes as the log displays them: bit order reversed when `lsb_first`,
+/// then bit 7 cleared when `mask_high_bit`. The stored data is untouched.
+pub fn display_bytes(bytes: &[u8], lsb_first: bool, mask_high_bit: bool) -> Vec<u8> {
+    bytes
+        .iter()
+        .map(|&b| {
+            let b = if lsb_first { reverse_bits(b) } else { b };
+            if mask_high_bit { b & 0x7F } else { b }
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -199,4 +291,38 @@ mod tests {
         );
         assert_eq!(bytes_to_string(&[0x00, 0x1F, 0x7F]), "\\x00\\x1F\\x7F");
     }
+
+    #[test]
+    fn test_bytes_to_control_pictures() {
+        assert_eq!(bytes_to_control_pictures(b"AT\r\n"), "AT␍␊");
+        assert_eq!(
+            bytes_to_control_pictures(&[0x00, 0x09, 0x1B, 0x1F, 0x7F]),
+            "␀␉␛␟␡"
+        );
+        // Space stays a space, bytes above ASCII are still escaped
+        assert_eq!(bytes_to_control_pictures(b"a b"), "a b");
+        assert_eq!(bytes_to_control_pictures(&[0x80, 0xFF]), "\\x80\\xFF");
+    }
+
+    #[test]
+    fn test_reverse_bits() {
+        assert_eq!(reverse_bits(0x00), 0x00);
+        assert_eq!(reverse_bits(0xFF), 0xFF);
+        assert_eq!(reverse_bits(0x01), 0x80);
+        assert_eq!(reverse_bits(0x12), 0x48);
+        assert_eq!(reverse_bits(0b1100_1010), 0b0101_0011);
+        for b in 0..=255u8 {
+            assert_eq!(reverse_bits(reverse_bits(b)), b);
+        }
+    }
+
+    #[test]
+    fn test_display_bytes() {
+        let data = [0x82, 0xC1];
+        assert_eq!(display_bytes(&data, false, false), data);
+        assert_eq!(display_bytes(&data, true, false), [0x41, 0x83]);
+        assert_eq!(display_bytes(&data, false, true), [0x02, 0x41]);
+        // Reversed first, then masked
+        assert_eq!(display_bytes(&data, true, true), [0x41, 0x03]);
+    }
 }
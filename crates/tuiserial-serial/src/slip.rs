@@ -0,0 +1,202 @@
+//! SLIP framing (RFC 1055).
+//!
+//! Frames end with `END` (0xC0). An `END` or `ESC` (0xDB) inside a frame is
+//! sent as `ESC ESC_END` or `ESC ESC_ESC`. Encoded frames also start with
+//! `END` so line noise before them ends up in a discarded empty frame.
+
+/// Frame delimiter
+pub const SLIP_END: u8 = 0xC0;
+/// Escape introducer
+pub const SLIP_ESC: u8 = 0xDB;
+/// Escaped `END`
+pub const SLIP_ESC_END: u8 = 0xDC;
+/// Escaped `ESC`
+pub const SLIP_ESC_ESC: u8 = 0xDD;
+/// Longest frame [`SlipDecoder`] buffers; longer ones are dropped
+pub const SLIP_MAX_FRAME: usize = 64 * 1024;
+
+/// Encode `data` as one SLIP frame: `END`, the escaped data, `END`
+pub fn slip_encode(data: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(data.len() + 2);
+    out.push(SLIP_END);
+    for &b in data {
+        match b {
+            SLIP_END => out.extend_from_slice(&[SLIP_ESC, SLIP_ESC_END]),
+            SLIP_ESC => out.extend_from_slice(&[SLIP_ESC, SLIP_ESC_ESC]),
+            b => out.push(b),
+        }
+    }
+    out.push(SLIP_END);
+    out
+}
+
+/// Decode the complete frames in `data`. Empty frames are skipped and
+/// bytes after the last `END` are dropped; use [`SlipDecoder`] for data
+/// arriving in chunks.
+pub fn slip_decode(data: &[u8]) -> Vec<Vec<u8>> {
+    SlipDecoder::default().feed(data)
+}
+
+/// Incremental SLIP decoder; a frame split across reads is held until its
+/// `END` arrives. A frame growing past the maximum size is dropped along
+/// with the rest of it up to the next `END`, so a stream without `END`s
+/// cannot grow the buffer without bound.
+#[derive(Debug, Clone)]
+pub struct SlipDecoder {
+    frame: Vec<u8>,
+    escaped: bool,
+    max_frame: usize,
+    /// Skipping the rest of an oversized frame
+    discarding: bool,
+    /// Oversized frames dropped since the last `take_dropped`
+    dropped: usize,
+}
+
+impl Default for SlipDecoder {
+    fn default() -> Self {
+        Self::with_max_frame(SLIP_MAX_FRAME)
+    }
+}
+
+impl SlipDecoder {
+    /// Decoder dropping frames longer than `max_frame` bytes
+    pub fn with_max_frame(max_frame: usize) -> Self {
+        Self {
+            frame: Vec::new(),
+            escaped: false,
+            max_frame,
+            discarding: false,
+            dropped: 0,
+        }
+    }
+
+    /// Decode the next chunk, returning the frames it completes
+    pub fn feed(&mut self, data: &[u8]) -> Vec<Vec<u8>> {
+        let mut frames = Vec::new();
+        for &b in data {
+            if self.discarding {
+                self.discarding = b != SLIP_END;
+                continue;
+            }
+            if self.frame.len() >= self.max_frame && b != SLIP_END {
+                self.frame = Vec::new();
+                self.escaped = false;
+                self.discarding = true;
+                self.dropped += 1;
+                continue;
+            }
+            if std::mem::take(&mut self.escaped) {
+                // RFC 1055 leaves an invalid escape as the byte itself
+                self.frame.push(match b {
+                    SLIP_ESC_END => SLIP_END,
+                    SLIP_ESC_ESC => SLIP_ESC,
+                    b => b,
+                });
+                continue;
+            }
+            match b {
+                SLIP_END if !self.frame.is_empty() => frames.push(std::mem::take(&mut self.frame)),
+                SLIP_END => {}
+                SLIP_ESC => self.escaped = true,
+                b => self.frame.push(b),
+            }
+        }
+        frames
+    }
+
+    /// Bytes of the frame still waiting for its `END`
+    pub fn pending(&self) -> &[u8] {
+        &self.frame
+    }
+
+    /// Number of oversized frames dropped since the last call
+    pub fn take_dropped(&mut self) -> usize {
+        std::mem::take(&mut self.dropped)
+    }
+
+    /// Drop a partial frame, e.g. on reconnect or a framing change
+    pub fn reset(&mut self) {
+        *self = Self::with_max_frame(self.max_frame);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_escapes() {
+        assert_eq!(slip_encode(b"AB"), vec![0xC0, b'A', b'B', 0xC0]);
+        assert_eq!(
+            slip_encode(&[0x01, 0xC0, 0xDB, 0x02]),
+            vec![0xC0, 0x01, 0xDB, 0xDC, 0xDB, 0xDD, 0x02, 0xC0]
+        );
+        assert_eq!(slip_encode(&[]), vec![0xC0, 0xC0]);
+    }
+
+    #[test]
+    fn test_decode_escapes_and_round_trip() {
+        assert_eq!(
+            slip_decode(&[0xC0, 0x01, 0xDB, 0xDC, 0xDB, 0xDD, 0x02, 0xC0]),
+            vec![vec![0x01, 0xC0, 0xDB, 0x02]]
+        );
+        let payload: Vec<u8> = (0..=255).collect();
+        assert_eq!(slip_decode(&slip_encode(&payload)), vec![payload]);
+    }
+
+    #[test]
+    fn test_empty_frames_skipped() {
+        assert_eq!(slip_decode(&[0xC0, 0xC0, 0xC0]), Vec::<Vec<u8>>::new());
+        assert_eq!(
+            slip_decode(&[0xC0, b'a', 0xC0, 0xC0, b'b', 0xC0]),
+            vec![b"a".to_vec(), b"b".to_vec()]
+        );
+        // Without a leading END
+        assert_eq!(slip_decode(&[b'a', 0xC0]), vec![b"a".to_vec()]);
+    }
+
+    #[test]
+    fn test_frame_split_across_reads() {
+        let mut decoder = SlipDecoder::default();
+        assert!(decoder.feed(&[0xC0, b'h', b'i']).is_empty());
+        assert_eq!(decoder.pending(), b"hi");
+        // Split between ESC and its escaped byte
+        assert!(decoder.feed(&[0xDB]).is_empty());
+        assert_eq!(
+            decoder.feed(&[0xDC, 0xC0, b'x']),
+            vec![vec![b'h', b'i', 0xC0]]
+        );
+        assert_eq!(decoder.pending(), b"x");
+
+        decoder.reset();
+        assert!(decoder.pending().is_empty());
+        assert!(slip_decode(b"no end").is_empty());
+    }
+
+    #[test]
+    fn test_oversized_frame_dropped() {
+        let mut decoder = SlipDecoder::with_max_frame(4);
+        assert!(decoder.feed(b"abcd").is_empty());
+        assert_eq!(decoder.take_dropped(), 0);
+        // The fifth byte drops the frame; the rest of it is skipped too
+        assert!(decoder.feed(b"efgh").is_empty());
+        assert!(decoder.pending().is_empty());
+        assert_eq!(
+            decoder.feed(&[b'i', 0xC0, b'o', b'k', 0xC0]),
+            vec![b"ok".to_vec()]
+        );
+        assert_eq!(decoder.take_dropped(), 1);
+        assert_eq!(decoder.take_dropped(), 0);
+
+        // A frame of exactly the maximum still decodes
+        assert_eq!(
+            decoder.feed(&[b'w', b'x', b'y', b'z', 0xC0]),
+            vec![b"wxyz".to_vec()]
+        );
+    }
+
+    #[test]
+    fn test_invalid_escape_kept() {
+        assert_eq!(slip_decode(&[0xDB, b'A', 0xC0]), vec![b"A".to_vec()]);
+    }
+}
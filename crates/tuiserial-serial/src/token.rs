@@ -0,0 +1,369 @@
+//! Integer tokens for ASCII TX input
+//!
+//! `{{u16le:1234}}` / `{{u32be:0xDEADBEEF}}` / `{{i8:-1}}` expand to the raw
+//! bytes of the integer in the given byte order; everything outside a token
+//! is sent as-is. Values are decimal, or `0x` / `0o` / `0b` prefixed, with an
+//! optional leading `-` for signed types. `{{{{` sends a literal `{{`.
+
+use std::fmt;
+
+use thiserror::Error;
+
+/// Byte order of a multi-byte token
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Endian {
+    Little,
+    Big,
+}
+
+impl fmt::Display for Endian {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Endian::Little => "le",
+            Endian::Big => "be",
+        })
+    }
+}
+
+/// Integer type named by a token, e.g. `u16le`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IntType {
+    pub signed: bool,
+    /// Width in bytes (1, 2, 4 or 8)
+    pub width: usize,
+    pub endian: Endian,
+}
+
+impl IntType {
+    fn parse(name: &str) -> Result<Self, TokenError> {
+        let unknown = || TokenError::UnknownType(name.to_string());
+        let signed = match name.as_bytes().first() {
+            Some(b'u') => false,
+            Some(b'i') => true,
+            _ => return Err(unknown()),
+        };
+        let rest = &name[1..];
+        let (bits, endian) = if let Some(bits) = rest.strip_suffix("le") {
+            (bits, Some(Endian::Little))
+        } else if let Some(bits) = rest.strip_suffix("be") {
+            (bits, Some(Endian::Big))
+        } else {
+            (rest, None)
+        };
+        let width = match bits {
+            "8" => 1,
+            "16" => 2,
+            "32" => 4,
+            "64" => 8,
+            _ => return Err(unknown()),
+        };
+        let endian = match endian {
+            Some(endian) => endian,
+            // Byte order is meaningless for a single byte
+            None if width == 1 => Endian::Little,
+            None => return Err(TokenError::MissingEndian(name.to_string())),
+        };
+        Ok(Self {
+            signed,
+            width,
+            endian,
+        })
+    }
+
+    fn range(&self) -> (i128, i128) {
+        let bits = self.width as u32 * 8;
+        if self.signed {
+            (-(1i128 << (bits - 1)), (1i128 << (bits - 1)) - 1)
+        } else {
+            (0, (1i128 << bits) - 1)
+        }
+    }
+
+    /// Encode `value` (already range-checked) into `width` bytes
+    fn encode(&self, value: i128) -> Vec<u8> {
+        let le = (value as u64).to_le_bytes();
+        let mut bytes = le[..self.width].to_vec();
+        if self.endian == Endian::Big {
+            bytes.reverse();
+        }
+        bytes
+    }
+}
+
+impl fmt::Display for IntType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = if self.signed { 'i' } else { 'u' };
+        write!(f, "{}{}", sign, self.width * 8)?;
+        if self.width > 1 {
+            write!(f, "{}", self.endian)?;
+        }
+        Ok(())
+    }
+}
+
+/// Why a TX token could not be expanded
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum TokenError {
+    #[error("Unterminated token at position {0} (missing '}}}}')")]
+    Unterminated(usize),
+
+    #[error("Token '{0}' must have the form {{{{type:value}}}}")]
+    Malformed(String),
+
+    #[error("Unknown token type '{0}' (expected u8/i8, u16/i16, u32/i32, u64/i64)")]
+    UnknownType(String),
+
+    #[error("Token type '{0}' needs a byte order suffix (le or be)")]
+    MissingEndian(String),
+
+    #[error("Invalid base-{radix} value '{value}'")]
+    InvalidValue { value: String, radix: u32 },
+
+    #[error("Value '{value}' does not fit in {ty}")]
+    OutOfRange { value: String, ty: String },
+}
+
+/// Expand all `{{type:value}}` tokens in `input` and return the resulting bytes
+///
+/// # Example
+/// ```
+/// use tuiserial_serial::expand_tx_tokens;
+/// let bytes = expand_tx_tokens("A{{u16be:0x1234}}").unwrap();
+/// assert_eq!(bytes, vec![b'A', 0x12, 0x34]);
+/// ```
+pub fn expand_tx_tokens(input: &str) -> Result<Vec<u8>, TokenError> {
+    let mut out = Vec::with_capacity(input.len());
+    let mut rest = input;
+
+    while let Some(start) = rest.find("{{") {
+        out.extend_from_slice(&rest.as_bytes()[..start]);
+        let body_start = start + 2;
+        if rest[body_start..].starts_with("{{") {
+            out.extend_from_slice(b"{{");
+            rest = &rest[body_start + 2..];
+            continue;
+        }
+        let Some(len) = rest[body_start..].find("}}") else {
+            return Err(TokenError::Unterminated(input.len() - rest.len() + start));
+        };
+        out.extend(encode_token(&rest[body_start..body_start + len])?);
+        rest = &rest[body_start + len + 2..];
+    }
+    out.extend_from_slice(rest.as_bytes());
+    Ok(out)
+}
+
+/// Encode the inside of one token, e.g. `u32be:0xDEADBEEF`
+fn encode_token(body: &str) -> Result<Vec<u8>, TokenError> {
+    let Some((name, value)) = body.split_once(':') else {
+        return Err(TokenError::Malformed(body.to_string()));
+    };
+    let ty = IntType::parse(name.trim().to_ascii_lowercase().as_str())?;
+    let value = value.trim();
+    let parsed = parse_int(value)?;
+
+    let (min, max) = ty.range();
+    if !(min..=max).contains(&parsed) {
+        return Err(TokenError::OutOfRange {
+            value: value.to_string(),
+            ty: ty.to_string(),
+        });
+    }
+    Ok(ty.encode(parsed))
+}
+
+/// Parse a decimal or `0x`/`0o`/`0b` prefixed integer with optional sign
+fn parse_int(value: &str) -> Result<i128, TokenError> {
+    let (negative, digits) = match value.strip_prefix('-') {
+        Some(d) => (true, d),
+        None => (false, value.strip_prefix('+').unwrap_or(value)),
+    };
+    let lower = digits.to_ascii_lowercase();
+    let (radix, digits) = if let Some(d) = lower.strip_prefix("0x") {
+        (16, d)
+    } else if let Some(d) = lower.strip_prefix("0o") {
+        (8, d)
+    } else if let Some(d) = lower.strip_prefix("0b") {
+        (2, d)
+    } else {
+        (10, lower.as_str())
+    };
+
+    let invalid = || TokenError::InvalidValue {
+        value: value.to_string(),
+        radix,
+    };
+    let digits = digits.replace('_', "");
+    if digits.is_empty() || !digits.chars().all(|c| c.is_digit(radix)) {
+        return Err(invalid());
+    }
+    // All digits are valid, so the only possible failure is u128 overflow
+    let magnitude = u128::from_str_radix(&digits, radix).unwrap_or(u128::MAX);
+    let magnitude = i128::try_from(magnitude).unwrap_or(i128::MAX);
+    Ok(if negative { -magnitude } else { magnitude })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_8bit_tokens() {
+        assert_eq!(expand_tx_tokens("{{u8:255}}").unwrap(), vec![0xFF]);
+        assert_eq!(expand_tx_tokens("{{i8:-1}}").unwrap(), vec![0xFF]);
+        assert_eq!(expand_tx_tokens("{{i8:-128}}").unwrap(), vec![0x80]);
+        assert_eq!(expand_tx_tokens("{{u8le:0x7f}}").unwrap(), vec![0x7F]);
+    }
+
+    #[test]
+    fn test_16bit_tokens() {
+        assert_eq!(
+            expand_tx_tokens("{{u16le:1234}}").unwrap(),
+            vec![0xD2, 0x04]
+        );
+        assert_eq!(
+            expand_tx_tokens("{{u16be:1234}}").unwrap(),
+            vec![0x04, 0xD2]
+        );
+        assert_eq!(expand_tx_tokens("{{i16le:-2}}").unwrap(), vec![0xFE, 0xFF]);
+        assert_eq!(expand_tx_tokens("{{i16be:-2}}").unwrap(), vec![0xFF, 0xFE]);
+    }
+
+    #[test]
+    fn test_32bit_tokens() {
+        assert_eq!(
+            expand_tx_tokens("{{u32le:0xDEADBEEF}}").unwrap(),
+            vec![0xEF, 0xBE, 0xAD, 0xDE]
+        );
+        assert_eq!(
+            expand_tx_tokens("{{u32be:0xDEADBEEF}}").unwrap(),
+            vec![0xDE, 0xAD, 0xBE, 0xEF]
+        );
+        assert_eq!(
+            expand_tx_tokens("{{i32le:-1}}").unwrap(),
+            vec![0xFF, 0xFF, 0xFF, 0xFF]
+        );
+        assert_eq!(
+            expand_tx_tokens("{{i32be:-2147483648}}").unwrap(),
+            vec![0x80, 0x00, 0x00, 0x00]
+        );
+    }
+
+    #[test]
+    fn test_64bit_tokens() {
+        assert_eq!(
+            expand_tx_tokens("{{u64le:0x0102030405060708}}").unwrap(),
+            vec![8, 7, 6, 5, 4, 3, 2, 1]
+        );
+        assert_eq!(
+            expand_tx_tokens("{{u64be:0x0102030405060708}}").unwrap(),
+            vec![1, 2, 3, 4, 5, 6, 7, 8]
+        );
+        assert_eq!(expand_tx_tokens("{{i64le:-1}}").unwrap(), vec![0xFF; 8]);
+        assert_eq!(
+            expand_tx_tokens("{{i64be:-9223372036854775808}}").unwrap(),
+            vec![0x80, 0, 0, 0, 0, 0, 0, 0]
+        );
+    }
+
+    #[test]
+    fn test_radix_prefixes_and_mixed_text() {
+        assert_eq!(expand_tx_tokens("{{u8:0b1010}}").unwrap(), vec![0x0A]);
+        assert_eq!(expand_tx_tokens("{{u8:0o17}}").unwrap(), vec![0x0F]);
+        assert_eq!(
+            expand_tx_tokens("{{U16BE:0X1_000}}").unwrap(),
+            vec![0x10, 0x00]
+        );
+        assert_eq!(
+            expand_tx_tokens("ID{{u8:1}}:{{u16be:2}}!").unwrap(),
+            b"ID\x01:\x00\x02!".to_vec()
+        );
+        assert_eq!(expand_tx_tokens("plain text").unwrap(), b"plain text");
+        assert_eq!(expand_tx_tokens("a}}b").unwrap(), b"a}}b");
+    }
+
+    #[test]
+    fn test_overflow() {
+        for token in [
+            "{{u8:256}}",
+            "{{i8:128}}",
+            "{{i8:-129}}",
+            "{{u16le:65536}}",
+            "{{i16be:0x8000}}",
+            "{{u32le:0x100000000}}",
+            "{{u64be:18446744073709551616}}",
+            "{{u16le:-1}}",
+            "{{u64le:999999999999999999999999999999999999999999}}",
+        ] {
+            assert!(
+                matches!(expand_tx_tokens(token), Err(TokenError::OutOfRange { .. })),
+                "{token}"
+            );
+        }
+        assert_eq!(
+            expand_tx_tokens("{{u8:300}}").unwrap_err().to_string(),
+            "Value '300' does not fit in u8"
+        );
+    }
+
+    #[test]
+    fn test_bad_radix() {
+        assert_eq!(
+            expand_tx_tokens("{{u16le:0xZZ}}"),
+            Err(TokenError::InvalidValue {
+                value: "0xZZ".to_string(),
+                radix: 16
+            })
+        );
+        assert!(matches!(
+            expand_tx_tokens("{{u8:0b102}}"),
+            Err(TokenError::InvalidValue { radix: 2, .. })
+        ));
+        assert!(matches!(
+            expand_tx_tokens("{{u8:0o9}}"),
+            Err(TokenError::InvalidValue { radix: 8, .. })
+        ));
+        assert!(matches!(
+            expand_tx_tokens("{{u8:12ab}}"),
+            Err(TokenError::InvalidValue { radix: 10, .. })
+        ));
+        assert!(matches!(
+            expand_tx_tokens("{{u8:0x}}"),
+            Err(TokenError::InvalidValue { .. })
+        ));
+    }
+
+    #[test]
+    fn test_malformed_tokens() {
+        assert_eq!(
+            expand_tx_tokens("ab{{u8:1"),
+            Err(TokenError::Unterminated(2))
+        );
+        assert!(matches!(
+            expand_tx_tokens("{{u8}}"),
+            Err(TokenError::Malformed(_))
+        ));
+        assert!(matches!(
+            expand_tx_tokens("{{u24le:1}}"),
+            Err(TokenError::UnknownType(_))
+        ));
+        assert!(matches!(
+            expand_tx_tokens("{{f32le:1}}"),
+            Err(TokenError::UnknownType(_))
+        ));
+        assert!(matches!(
+            expand_tx_tokens("{{u16:1}}"),
+            Err(TokenError::MissingEndian(_))
+        ));
+    }
+
+    #[test]
+    fn test_escaped_braces_are_literal() {
+        assert_eq!(expand_tx_tokens("{{{{").unwrap(), b"{{");
+        assert_eq!(expand_tx_tokens("a{{{{b}}").unwrap(), b"a{{b}}");
+        assert_eq!(
+            expand_tx_tokens("{{{{{{u8:1}}").unwrap(),
+            vec![b'{', b'{', 0x01]
+        );
+    }
+}
@@ -4,11 +4,20 @@
 //! - Creating multiple sessions
 //! - Switching between layouts
 //! - Basic keyboard navigation
+//! - Reordering tabs with Ctrl+Shift+←/→ or by dragging them
+//! - Mirroring a session's log read-only in another pane (V), with its own
+//!   HEX/TEXT mode (X)
+//! - Restoring the last layout, focused pane, active session and pane
+//!   settings, plus session names, configs and color tags, saved to small
+//!   state files on quit
 //!
 //! Run with: cargo run --example basic
 
 use crossterm::{
-    event::{self, Event, KeyCode, KeyModifiers},
+    event::{
+        self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyModifiers, MouseButton,
+        MouseEvent, MouseEventKind,
+    },
     execute,
     terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
 };
@@ -23,40 +32,82 @@ use ratatui::{
 use std::{
     error::Error,
     io,
+    path::PathBuf,
     time::{Duration, Instant},
 };
 use tuiserial_tabs::{
-    TabsManager, calculate_tab_bar_height, draw_compact_tab_bar, draw_pane_border,
+    LayoutState, SessionDefaults, SessionManager, TabsManager, calculate_tab_bar_height,
+    draw_compact_tab_bar, draw_tagged_pane_border, get_tab_at_position,
+    tuiserial_core::{AppState, ConnectionState, DisplayMode},
 };
 
+/// Layout state file in the per-user state directory (the data directory
+/// on platforms without one), kept apart from any serial config
+fn layout_state_path() -> PathBuf {
+    dirs::state_dir()
+        .or_else(dirs::data_dir)
+        .unwrap_or_else(std::env::temp_dir)
+        .join("tuiserial")
+        .join("tabs-demo")
+        .join("layout.json")
+}
+
+/// Saved sessions (names, configs, color tags), next to the layout state
+fn sessions_path() -> PathBuf {
+    layout_state_path().with_file_name("sessions.json")
+}
+
+/// Sessions shown on the first run, before any were saved
+fn add_demo_sessions(tabs_manager: &mut TabsManager) {
+    tabs_manager.add_session_with_port("COM1".to_string(), Some("Arduino Uno".to_string()));
+    tabs_manager.add_session_with_port("COM3".to_string(), Some("ESP32".to_string()));
+    tabs_manager.add_session_with_port("COM5".to_string(), Some("Sensor Board".to_string()));
+
+    // Simulate some session states
+    if let Some(session) = tabs_manager.sessions_mut().get_session_mut(0) {
+        session.connection = ConnectionState::Connected;
+        session
+            .message_log
+            .push_rx(b"Hello from Arduino!\r\n".to_vec());
+        session.message_log.push_tx(b"AT+CMD\r\n".to_vec());
+    }
+
+    if let Some(session) = tabs_manager.sessions_mut().get_session_mut(1) {
+        session.connection = ConnectionState::Connected;
+    }
+}
+
 struct DemoApp {
     tabs_manager: TabsManager,
     should_quit: bool,
     last_key: String,
     tick_count: u64,
     start_time: Instant,
+    /// Tab bar area from the last frame, for mouse hit-testing
+    tab_area: Rect,
+    /// Tab under the mouse when a drag started
+    drag_from: Option<usize>,
 }
 
 impl DemoApp {
     fn new() -> Self {
         let mut tabs_manager = TabsManager::new();
 
-        // Add some demo sessions
-        tabs_manager.add_session_with_port("COM1".to_string(), Some("Arduino Uno".to_string()));
-        tabs_manager.add_session_with_port("COM3".to_string(), Some("ESP32".to_string()));
-        tabs_manager.add_session_with_port("COM5".to_string(), Some("Sensor Board".to_string()));
-
-        // Simulate some session states
-        if let Some(session) = tabs_manager.sessions_mut().get_session_mut(0) {
-            session.is_connected = true;
-            session
-                .message_log
-                .push_rx(b"Hello from Arduino!\r\n".to_vec());
-            session.message_log.push_tx(b"AT+CMD\r\n".to_vec());
+        match SessionManager::load(&sessions_path()) {
+            Ok(Some(sessions)) => *tabs_manager.sessions_mut() = sessions,
+            _ => {
+                // First run: new sessions start from tuiserial's own config
+                let mut app = AppState::default();
+                app.load_config();
+                tabs_manager
+                    .sessions_mut()
+                    .set_defaults(SessionDefaults::from_app(&app));
+                add_demo_sessions(&mut tabs_manager);
+            }
         }
 
-        if let Some(session) = tabs_manager.sessions_mut().get_session_mut(1) {
-            session.is_connected = true;
+        if let Ok(Some(state)) = LayoutState::load(&layout_state_path()) {
+            tabs_manager.apply_layout_state(&state);
         }
 
         Self {
@@ -65,6 +116,8 @@ impl DemoApp {
             last_key: String::from("None"),
             tick_count: 0,
             start_time: Instant::now(),
+            tab_area: Rect::default(),
+            drag_from: None,
         }
     }
 
@@ -81,6 +134,11 @@ impl DemoApp {
             }
 
             // Session management
+            (KeyCode::Char('t') | KeyCode::Char('T'), m)
+                if m.contains(KeyModifiers::CONTROL | KeyModifiers::SHIFT) =>
+            {
+                self.tabs_manager.clone_settings_to_new_session();
+            }
             (KeyCode::Char('t'), m) if m.contains(KeyModifiers::CONTROL) => {
                 let count = self.tabs_manager.sessions().len() + 1;
                 self.tabs_manager
@@ -99,6 +157,17 @@ impl DemoApp {
                     self.tabs_manager.sessions_mut().next_session();
                 }
             }
+            // Reorder the active tab
+            (KeyCode::Left, m)
+                if m.contains(KeyModifiers::CONTROL) && m.contains(KeyModifiers::SHIFT) =>
+            {
+                self.tabs_manager.move_active_session(-1);
+            }
+            (KeyCode::Right, m)
+                if m.contains(KeyModifiers::CONTROL) && m.contains(KeyModifiers::SHIFT) =>
+            {
+                self.tabs_manager.move_active_session(1);
+            }
             (KeyCode::Left, m) if m.contains(KeyModifiers::CONTROL) => {
                 self.tabs_manager.sessions_mut().prev_session();
             }
@@ -140,8 +209,12 @@ impl DemoApp {
             // Toggle connection (demo)
             (KeyCode::Char('c'), _) => {
                 if let Some(session) = self.tabs_manager.focused_pane_session_mut() {
-                    session.is_connected = !session.is_connected;
-                    if session.is_connected {
+                    session.connection = if session.is_connected() {
+                        ConnectionState::Disconnected
+                    } else {
+                        ConnectionState::Connected
+                    };
+                    if session.is_connected() {
                         session.add_success("Connected to port");
                     } else {
                         session.add_info("Disconnected from port");
@@ -149,6 +222,37 @@ impl DemoApp {
                 }
             }
 
+            // Cycle the active session's color tag
+            (KeyCode::Char('g'), _) => {
+                let active_idx = self.tabs_manager.sessions().active_index();
+                self.tabs_manager.sessions_mut().cycle_color_tag(active_idx);
+            }
+
+            // Mirror the active session's log read-only in the focused pane
+            (KeyCode::Char('v'), _) => {
+                let pane = self.tabs_manager.panes().focused_pane();
+                if self.tabs_manager.is_pane_read_only(pane) {
+                    self.tabs_manager.panes_mut().clear_pane_mirror(pane);
+                } else {
+                    let active_idx = self.tabs_manager.sessions().active_index();
+                    self.tabs_manager
+                        .mirror_session(pane, active_idx, Some(DisplayMode::Hex));
+                }
+            }
+
+            // Toggle the focused pane's display mode
+            (KeyCode::Char('x'), _) => {
+                let pane = self.tabs_manager.panes().focused_pane();
+                self.tabs_manager.toggle_pane_display_mode(pane);
+            }
+
+            // New sessions take the active session's modes
+            (KeyCode::Char('d'), _) => {
+                let sessions = self.tabs_manager.sessions_mut();
+                let defaults = SessionDefaults::from_session(sessions.active_session());
+                sessions.set_defaults(defaults);
+            }
+
             // Add demo message
             (KeyCode::Char('m'), _) => {
                 if let Some(session) = self.tabs_manager.focused_pane_session_mut() {
@@ -162,13 +266,38 @@ impl DemoApp {
         }
     }
 
+    /// Click a tab to switch to it; drag it onto another tab to move it there
+    fn handle_mouse(&mut self, mouse: MouseEvent) {
+        let count = self.tabs_manager.sessions().len();
+        let active = self.tabs_manager.sessions().active_index();
+        let tab = get_tab_at_position(self.tab_area, mouse.column, mouse.row, count, active);
+
+        match mouse.kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                if let Some(idx) = tab {
+                    self.tabs_manager.sessions_mut().switch_to(idx);
+                }
+                self.drag_from = tab;
+            }
+            MouseEventKind::Up(MouseButton::Left) => {
+                if let (Some(from), Some(to)) = (self.drag_from.take(), tab)
+                    && from != to
+                {
+                    self.tabs_manager.move_session(from, to);
+                    self.last_key = format!("Drag tab {} -> {}", from + 1, to + 1);
+                }
+            }
+            _ => {}
+        }
+    }
+
     fn tick(&mut self) {
         self.tick_count += 1;
         self.tabs_manager.update_notifications();
     }
 }
 
-fn ui(f: &mut Frame, app: &DemoApp) {
+fn ui(f: &mut Frame, app: &mut DemoApp) {
     let tabs_manager = &app.tabs_manager;
 
     // Calculate tab bar height
@@ -192,6 +321,7 @@ fn ui(f: &mut Frame, app: &DemoApp) {
     if tab_height > 0 {
         draw_compact_tab_bar(f, main_chunks[1], tabs_manager.sessions());
     }
+    app.tab_area = main_chunks[1];
 
     // Content area with panes
     draw_panes(f, app, main_chunks[2]);
@@ -221,17 +351,27 @@ fn draw_panes(f: &mut Frame, app: &DemoApp, area: Rect) {
         if let Some(session) = tabs_manager.session_for_pane(pane_idx) {
             let is_focused = tabs_manager.is_pane_focused(pane_idx);
 
+            let title = if tabs_manager.is_pane_read_only(pane_idx) {
+                format!("{} (mirror)", session.name)
+            } else {
+                session.name.clone()
+            };
+
             // Draw pane border
-            let inner = draw_pane_border(
+            let inner = draw_tagged_pane_border(
                 f,
                 *pane_area,
-                &session.name,
+                &title,
                 is_focused,
-                session.is_connected,
+                session.is_connected(),
+                session.color_tag,
             );
 
             // Draw session content
-            draw_session_content(f, session, inner, is_focused);
+            let display_mode = tabs_manager
+                .pane_display_mode(pane_idx)
+                .unwrap_or(session.display_mode);
+            draw_session_content(f, session, inner, is_focused, display_mode);
         }
     }
 }
@@ -241,6 +381,7 @@ fn draw_session_content(
     session: &tuiserial_tabs::SerialSession,
     area: Rect,
     is_focused: bool,
+    display_mode: DisplayMode,
 ) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
@@ -255,7 +396,7 @@ fn draw_session_content(
     draw_config_info(f, session, chunks[0]);
 
     // Log area
-    draw_log_area(f, session, chunks[1], is_focused);
+    draw_log_area(f, session, chunks[1], is_focused, display_mode);
 
     // TX area
     draw_tx_info(f, session, chunks[2]);
@@ -281,7 +422,7 @@ fn draw_config_info(f: &mut Frame, session: &tuiserial_tabs::SerialSession, area
         ]),
         Line::from(vec![
             Span::styled("Status: ", Style::default().fg(Color::Cyan)),
-            if session.is_connected {
+            if session.is_connected() {
                 Span::styled("Connected", Style::default().fg(Color::Green))
             } else {
                 Span::styled("Disconnected", Style::default().fg(Color::Red))
@@ -299,6 +440,7 @@ fn draw_log_area(
     session: &tuiserial_tabs::SerialSession,
     area: Rect,
     is_focused: bool,
+    display_mode: DisplayMode,
 ) {
     let entries = &session.message_log.entries;
     let lines: Vec<Line> = entries
@@ -310,14 +452,24 @@ fn draw_log_area(
             let direction_color = match entry.direction {
                 tuiserial_core::log::LogDirection::Rx => Color::Green,
                 tuiserial_core::log::LogDirection::Tx => Color::Yellow,
+                tuiserial_core::log::LogDirection::Event(_) => Color::Magenta,
             };
 
             let direction_symbol = match entry.direction {
                 tuiserial_core::log::LogDirection::Rx => "← ",
                 tuiserial_core::log::LogDirection::Tx => "→ ",
+                tuiserial_core::log::LogDirection::Event(_) => "• ",
             };
 
-            let data_str = String::from_utf8_lossy(&entry.data).to_string();
+            let data_str = match display_mode {
+                DisplayMode::Hex => entry
+                    .data
+                    .iter()
+                    .map(|b| format!("{b:02X}"))
+                    .collect::<Vec<_>>()
+                    .join(" "),
+                DisplayMode::Text => String::from_utf8_lossy(&entry.data).to_string(),
+            };
             Line::from(vec![
                 Span::styled(direction_symbol, Style::default().fg(direction_color)),
                 Span::raw(data_str.trim_end().to_string()),
@@ -369,16 +521,28 @@ fn draw_status_bar(f: &mut Frame, area: Rect, app: &DemoApp) {
         Line::from(vec![
             Span::styled("Ctrl+T", Style::default().fg(Color::Yellow)),
             Span::raw(": New  "),
+            Span::styled("Ctrl+Shift+T", Style::default().fg(Color::Yellow)),
+            Span::raw(": Clone  "),
             Span::styled("Ctrl+W", Style::default().fg(Color::Yellow)),
             Span::raw(": Close  "),
             Span::styled("Ctrl+Tab", Style::default().fg(Color::Yellow)),
             Span::raw(": Switch  "),
+            Span::styled("Ctrl+Shift+←/→", Style::default().fg(Color::Yellow)),
+            Span::raw(": Move Tab  "),
             Span::styled("Ctrl+L", Style::default().fg(Color::Yellow)),
             Span::raw(": Layout  "),
             Span::styled("C", Style::default().fg(Color::Yellow)),
             Span::raw(": Connect  "),
             Span::styled("M", Style::default().fg(Color::Yellow)),
-            Span::raw(": Add Msg"),
+            Span::raw(": Add Msg  "),
+            Span::styled("G", Style::default().fg(Color::Yellow)),
+            Span::raw(": Color Tag  "),
+            Span::styled("V", Style::default().fg(Color::Yellow)),
+            Span::raw(": Mirror  "),
+            Span::styled("X", Style::default().fg(Color::Yellow)),
+            Span::raw(": HEX/TEXT  "),
+            Span::styled("D", Style::default().fg(Color::Yellow)),
+            Span::raw(": Set Defaults"),
         ]),
         Line::from(vec![
             Span::styled("Layout: ", Style::default().fg(Color::Cyan)),
@@ -401,7 +565,7 @@ fn main() -> Result<(), Box<dyn Error>> {
     // Setup terminal
     enable_raw_mode()?;
     let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen)?;
+    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
@@ -413,15 +577,17 @@ fn main() -> Result<(), Box<dyn Error>> {
     let mut last_tick = Instant::now();
 
     loop {
-        terminal.draw(|f| ui(f, &app))?;
+        terminal.draw(|f| ui(f, &mut app))?;
 
         let timeout = tick_rate
             .checked_sub(last_tick.elapsed())
             .unwrap_or_else(|| Duration::from_secs(0));
 
         if event::poll(timeout)? {
-            if let Event::Key(key) = event::read()? {
-                app.handle_key(key.code, key.modifiers);
+            match event::read()? {
+                Event::Key(key) => app.handle_key(key.code, key.modifiers),
+                Event::Mouse(mouse) => app.handle_mouse(mouse),
+                _ => {}
             }
         }
 
@@ -437,8 +603,19 @@ fn main() -> Result<(), Box<dyn Error>> {
 
     // Restore terminal
     disable_raw_mode()?;
-    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+    execute!(
+        terminal.backend_mut(),
+        LeaveAlternateScreen,
+        DisableMouseCapture
+    )?;
     terminal.show_cursor()?;
 
+    if let Err(e) = app.tabs_manager.layout_state().save(&layout_state_path()) {
+        eprintln!("Failed to save layout state: {e}");
+    }
+    if let Err(e) = app.tabs_manager.sessions().save(&sessions_path()) {
+        eprintln!("Failed to save sessions: {e}");
+    }
+
     Ok(())
 }
@@ -6,10 +6,82 @@
 //! The canonical `LayoutMode` type is defined in `tuiserial_core`.
 
 use ratatui::layout::Rect;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
 
 // Re-export from core — single source of truth
+use tuiserial_core::types::DisplayMode;
 pub use tuiserial_core::types::LayoutMode;
 
+/// Per-pane overrides of how the pane's session is shown
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PaneSettings {
+    /// Display mode used instead of the session's own
+    pub display_mode: Option<DisplayMode>,
+    /// The pane only mirrors the session's log; input, connection and
+    /// config changes go through a pane that is not read-only
+    pub read_only: bool,
+}
+
+/// Layout preferences restored on the next launch: the layout, the
+/// focused pane, the active session and what each pane shows. Saved in
+/// its own file so they stay out of the serial config.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LayoutState {
+    pub layout_mode: LayoutMode,
+    pub focused_pane: usize,
+    pub active_session: usize,
+    /// Session shown in each pane, in pane order
+    pub pane_sessions: Vec<usize>,
+    /// Display overrides of each pane, parallel to `pane_sessions`
+    pub pane_settings: Vec<PaneSettings>,
+}
+
+impl Default for LayoutState {
+    fn default() -> Self {
+        Self {
+            layout_mode: LayoutMode::Single,
+            focused_pane: 0,
+            active_session: 0,
+            pane_sessions: Vec::new(),
+            pane_settings: Vec::new(),
+        }
+    }
+}
+
+impl LayoutState {
+    /// Serialize as JSON
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Restore a state saved with `to_json`
+    pub fn from_json(json: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(json)
+    }
+
+    /// Write the state to `path`, creating its directory if needed
+    pub fn save(&self, path: &Path) -> std::io::Result<()> {
+        if let Some(dir) = path.parent()
+            && !dir.as_os_str().is_empty()
+        {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(path, self.to_json()?)
+    }
+
+    /// Read the state saved at `path`; `None` if nothing was saved yet
+    pub fn load(path: &Path) -> std::io::Result<Option<Self>> {
+        match std::fs::read_to_string(path) {
+            Ok(json) => Ok(Some(Self::from_json(&json)?)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+}
+
 /// Pane manager for tracking visible panes and their mappings to sessions
 pub struct PaneManager {
     /// Current layout mode
@@ -18,6 +90,9 @@ pub struct PaneManager {
     /// Mapping of pane index to session index
     pane_to_session: Vec<usize>,
 
+    /// Display overrides per pane, parallel to `pane_to_session`
+    pane_settings: Vec<PaneSettings>,
+
     /// Currently focused pane index
     focused_pane: usize,
 }
@@ -28,6 +103,7 @@ impl PaneManager {
         Self {
             layout_mode: LayoutMode::Single,
             pane_to_session: vec![0],
+            pane_settings: vec![PaneSettings::default()],
             focused_pane: 0,
         }
     }
@@ -82,6 +158,79 @@ impl PaneManager {
         }
     }
 
+    /// Get the display overrides of a specific pane
+    pub fn pane_settings(&self, pane_index: usize) -> Option<&PaneSettings> {
+        self.pane_settings.get(pane_index)
+    }
+
+    /// Display overrides of every pane, parallel to `pane_mappings`
+    pub fn all_pane_settings(&self) -> &[PaneSettings] {
+        &self.pane_settings
+    }
+
+    /// Replace the display overrides of a specific pane
+    pub fn set_pane_settings(&mut self, pane_index: usize, settings: PaneSettings) {
+        if let Some(current) = self.pane_settings.get_mut(pane_index) {
+            *current = settings;
+        }
+    }
+
+    /// Check if a pane only mirrors its session's log
+    pub fn is_pane_read_only(&self, pane_index: usize) -> bool {
+        self.pane_settings(pane_index).is_some_and(|s| s.read_only)
+    }
+
+    /// Make a pane a read-only mirror of `session_index`, optionally shown
+    /// in its own display mode
+    pub fn set_pane_mirror(
+        &mut self,
+        pane_index: usize,
+        session_index: usize,
+        display_mode: Option<DisplayMode>,
+    ) -> bool {
+        if pane_index >= self.pane_to_session.len() {
+            return false;
+        }
+        self.pane_to_session[pane_index] = session_index;
+        self.pane_settings[pane_index] = PaneSettings {
+            display_mode,
+            read_only: true,
+        };
+        true
+    }
+
+    /// Turn a mirror pane back into a normal pane showing the same session
+    pub fn clear_pane_mirror(&mut self, pane_index: usize) {
+        if let Some(settings) = self.pane_settings.get_mut(pane_index) {
+            *settings = PaneSettings::default();
+        }
+    }
+
+    /// Display mode of a pane whose session uses `session_mode`
+    pub fn pane_display_mode(&self, pane_index: usize, session_mode: DisplayMode) -> DisplayMode {
+        self.pane_settings(pane_index)
+            .and_then(|s| s.display_mode)
+            .unwrap_or(session_mode)
+    }
+
+    /// Toggle a pane's display mode override between HEX and TEXT. Returns
+    /// `false` if the pane has no override, i.e. the session's own mode
+    /// should be toggled instead.
+    pub fn toggle_pane_display_mode(&mut self, pane_index: usize) -> bool {
+        let Some(mode) = self
+            .pane_settings
+            .get_mut(pane_index)
+            .and_then(|s| s.display_mode.as_mut())
+        else {
+            return false;
+        };
+        *mode = match mode {
+            DisplayMode::Hex => DisplayMode::Text,
+            DisplayMode::Text => DisplayMode::Hex,
+        };
+        true
+    }
+
     /// Focus the next pane
     pub fn focus_next_pane(&mut self) {
         if !self.pane_to_session.is_empty() {
@@ -129,6 +278,8 @@ impl PaneManager {
             // Remove excess panes
             self.pane_to_session.truncate(max_panes);
         }
+        self.pane_settings
+            .resize(self.pane_to_session.len(), PaneSettings::default());
 
         // Ensure focused pane is valid
         if self.focused_pane >= self.pane_to_session.len() {
@@ -170,6 +321,14 @@ impl PaneManager {
         self.layout_mode.calculate_areas(area)
     }
 
+    /// Rewrite every pane's session index through `remap`, e.g. after the
+    /// sessions were reordered
+    pub fn remap_sessions(&mut self, remap: impl Fn(usize) -> usize) {
+        for session_idx in &mut self.pane_to_session {
+            *session_idx = remap(*session_idx);
+        }
+    }
+
     /// Reset pane mappings to sequential sessions
     pub fn reset_mappings(&mut self) {
         for (i, session_idx) in self.pane_to_session.iter_mut().enumerate() {
@@ -244,4 +403,80 @@ mod tests {
         manager.cycle_focused_session(3);
         assert_eq!(manager.focused_session(), Some(0));
     }
+
+    #[test]
+    fn test_pane_settings_follow_layout() {
+        let mut manager = PaneManager::new();
+        manager.set_layout_mode(LayoutMode::Grid2x2);
+        assert!(manager.set_pane_mirror(3, 0, Some(DisplayMode::Hex)));
+        assert!(manager.is_pane_read_only(3));
+        assert_eq!(manager.session_for_pane(3), Some(0));
+        assert!(!manager.set_pane_mirror(4, 0, None));
+
+        manager.set_layout_mode(LayoutMode::SplitHorizontal);
+        assert_eq!(manager.pane_settings(3), None);
+        manager.set_layout_mode(LayoutMode::Grid2x2);
+        assert!(!manager.is_pane_read_only(3));
+    }
+
+    #[test]
+    fn test_pane_display_mode_override() {
+        let mut manager = PaneManager::new();
+        assert_eq!(
+            manager.pane_display_mode(0, DisplayMode::Text),
+            DisplayMode::Text
+        );
+        assert!(!manager.toggle_pane_display_mode(0));
+
+        manager.set_pane_mirror(0, 0, Some(DisplayMode::Hex));
+        assert_eq!(
+            manager.pane_display_mode(0, DisplayMode::Text),
+            DisplayMode::Hex
+        );
+        assert!(manager.toggle_pane_display_mode(0));
+        assert_eq!(
+            manager.pane_display_mode(0, DisplayMode::Hex),
+            DisplayMode::Text
+        );
+
+        manager.clear_pane_mirror(0);
+        assert!(!manager.is_pane_read_only(0));
+        assert_eq!(
+            manager.pane_display_mode(0, DisplayMode::Hex),
+            DisplayMode::Hex
+        );
+    }
+
+    #[test]
+    fn test_layout_state_file_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("state").join("layout.json");
+        assert_eq!(LayoutState::load(&path).unwrap(), None);
+
+        let state = LayoutState {
+            layout_mode: LayoutMode::Grid2x2,
+            focused_pane: 3,
+            active_session: 2,
+            pane_sessions: vec![0, 1, 2, 1],
+            pane_settings: vec![
+                PaneSettings::default(),
+                PaneSettings::default(),
+                PaneSettings::default(),
+                PaneSettings {
+                    display_mode: Some(DisplayMode::Text),
+                    read_only: true,
+                },
+            ],
+        };
+        state.save(&path).unwrap();
+        assert_eq!(LayoutState::load(&path).unwrap(), Some(state));
+
+        std::fs::write(&path, "{\"layout_mode\":\"SplitVertical\"}").unwrap();
+        let partial = LayoutState::load(&path).unwrap().unwrap();
+        assert_eq!(partial.layout_mode, LayoutMode::SplitVertical);
+        assert_eq!(partial.focused_pane, 0);
+
+        std::fs::write(&path, "not json").unwrap();
+        assert!(LayoutState::load(&path).is_err());
+    }
 }
@@ -44,16 +44,20 @@ pub mod session;
 pub mod tabs_ui;
 
 // Re-exports for convenience
-pub use layout::{LayoutMode, PaneManager};
-pub use session::{SerialSession, SessionManager};
+pub use layout::{LayoutMode, LayoutState, PaneManager, PaneSettings};
+pub use session::{
+    SerialSession, SessionDefaults, SessionManager, SessionSnapshot, TAG_COLORS, next_color_tag,
+};
 
 // Re-export UI rendering functions
 pub use tabs_ui::{
     calculate_tab_bar_height, draw_compact_tab_bar, draw_layout_indicator, draw_pane_border,
     draw_session_info_overlay, draw_session_list, draw_tab_bar, draw_tab_bar_with_controls,
-    draw_tab_notification_badge, get_tab_at_position,
+    draw_tab_notification_badge, draw_tagged_pane_border, get_tab_at_position, session_markers,
 };
 
+use tuiserial_core::DisplayMode;
+
 // Re-export commonly used dependencies
 pub use ratatui;
 pub use tuiserial_core;
@@ -126,10 +130,10 @@ impl TabsManager {
         self.sessions.get_session(session_idx)
     }
 
-    /// Get the session for the currently focused pane mutably
+    /// Get the session for the currently focused pane mutably; `None` while
+    /// the focused pane is a read-only mirror
     pub fn focused_pane_session_mut(&mut self) -> Option<&mut SerialSession> {
-        let session_idx = self.panes.focused_session()?;
-        self.sessions.get_session_mut(session_idx)
+        self.session_for_pane_mut(self.panes.focused_pane())
     }
 
     /// Add a new session
@@ -142,6 +146,41 @@ impl TabsManager {
         self.sessions.add_session_with_port(port, name)
     }
 
+    /// Clone the active session's settings (without its port) into a new session
+    pub fn clone_settings_to_new_session(&mut self) -> usize {
+        self.sessions.clone_settings_to_new_session()
+    }
+
+    /// Move a session to another tab position; panes keep showing the
+    /// same sessions
+    pub fn move_session(&mut self, from: usize, to: usize) -> bool {
+        if !self.sessions.move_session(from, to) {
+            return false;
+        }
+        self.panes
+            .remap_sessions(|idx| session::index_after_move(idx, from, to));
+        true
+    }
+
+    /// Swap two sessions' tab positions; panes keep showing the same sessions
+    pub fn swap_sessions(&mut self, a: usize, b: usize) -> bool {
+        if !self.sessions.swap_sessions(a, b) {
+            return false;
+        }
+        self.panes
+            .remap_sessions(|idx| session::index_after_swap(idx, a, b));
+        true
+    }
+
+    /// Move the active session one tab left (`-1`) or right (`1`)
+    pub fn move_active_session(&mut self, delta: isize) -> bool {
+        let from = self.sessions.active_index();
+        match from.checked_add_signed(delta) {
+            Some(to) => self.move_session(from, to),
+            None => false,
+        }
+    }
+
     /// Remove a session
     pub fn remove_session(&mut self, index: usize) -> Option<SerialSession> {
         let removed = self.sessions.remove_session(index)?;
@@ -242,16 +281,91 @@ impl TabsManager {
         self.sessions.get_session(session_idx)
     }
 
-    /// Get session for a specific pane mutably
+    /// Get session for a specific pane mutably; `None` for a read-only
+    /// mirror pane
     pub fn session_for_pane_mut(&mut self, pane_index: usize) -> Option<&mut SerialSession> {
+        if self.panes.is_pane_read_only(pane_index) {
+            return None;
+        }
         let session_idx = self.panes.session_for_pane(pane_index)?;
         self.sessions.get_session_mut(session_idx)
     }
 
+    /// Make a pane a read-only mirror of another session's log, shown in
+    /// `display_mode` (or the session's own mode) independently of the
+    /// panes that own the session
+    pub fn mirror_session(
+        &mut self,
+        pane_index: usize,
+        session_index: usize,
+        display_mode: Option<DisplayMode>,
+    ) -> bool {
+        session_index < self.sessions.len()
+            && self
+                .panes
+                .set_pane_mirror(pane_index, session_index, display_mode)
+    }
+
+    /// Check if a pane is a read-only mirror
+    pub fn is_pane_read_only(&self, pane_index: usize) -> bool {
+        self.panes.is_pane_read_only(pane_index)
+    }
+
+    /// Display mode a pane renders its session's log in
+    pub fn pane_display_mode(&self, pane_index: usize) -> Option<DisplayMode> {
+        let session = self.session_for_pane(pane_index)?;
+        Some(
+            self.panes
+                .pane_display_mode(pane_index, session.display_mode),
+        )
+    }
+
+    /// Toggle the display mode of a pane: its own override if it has one,
+    /// otherwise the session's mode (not possible for a read-only mirror)
+    pub fn toggle_pane_display_mode(&mut self, pane_index: usize) {
+        if !self.panes.toggle_pane_display_mode(pane_index)
+            && let Some(session) = self.session_for_pane_mut(pane_index)
+        {
+            session.toggle_display_mode();
+        }
+    }
+
     /// Check if a pane is focused
     pub fn is_pane_focused(&self, pane_index: usize) -> bool {
         self.panes.is_pane_focused(pane_index)
     }
+
+    /// Current layout, focused pane and active session, for saving
+    pub fn layout_state(&self) -> LayoutState {
+        LayoutState {
+            layout_mode: self.panes.layout_mode(),
+            focused_pane: self.panes.focused_pane(),
+            active_session: self.sessions.active_index(),
+            pane_sessions: self.panes.pane_mappings().to_vec(),
+            pane_settings: self.panes.all_pane_settings().to_vec(),
+        }
+    }
+
+    /// Restore a saved layout state. Sessions are added if the layout has
+    /// more panes than there are sessions; a focused pane, active session
+    /// or pane mapping that no longer exists is left at its current value.
+    pub fn apply_layout_state(&mut self, state: &LayoutState) {
+        self.panes.set_layout_mode(state.layout_mode);
+        for i in self.sessions.len()..self.panes.pane_count() {
+            self.sessions
+                .add_session(Some(format!("Session {}", i + 1)));
+        }
+        for (pane, &session) in state.pane_sessions.iter().enumerate() {
+            if session < self.sessions.len() {
+                self.panes.set_pane_session(pane, session);
+                if let Some(&settings) = state.pane_settings.get(pane) {
+                    self.panes.set_pane_settings(pane, settings);
+                }
+            }
+        }
+        self.panes.focus_pane(state.focused_pane);
+        self.sessions.switch_to(state.active_session);
+    }
 }
 
 impl Default for TabsManager {
@@ -312,4 +426,116 @@ mod tests {
         manager.focus_prev_pane();
         assert_eq!(manager.panes().focused_pane(), 0);
     }
+
+    #[test]
+    fn test_move_session_updates_pane_mappings() {
+        let mut manager = TabsManager::new();
+        manager.add_session(Some("B".to_string()));
+        manager.add_session(Some("C".to_string()));
+        manager.add_session(Some("D".to_string()));
+        manager.panes_mut().set_layout_mode(LayoutMode::Grid2x2);
+        let shown = |m: &TabsManager| -> Vec<String> {
+            (0..m.visible_pane_count())
+                .map(|p| m.session_for_pane(p).unwrap().name.clone())
+                .collect()
+        };
+        let before = shown(&manager);
+
+        assert!(manager.move_session(0, 2));
+        assert_eq!(shown(&manager), before);
+        assert_eq!(manager.panes().pane_mappings(), &[2, 0, 1, 3]);
+
+        assert!(manager.swap_sessions(1, 3));
+        assert_eq!(shown(&manager), before);
+    }
+
+    #[test]
+    fn test_move_active_session() {
+        let mut manager = TabsManager::new();
+        manager.add_session(Some("B".to_string()));
+        let active = manager.active_session().id;
+
+        assert!(!manager.move_active_session(-1));
+        assert!(manager.move_active_session(1));
+        assert_eq!(manager.sessions().active_index(), 1);
+        assert_eq!(manager.active_session().id, active);
+        assert!(!manager.move_active_session(1));
+    }
+
+    #[test]
+    fn test_mirror_pane_shares_log_with_own_display_mode() {
+        let mut manager = TabsManager::new();
+        manager.next_layout();
+        manager.active_session_mut().display_mode = DisplayMode::Text;
+        assert!(manager.mirror_session(1, 0, Some(DisplayMode::Hex)));
+        assert!(!manager.mirror_session(1, 9, None));
+
+        manager
+            .focused_pane_session_mut()
+            .unwrap()
+            .message_log
+            .push_rx(b"OK\r\n".to_vec());
+        let mirrored = manager.session_for_pane(1).unwrap();
+        assert_eq!(mirrored.id, manager.session_for_pane(0).unwrap().id);
+        assert_eq!(mirrored.message_log.entries.len(), 1);
+
+        assert_eq!(manager.pane_display_mode(0), Some(DisplayMode::Text));
+        assert_eq!(manager.pane_display_mode(1), Some(DisplayMode::Hex));
+        manager.toggle_pane_display_mode(0);
+        assert_eq!(manager.pane_display_mode(0), Some(DisplayMode::Hex));
+        manager.toggle_pane_display_mode(1);
+        assert_eq!(manager.pane_display_mode(1), Some(DisplayMode::Text));
+        assert_eq!(manager.active_session().display_mode, DisplayMode::Hex);
+
+        // The mirror is read-only
+        manager.focus_next_pane();
+        assert!(manager.focused_pane_session_mut().is_none());
+        assert!(manager.session_for_pane_mut(1).is_none());
+    }
+
+    #[test]
+    fn test_layout_state_round_trip() {
+        let mut manager = TabsManager::new();
+        manager.add_session(Some("B".to_string()));
+        manager.add_session(Some("C".to_string()));
+        manager.panes_mut().set_layout_mode(LayoutMode::Grid1x2);
+        manager.focus_next_pane();
+        manager.focus_next_pane();
+        manager.sessions_mut().switch_to(1);
+        manager
+            .panes_mut()
+            .set_pane_mirror(1, 0, Some(DisplayMode::Text));
+
+        let json = manager.layout_state().to_json().unwrap();
+        let mut restored = TabsManager::new();
+        restored.add_session(Some("B".to_string()));
+        restored.add_session(Some("C".to_string()));
+        restored.apply_layout_state(&LayoutState::from_json(&json).unwrap());
+
+        assert_eq!(restored.layout_state(), manager.layout_state());
+        assert_eq!(restored.layout_mode(), LayoutMode::Grid1x2);
+        assert_eq!(restored.panes().focused_pane(), 2);
+        assert_eq!(restored.sessions().active_index(), 1);
+        assert_eq!(restored.panes().session_for_pane(1), Some(0));
+        assert!(restored.is_pane_read_only(1));
+        assert_eq!(restored.pane_display_mode(1), Some(DisplayMode::Text));
+    }
+
+    #[test]
+    fn test_apply_layout_state_with_fewer_sessions() {
+        let mut manager = TabsManager::new();
+        manager.apply_layout_state(&LayoutState {
+            layout_mode: LayoutMode::Grid2x2,
+            focused_pane: 9,
+            active_session: 9,
+            pane_sessions: vec![0, 9],
+            ..Default::default()
+        });
+        // The grid gets its sessions; out-of-range indices are ignored
+        assert_eq!(manager.sessions().len(), 4);
+        assert_eq!(manager.visible_pane_count(), 4);
+        assert_eq!(manager.panes().focused_pane(), 0);
+        assert_eq!(manager.sessions().active_index(), 0);
+        assert_eq!(manager.panes().session_for_pane(1), Some(1));
+    }
 }
@@ -3,14 +3,17 @@
 //! This module provides session management for multiple serial port connections,
 //! allowing users to monitor and interact with multiple serial ports simultaneously.
 
-use ratatui::widgets::ListState;
+use ratatui::{style::Color, widgets::ListState};
+use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
+use std::path::Path;
 
 use tuiserial_core::{
+    AppState, FramingMode, TextInput,
     config::SerialConfig,
     log::MessageLog,
     notification::Notification,
-    types::{AppendMode, DisplayMode, FlowControl, FocusedField, Parity, TxMode},
+    types::{AppendMode, ConnectionState, DisplayMode, FlowControl, FocusedField, Parity, TxMode},
 };
 
 /// A single serial port session
@@ -22,6 +25,9 @@ pub struct SerialSession {
     /// Session name (user-customizable)
     pub name: String,
 
+    /// Color tag grouping related sessions in the tab bar and pane borders
+    pub color_tag: Option<Color>,
+
     /// Serial configuration for this session
     pub config: SerialConfig,
 
@@ -32,7 +38,7 @@ pub struct SerialSession {
     pub display_mode: DisplayMode,
 
     /// Connection status
-    pub is_connected: bool,
+    pub connection: ConnectionState,
 
     /// Configuration lock status
     pub config_locked: bool,
@@ -42,10 +48,12 @@ pub struct SerialSession {
     pub auto_scroll: bool,
 
     /// TX Input state
-    pub tx_input: String,
+    pub tx_input: TextInput,
     pub tx_mode: TxMode,
     pub tx_append_mode: AppendMode,
-    pub tx_cursor: usize,
+
+    /// How received data is split into entries and TX is framed
+    pub framing: FramingMode,
 
     /// UI State for this session
     pub port_list_state: ListState,
@@ -69,10 +77,11 @@ impl SerialSession {
         Self {
             id,
             name,
+            color_tag: None,
             config: SerialConfig::default(),
             message_log: MessageLog::new(),
             display_mode: DisplayMode::Hex,
-            is_connected: false,
+            connection: ConnectionState::Disconnected,
             config_locked: false,
             scroll_offset: 0,
             auto_scroll: true,
@@ -82,10 +91,10 @@ impl SerialSession {
             flow_control_state: ListState::default().with_selected(Some(0)), // None
             data_bits_state: ListState::default().with_selected(Some(3)), // 8
             stop_bits_state: ListState::default().with_selected(Some(0)), // 1
-            tx_input: String::new(),
+            tx_input: TextInput::new(),
             tx_mode: TxMode::Ascii,
             tx_append_mode: AppendMode::None,
-            tx_cursor: 0,
+            framing: FramingMode::Raw,
             append_mode_state: ListState::default().with_selected(Some(0)),
             focused_field: FocusedField::Port,
             notifications: VecDeque::new(),
@@ -99,6 +108,19 @@ impl SerialSession {
         session
     }
 
+    /// Take the display, TX, append and framing modes from `defaults`
+    pub fn apply_defaults(&mut self, defaults: &SessionDefaults) {
+        self.display_mode = defaults.display_mode;
+        self.tx_mode = defaults.tx_mode;
+        self.tx_append_mode = defaults.append_mode;
+        self.append_mode_state.select(
+            AppendMode::all()
+                .iter()
+                .position(|&mode| mode == defaults.append_mode),
+        );
+        self.framing = defaults.framing;
+    }
+
     /// Lock configuration (when connecting)
     pub fn lock_config(&mut self) {
         self.config_locked = true;
@@ -109,6 +131,11 @@ impl SerialSession {
         self.config_locked = false;
     }
 
+    /// Whether the session's port is open
+    pub fn is_connected(&self) -> bool {
+        self.connection.is_connected()
+    }
+
     /// Check if configuration can be modified
     pub fn can_modify_config(&self) -> bool {
         !self.config_locked
@@ -198,12 +225,12 @@ impl SerialSession {
 
     /// Toggle TX mode, converting existing input when switching
     pub fn toggle_tx_mode(&mut self) {
-        self.tx_input = tuiserial_core::convert_tx_input(&self.tx_input, self.tx_mode);
+        let converted = tuiserial_core::convert_tx_input(self.tx_input.as_str(), self.tx_mode);
+        self.tx_input.set_text(converted);
         self.tx_mode = match self.tx_mode {
             TxMode::Hex => TxMode::Ascii,
             TxMode::Ascii => TxMode::Hex,
         };
-        self.tx_cursor = self.tx_input.chars().count();
     }
 
     /// Focus next field
@@ -365,6 +392,130 @@ impl SerialSession {
     }
 }
 
+/// Colors offered when cycling a session's tag, after "no tag"
+pub const TAG_COLORS: [Color; 6] = [
+    Color::Red,
+    Color::Green,
+    Color::Yellow,
+    Color::Blue,
+    Color::Magenta,
+    Color::Cyan,
+];
+
+/// The tag after `current` in `TAG_COLORS`, wrapping back to no tag.
+/// A custom color outside the palette restarts the cycle.
+pub fn next_color_tag(current: Option<Color>) -> Option<Color> {
+    match current.and_then(|c| TAG_COLORS.iter().position(|&t| t == c)) {
+        Some(i) => TAG_COLORS.get(i + 1).copied(),
+        None if current.is_some() => None,
+        None => Some(TAG_COLORS[0]),
+    }
+}
+
+/// The persisted part of a session: what it is, not what it is doing
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SessionSnapshot {
+    pub name: String,
+    pub config: SerialConfig,
+    /// Stored as a color name (`"blue"`) or `"#rrggbb"`
+    #[serde(default, with = "color_tag_serde")]
+    pub color_tag: Option<Color>,
+    #[serde(default)]
+    pub framing: FramingMode,
+}
+
+impl From<&SerialSession> for SessionSnapshot {
+    fn from(session: &SerialSession) -> Self {
+        Self {
+            name: session.name.clone(),
+            config: session.config.clone(),
+            color_tag: session.color_tag,
+            framing: session.framing,
+        }
+    }
+}
+
+/// What `SessionManager::save` writes: the defaults for new sessions and
+/// the sessions. A bare list of sessions, as saved before the defaults
+/// were, still loads with the built-in defaults.
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum SessionsFile {
+    Full {
+        #[serde(default)]
+        defaults: SessionDefaults,
+        sessions: Vec<SessionSnapshot>,
+    },
+    Sessions(Vec<SessionSnapshot>),
+}
+
+/// Serde adapter writing `Option<Color>` as a name/hex string
+mod color_tag_serde {
+    use ratatui::style::Color;
+    use serde::{Deserialize, Deserializer, Serializer, de::Error};
+
+    pub fn serialize<S: Serializer>(tag: &Option<Color>, s: S) -> Result<S::Ok, S::Error> {
+        match tag {
+            Some(color) => s.serialize_some(&color.to_string().to_lowercase()),
+            None => s.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Option<Color>, D::Error> {
+        Option::<String>::deserialize(d)?
+            .map(|name| {
+                name.parse()
+                    .map_err(|_| D::Error::custom(format!("invalid color tag '{name}'")))
+            })
+            .transpose()
+    }
+}
+
+/// Preferences given to every newly added session, saved along with the
+/// sessions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SessionDefaults {
+    pub display_mode: DisplayMode,
+    pub tx_mode: TxMode,
+    pub append_mode: AppendMode,
+    pub framing: FramingMode,
+}
+
+impl Default for SessionDefaults {
+    /// The built-in defaults of [`SerialSession::new`]
+    fn default() -> Self {
+        Self {
+            display_mode: DisplayMode::Hex,
+            tx_mode: TxMode::Ascii,
+            append_mode: AppendMode::None,
+            framing: FramingMode::Raw,
+        }
+    }
+}
+
+impl SessionDefaults {
+    /// The preferences `session` is currently using
+    pub fn from_session(session: &SerialSession) -> Self {
+        Self {
+            display_mode: session.display_mode,
+            tx_mode: session.tx_mode,
+            append_mode: session.tx_append_mode,
+            framing: session.framing,
+        }
+    }
+
+    /// The preferences of the single-session app
+    pub fn from_app(app: &AppState) -> Self {
+        Self {
+            display_mode: app.display_mode,
+            tx_mode: app.tx_mode,
+            append_mode: app.tx_append_mode,
+            framing: app.settings.framing,
+        }
+    }
+}
+
 /// Session manager for handling multiple serial port sessions
 pub struct SessionManager {
     /// All sessions
@@ -375,6 +526,9 @@ pub struct SessionManager {
 
     /// Next session ID
     next_id: usize,
+
+    /// Applied to sessions created by `add_session`/`add_session_with_port`
+    defaults: SessionDefaults,
 }
 
 impl SessionManager {
@@ -386,6 +540,7 @@ impl SessionManager {
             sessions,
             active_session: 0,
             next_id: 1,
+            defaults: SessionDefaults::default(),
         }
     }
 
@@ -440,7 +595,8 @@ impl SessionManager {
         self.next_id += 1;
 
         let name = name.unwrap_or_else(|| format!("Session {}", id + 1));
-        let session = SerialSession::new(id, name);
+        let mut session = SerialSession::new(id, name);
+        session.apply_defaults(&self.defaults);
 
         self.sessions.push(session);
         self.sessions.len() - 1
@@ -452,12 +608,24 @@ impl SessionManager {
         self.next_id += 1;
 
         let name = name.unwrap_or_else(|| format!("Session {} - {}", id + 1, port));
-        let session = SerialSession::with_port(id, name, port);
+        let mut session = SerialSession::with_port(id, name, port);
+        session.apply_defaults(&self.defaults);
 
         self.sessions.push(session);
         self.sessions.len() - 1
     }
 
+    /// Preferences given to newly added sessions
+    pub fn defaults(&self) -> &SessionDefaults {
+        &self.defaults
+    }
+
+    /// Set the preferences given to sessions added from now on; existing
+    /// sessions keep theirs
+    pub fn set_defaults(&mut self, defaults: SessionDefaults) {
+        self.defaults = defaults;
+    }
+
     /// Remove a session by index
     pub fn remove_session(&mut self, index: usize) -> Option<SerialSession> {
         if self.sessions.len() <= 1 {
@@ -519,13 +687,99 @@ impl SessionManager {
         }
     }
 
+    /// Set or clear a session's color tag
+    pub fn set_color_tag(&mut self, index: usize, tag: Option<Color>) -> bool {
+        if let Some(session) = self.sessions.get_mut(index) {
+            session.color_tag = tag;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Advance a session's color tag through `TAG_COLORS` (then no tag)
+    pub fn cycle_color_tag(&mut self, index: usize) -> Option<Color> {
+        let session = self.sessions.get_mut(index)?;
+        session.color_tag = next_color_tag(session.color_tag);
+        session.color_tag
+    }
+
+    /// Snapshots of every session, in tab order, for saving
+    pub fn snapshots(&self) -> Vec<SessionSnapshot> {
+        self.sessions.iter().map(SessionSnapshot::from).collect()
+    }
+
+    /// Rebuild sessions from saved snapshots; the first one becomes
+    /// active. An empty list yields the usual single default session. New
+    /// sessions get the built-in defaults until `set_defaults`.
+    pub fn from_snapshots(snapshots: Vec<SessionSnapshot>) -> Self {
+        if snapshots.is_empty() {
+            return Self::new();
+        }
+        let sessions: Vec<SerialSession> = snapshots
+            .into_iter()
+            .enumerate()
+            .map(|(id, snapshot)| {
+                let mut session = SerialSession::new(id, snapshot.name);
+                session.config = snapshot.config;
+                session.color_tag = snapshot.color_tag;
+                session.framing = snapshot.framing;
+                session
+            })
+            .collect();
+        Self {
+            next_id: sessions.len(),
+            sessions,
+            active_session: 0,
+            defaults: SessionDefaults::default(),
+        }
+    }
+
+    /// Serialize the sessions and the defaults for new ones as JSON
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(&SessionsFile::Full {
+            defaults: self.defaults,
+            sessions: self.snapshots(),
+        })
+    }
+
+    /// Restore sessions and defaults saved with `to_json`
+    pub fn from_json(json: &str) -> serde_json::Result<Self> {
+        let (defaults, sessions) = match serde_json::from_str(json)? {
+            SessionsFile::Full { defaults, sessions } => (defaults, sessions),
+            SessionsFile::Sessions(sessions) => (SessionDefaults::default(), sessions),
+        };
+        let mut manager = Self::from_snapshots(sessions);
+        manager.set_defaults(defaults);
+        Ok(manager)
+    }
+
+    /// Write the sessions to `path`, creating its directory if needed
+    pub fn save(&self, path: &Path) -> std::io::Result<()> {
+        if let Some(dir) = path.parent()
+            && !dir.as_os_str().is_empty()
+        {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(path, self.to_json()?)
+    }
+
+    /// Read the sessions saved at `path`; `None` if nothing was saved yet
+    pub fn load(path: &Path) -> std::io::Result<Option<Self>> {
+        match std::fs::read_to_string(path) {
+            Ok(json) => Ok(Some(Self::from_json(&json)?)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
     /// Duplicate current session
     pub fn duplicate_active_session(&mut self) -> usize {
         let mut new_session = self.active_session().clone();
         new_session.id = self.next_id;
         self.next_id += 1;
         new_session.name = format!("{} (Copy)", new_session.name);
-        new_session.is_connected = false;
+        new_session.connection = ConnectionState::Disconnected;
         new_session.config_locked = false;
         new_session.message_log = MessageLog::new();
 
@@ -533,6 +787,49 @@ impl SessionManager {
         self.sessions.len() - 1
     }
 
+    /// Clone the active session's settings into a new session and switch to it
+    ///
+    /// Serial config, display and TX preferences are copied, but the port is
+    /// cleared (one physical port cannot be shared) and the new session starts
+    /// disconnected with an empty log and input.
+    pub fn clone_settings_to_new_session(&mut self) -> usize {
+        let index = self.duplicate_active_session();
+        let session = &mut self.sessions[index];
+        session.config.port.clear();
+        session.port_list_state.select(None);
+        session.tx_input.clear();
+        session.scroll_offset = 0;
+        session.auto_scroll = true;
+        session.notifications.clear();
+        session.focused_field = FocusedField::Port;
+        self.active_session = index;
+        index
+    }
+
+    /// Move the session at `from` to position `to`, shifting the sessions in
+    /// between. The active index keeps pointing at the same session.
+    pub fn move_session(&mut self, from: usize, to: usize) -> bool {
+        let len = self.sessions.len();
+        if from >= len || to >= len {
+            return false;
+        }
+        let session = self.sessions.remove(from);
+        self.sessions.insert(to, session);
+        self.active_session = index_after_move(self.active_session, from, to);
+        true
+    }
+
+    /// Swap two sessions. The active index keeps pointing at the same session.
+    pub fn swap_sessions(&mut self, a: usize, b: usize) -> bool {
+        let len = self.sessions.len();
+        if a >= len || b >= len {
+            return false;
+        }
+        self.sessions.swap(a, b);
+        self.active_session = index_after_swap(self.active_session, a, b);
+        true
+    }
+
     /// Update all sessions' notifications
     pub fn update_all_notifications(&mut self) {
         for session in &mut self.sessions {
@@ -541,8 +838,281 @@ impl SessionManager {
     }
 }
 
+/// New position of the session at `index` after `move_session(from, to)`
+pub(crate) fn index_after_move(index: usize, from: usize, to: usize) -> usize {
+    if index == from {
+        to
+    } else if from < index && index <= to {
+        index - 1
+    } else if to <= index && index < from {
+        index + 1
+    } else {
+        index
+    }
+}
+
+/// New position of the session at `index` after `swap_sessions(a, b)`
+pub(crate) fn index_after_swap(index: usize, a: usize, b: usize) -> usize {
+    if index == a {
+        b
+    } else if index == b {
+        a
+    } else {
+        index
+    }
+}
+
 impl Default for SessionManager {
     fn default() -> Self {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_sessions_use_configured_defaults() {
+        let mut manager = SessionManager::new();
+        let builtin = manager.add_session(None);
+        assert_eq!(
+            SessionDefaults::from_session(manager.get_session(builtin).unwrap()),
+            SessionDefaults::default()
+        );
+
+        let defaults = SessionDefaults {
+            display_mode: DisplayMode::Text,
+            tx_mode: TxMode::Hex,
+            append_mode: AppendMode::CRLF,
+            framing: FramingMode::Slip,
+        };
+        manager.set_defaults(defaults);
+        let index = manager.add_session(Some("B".to_string()));
+        let with_port = manager.add_session_with_port("COM3".to_string(), None);
+        for index in [index, with_port] {
+            let session = manager.get_session(index).unwrap();
+            assert_eq!(SessionDefaults::from_session(session), defaults);
+            assert_eq!(
+                session
+                    .append_mode_state
+                    .selected()
+                    .map(|i| AppendMode::all()[i]),
+                Some(AppendMode::CRLF)
+            );
+        }
+        // Existing sessions keep their modes
+        assert_eq!(
+            manager.get_session(0).unwrap().display_mode,
+            DisplayMode::Hex
+        );
+    }
+
+    #[test]
+    fn test_defaults_from_app() {
+        let mut app = AppState {
+            display_mode: DisplayMode::Text,
+            tx_append_mode: AppendMode::LF,
+            ..Default::default()
+        };
+        app.settings.framing = FramingMode::Slip;
+        let defaults = SessionDefaults::from_app(&app);
+        assert_eq!(defaults.display_mode, DisplayMode::Text);
+        assert_eq!(defaults.tx_mode, app.tx_mode);
+        assert_eq!(defaults.append_mode, AppendMode::LF);
+        assert_eq!(defaults.framing, FramingMode::Slip);
+    }
+
+    #[test]
+    fn test_clone_settings_clears_port_log_and_connection() {
+        let mut manager = SessionManager::new();
+        {
+            let source = manager.active_session_mut();
+            source.config.port = "/dev/ttyUSB0".to_string();
+            source.config.baud_rate = 115200;
+            source.config.parity = Parity::Even;
+            source.display_mode = DisplayMode::Text;
+            source.tx_mode = TxMode::Hex;
+            source.tx_append_mode = AppendMode::CRLF;
+            source.tx_input = "01 02".into();
+            source.connection = ConnectionState::Connected;
+            source.lock_config();
+            source.message_log.push_rx(vec![1, 2, 3]);
+        }
+
+        let index = manager.clone_settings_to_new_session();
+        assert_eq!(manager.len(), 2);
+        assert_eq!(manager.active_index(), index);
+
+        let clone = manager.active_session();
+        assert_eq!(clone.config.baud_rate, 115200);
+        assert_eq!(clone.config.parity, Parity::Even);
+        assert_eq!(clone.display_mode, DisplayMode::Text);
+        assert_eq!(clone.tx_mode, TxMode::Hex);
+        assert_eq!(clone.tx_append_mode, AppendMode::CRLF);
+
+        assert!(clone.config.port.is_empty());
+        assert!(!clone.is_connected());
+        assert!(clone.can_modify_config());
+        assert!(clone.message_log.entries.is_empty());
+        assert!(clone.tx_input.is_empty());
+
+        // The source session is untouched
+        let source = manager.get_session(0).unwrap();
+        assert_eq!(source.config.port, "/dev/ttyUSB0");
+        assert!(source.is_connected());
+        assert_eq!(source.message_log.entries.len(), 1);
+    }
+
+    fn manager_with(names: &[&str]) -> SessionManager {
+        let mut manager = SessionManager::new();
+        manager.rename_session(0, names[0].to_string());
+        for name in &names[1..] {
+            manager.add_session(Some(name.to_string()));
+        }
+        manager
+    }
+
+    fn names(manager: &SessionManager) -> Vec<&str> {
+        manager.sessions().iter().map(|s| s.name.as_str()).collect()
+    }
+
+    #[test]
+    fn test_move_session_keeps_active_identity() {
+        let mut manager = manager_with(&["A", "B", "C", "D"]);
+        manager.switch_to(1);
+
+        assert!(manager.move_session(0, 3));
+        assert_eq!(names(&manager), ["B", "C", "D", "A"]);
+        assert_eq!(manager.active_session().name, "B");
+
+        assert!(manager.move_session(0, 2));
+        assert_eq!(names(&manager), ["C", "D", "B", "A"]);
+        assert_eq!(manager.active_session().name, "B");
+
+        assert!(manager.move_session(3, 0));
+        assert_eq!(names(&manager), ["A", "C", "D", "B"]);
+        assert_eq!(manager.active_session().name, "B");
+
+        assert!(!manager.move_session(0, 4));
+    }
+
+    #[test]
+    fn test_swap_sessions_keeps_active_identity() {
+        let mut manager = manager_with(&["A", "B", "C"]);
+        manager.switch_to(2);
+        assert!(manager.swap_sessions(0, 2));
+        assert_eq!(names(&manager), ["C", "B", "A"]);
+        assert_eq!(manager.active_session().name, "C");
+
+        assert!(manager.swap_sessions(1, 2));
+        assert_eq!(manager.active_session().name, "C");
+        assert!(!manager.swap_sessions(0, 3));
+    }
+
+    #[test]
+    fn test_color_tag_assignment_and_cycle() {
+        let mut manager = manager_with(&["A", "B"]);
+        assert_eq!(manager.get_session(1).unwrap().color_tag, None);
+        assert!(manager.set_color_tag(1, Some(Color::Blue)));
+        assert_eq!(manager.get_session(1).unwrap().color_tag, Some(Color::Blue));
+        assert!(!manager.set_color_tag(5, Some(Color::Red)));
+
+        assert_eq!(manager.cycle_color_tag(1), Some(Color::Magenta));
+        assert_eq!(manager.cycle_color_tag(1), Some(Color::Cyan));
+        assert_eq!(manager.cycle_color_tag(1), None);
+        assert_eq!(manager.cycle_color_tag(1), Some(Color::Red));
+        assert_eq!(next_color_tag(Some(Color::Rgb(1, 2, 3))), None);
+
+        // Copies keep the tag so related sessions stay grouped
+        manager.switch_to(1);
+        let copy = manager.duplicate_active_session();
+        assert_eq!(
+            manager.get_session(copy).unwrap().color_tag,
+            Some(Color::Red)
+        );
+    }
+
+    #[test]
+    fn test_color_tags_round_trip_through_snapshots() {
+        let mut manager = manager_with(&["sensor-1", "sensor-2", "gps"]);
+        manager.set_color_tag(0, Some(Color::Blue));
+        manager.set_color_tag(1, Some(Color::Rgb(0x12, 0xab, 0xef)));
+        manager.get_session_mut(2).unwrap().config.baud_rate = 115200;
+
+        let json = manager.to_json().unwrap();
+        assert!(json.contains("\"blue\""));
+        assert!(json.contains("\"#12abef\""));
+
+        let restored = SessionManager::from_json(&json).unwrap();
+        assert_eq!(restored.snapshots(), manager.snapshots());
+        assert_eq!(names(&restored), ["sensor-1", "sensor-2", "gps"]);
+        assert_eq!(restored.get_session(2).unwrap().color_tag, None);
+        assert_eq!(restored.get_session(2).unwrap().config.baud_rate, 115200);
+    }
+
+    #[test]
+    fn test_snapshot_color_tag_parsing() {
+        let json = r#"[{"name":"A","config":CONFIG,"color_tag":"Light Blue"},{"name":"B","config":CONFIG}]"#
+            .replace("CONFIG", &serde_json::to_string(&SerialConfig::default()).unwrap());
+        let restored = SessionManager::from_json(&json).unwrap();
+        assert_eq!(
+            restored.get_session(0).unwrap().color_tag,
+            Some(Color::LightBlue)
+        );
+        assert_eq!(restored.get_session(1).unwrap().color_tag, None);
+
+        let bad = json.replace("Light Blue", "chartreuse");
+        assert!(SessionManager::from_json(&bad).is_err());
+        assert_eq!(SessionManager::from_json("[]").unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_sessions_saved_to_disk() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("tabs").join("sessions.json");
+        assert!(SessionManager::load(&path).unwrap().is_none());
+
+        let mut manager = manager_with(&["sensor", "gps"]);
+        manager.set_color_tag(1, Some(Color::Green));
+        manager.save(&path).unwrap();
+
+        let restored = SessionManager::load(&path).unwrap().unwrap();
+        assert_eq!(restored.snapshots(), manager.snapshots());
+
+        std::fs::write(&path, "not json").unwrap();
+        assert!(SessionManager::load(&path).is_err());
+    }
+
+    #[test]
+    fn test_defaults_and_framing_persisted() {
+        let mut manager = manager_with(&["sensor"]);
+        let defaults = SessionDefaults {
+            display_mode: DisplayMode::Text,
+            append_mode: AppendMode::CRLF,
+            framing: FramingMode::Slip,
+            ..Default::default()
+        };
+        manager.set_defaults(defaults);
+        let slip = manager.add_session(Some("modem".to_string()));
+
+        let mut restored = SessionManager::from_json(&manager.to_json().unwrap()).unwrap();
+        assert_eq!(*restored.defaults(), defaults);
+        assert_eq!(
+            restored.get_session(slip).unwrap().framing,
+            FramingMode::Slip
+        );
+        assert_eq!(restored.get_session(0).unwrap().framing, FramingMode::Raw);
+        let added = restored.add_session(None);
+        assert_eq!(
+            SessionDefaults::from_session(restored.get_session(added).unwrap()),
+            defaults
+        );
+
+        // A bare session list loads with the built-in defaults
+        let legacy = serde_json::to_string(&manager.snapshots()).unwrap();
+        let restored = SessionManager::from_json(&legacy).unwrap();
+        assert_eq!(*restored.defaults(), SessionDefaults::default());
+        assert_eq!(restored.len(), 2);
+    }
+}
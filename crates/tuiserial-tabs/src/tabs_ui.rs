@@ -11,7 +11,19 @@ use ratatui::{
     widgets::{Block, Borders, Paragraph, Tabs},
 };
 
-use crate::session::SessionManager;
+use tuiserial_core::chrono::{DateTime, Local, TimeDelta};
+use tuiserial_core::{DisplayMode, LogDirection, TextInput, TxMode, display_width};
+
+use crate::session::{SerialSession, SessionManager};
+
+/// Traffic newer than this marks a tab as active
+const ACTIVITY_WINDOW: TimeDelta = TimeDelta::seconds(2);
+
+/// Width of the " | " divider between tabs
+const TAB_DIVIDER_WIDTH: usize = 3;
+
+/// Swatch drawn in a session's tag color
+const TAG_SWATCH: &str = "■ ";
 
 /// Render the tab bar showing all sessions
 pub fn draw_tab_bar(
@@ -23,6 +35,13 @@ pub fn draw_tab_bar(
     let active_idx = session_manager.active_index();
     let sessions = session_manager.sessions();
 
+    // Each tab gets an equal share of the width; markers are dropped
+    // before the name is shortened
+    let budget = (area.width as usize)
+        .saturating_sub(TAB_DIVIDER_WIDTH * sessions.len().saturating_sub(1))
+        / sessions.len().max(1);
+    let now = Local::now();
+
     // Build tab titles
     let titles: Vec<Line> = sessions
         .iter()
@@ -31,18 +50,37 @@ pub fn draw_tab_bar(
             let mut spans = vec![];
 
             // Add connection indicator
-            if session.is_connected {
+            if session.is_connected() {
                 spans.push(Span::styled("● ", Style::default().fg(Color::Green)));
             } else {
                 spans.push(Span::styled("○ ", Style::default().fg(Color::DarkGray)));
             }
+            if let Some(tag) = session.color_tag {
+                spans.push(Span::styled(TAG_SWATCH, Style::default().fg(tag)));
+            }
 
-            // Add session name
-            spans.push(Span::raw(&session.name));
+            // Close button hint for the active tab
+            let close = if idx == active_idx { " [×]" } else { "" };
+            let markers = session_markers(session, now);
+            let swatch = if session.color_tag.is_some() { 2 } else { 0 };
+            let fixed = 2 + swatch + close.chars().count();
+            let with_markers = fixed + 1 + display_width(&markers);
+
+            if with_markers + display_width(&session.name) <= budget {
+                spans.push(Span::raw(session.name.clone()));
+                spans.push(Span::styled(
+                    format!(" {markers}"),
+                    Style::default().fg(Color::Cyan),
+                ));
+            } else {
+                spans.push(Span::raw(truncate_to_width(
+                    &session.name,
+                    budget.saturating_sub(fixed),
+                )));
+            }
 
-            // Add close button hint for active tab
-            if idx == active_idx {
-                spans.push(Span::styled(" [×]", Style::default().fg(Color::Red)));
+            if !close.is_empty() {
+                spans.push(Span::styled(close, Style::default().fg(Color::Red)));
             }
 
             Line::from(spans)
@@ -68,6 +106,60 @@ pub fn draw_tab_bar(
     f.render_widget(tabs, area);
 }
 
+/// Compact status markers for a tab: display mode, TX mode and recent
+/// traffic, e.g. `H/A ↓` (hex display, ASCII TX, RX in the last 2 s)
+pub fn session_markers(session: &SerialSession, now: DateTime<Local>) -> String {
+    let display = match session.display_mode {
+        DisplayMode::Hex => 'H',
+        DisplayMode::Text => 'T',
+    };
+    let tx = match session.tx_mode {
+        TxMode::Hex => 'H',
+        TxMode::Ascii => 'A',
+    };
+
+    let (mut rx_recent, mut tx_recent) = (false, false);
+    for entry in session.message_log.entries.iter().rev() {
+        if now - entry.timestamp > ACTIVITY_WINDOW {
+            break;
+        }
+        match entry.direction {
+            LogDirection::Rx => rx_recent = true,
+            LogDirection::Tx => tx_recent = true,
+            LogDirection::Event(_) => {}
+        }
+    }
+    let activity = match (rx_recent, tx_recent) {
+        (true, true) => " ↕",
+        (true, false) => " ↓",
+        (false, true) => " ↑",
+        (false, false) => "",
+    };
+
+    format!("{display}/{tx}{activity}")
+}
+
+/// Shorten `text` to at most `max_width` columns, ending in `…` if cut
+fn truncate_to_width(text: &str, max_width: usize) -> String {
+    if display_width(text) <= max_width {
+        return text.to_string();
+    }
+    let mut out = String::new();
+    let mut width = 0;
+    for c in text.chars() {
+        let w = display_width(c.encode_utf8(&mut [0; 4]));
+        if width + w + 1 > max_width {
+            break;
+        }
+        out.push(c);
+        width += w;
+    }
+    if max_width > 0 {
+        out.push('…');
+    }
+    out
+}
+
 /// Render a compact tab bar (single line without border)
 pub fn draw_compact_tab_bar(f: &mut Frame, area: Rect, session_manager: &SessionManager) {
     draw_tab_bar(f, area, session_manager, false);
@@ -99,6 +191,8 @@ pub fn draw_tab_bar_with_controls(
 }
 
 /// Render session info overlay (for renaming, etc.)
+///
+/// `cursor_pos` is a char index into `session_name`.
 pub fn draw_session_info_overlay(
     f: &mut Frame,
     session_name: &str,
@@ -137,10 +231,7 @@ pub fn draw_session_info_overlay(
 
     if is_editing {
         // Show editable text with cursor
-        let mut text = session_name.to_string();
-        if cursor_pos <= text.len() {
-            text.insert(cursor_pos, '█');
-        }
+        let text = rename_text_with_cursor(session_name, cursor_pos);
 
         let paragraph = Paragraph::new(text).style(Style::default().fg(Color::Yellow));
         f.render_widget(paragraph, inner);
@@ -161,6 +252,13 @@ pub fn draw_session_info_overlay(
     }
 }
 
+/// Session name with a block cursor inserted at char index `cursor_pos`
+fn rename_text_with_cursor(session_name: &str, cursor_pos: usize) -> String {
+    TextInput::from(session_name)
+        .with_cursor(cursor_pos)
+        .with_cursor_marker('█')
+}
+
 /// Render pane borders with labels
 pub fn draw_pane_border(
     f: &mut Frame,
@@ -168,13 +266,29 @@ pub fn draw_pane_border(
     session_name: &str,
     is_focused: bool,
     is_connected: bool,
+) -> Rect {
+    draw_tagged_pane_border(f, area, session_name, is_focused, is_connected, None)
+}
+
+/// Render a pane border like [`draw_pane_border`], marked with the
+/// session's color tag
+///
+/// An unfocused pane's border takes the color tag; the focused pane keeps
+/// the cyan highlight and shows the tag as a swatch in its title.
+pub fn draw_tagged_pane_border(
+    f: &mut Frame,
+    area: Rect,
+    session_name: &str,
+    is_focused: bool,
+    is_connected: bool,
+    color_tag: Option<Color>,
 ) -> Rect {
     let border_style = if is_focused {
         Style::default()
             .fg(Color::Cyan)
             .add_modifier(Modifier::BOLD)
     } else {
-        Style::default().fg(Color::DarkGray)
+        Style::default().fg(color_tag.unwrap_or(Color::DarkGray))
     };
 
     let connection_indicator = if is_connected {
@@ -183,12 +297,12 @@ pub fn draw_pane_border(
         Span::styled("○ ", Style::default().fg(Color::DarkGray))
     };
 
-    let title = Line::from(vec![
-        Span::raw(" "),
-        connection_indicator,
-        Span::raw(session_name),
-        Span::raw(" "),
-    ]);
+    let mut title = vec![Span::raw(" "), connection_indicator];
+    if let Some(tag) = color_tag {
+        title.push(Span::styled(TAG_SWATCH, Style::default().fg(tag)));
+    }
+    title.extend([Span::raw(session_name), Span::raw(" ")]);
+    let title = Line::from(title);
 
     let block = Block::default()
         .borders(Borders::ALL)
@@ -348,4 +462,40 @@ mod tests {
         assert_eq!(get_tab_at_position(area, 30, 0, 4, 0), Some(1));
         assert_eq!(get_tab_at_position(area, 10, 5, 4, 0), None); // Outside area
     }
+
+    #[test]
+    fn test_session_markers() {
+        let now = Local::now();
+        let mut session = SerialSession::new(1, "S1".to_string());
+        assert_eq!(session_markers(&session, now), "H/A");
+
+        session.display_mode = DisplayMode::Text;
+        session.tx_mode = TxMode::Hex;
+        session.message_log.push_rx(vec![1]);
+        assert_eq!(session_markers(&session, now), "T/H ↓");
+
+        session.message_log.push_tx(vec![2]);
+        assert_eq!(session_markers(&session, now), "T/H ↕");
+
+        // Old traffic no longer counts as activity
+        let later = now + TimeDelta::seconds(10);
+        assert_eq!(session_markers(&session, later), "T/H");
+    }
+
+    #[test]
+    fn test_truncate_to_width() {
+        assert_eq!(truncate_to_width("Session 1", 20), "Session 1");
+        assert_eq!(truncate_to_width("Session 1", 5), "Sess…");
+        assert_eq!(truncate_to_width("串口调试", 5), "串口…");
+        assert_eq!(truncate_to_width("abc", 0), "");
+    }
+
+    #[test]
+    fn test_rename_cursor_multibyte() {
+        assert_eq!(rename_text_with_cursor("串口1", 0), "█串口1");
+        assert_eq!(rename_text_with_cursor("串口1", 1), "串█口1");
+        assert_eq!(rename_text_with_cursor("串口1", 3), "串口1█");
+        // Out-of-range cursor clamps to the end instead of panicking
+        assert_eq!(rename_text_with_cursor("串口", 9), "串口█");
+    }
 }
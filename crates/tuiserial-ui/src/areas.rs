@@ -17,6 +17,8 @@ pub struct UiAreas {
     pub parity: Rect,
     pub stop_bits: Rect,
     pub flow_control: Rect,
+    /// Collapsed frame settings line (compact config panel)
+    pub config_summary: Rect,
     pub status_panel: Rect,
     pub log_area: Rect,
     pub tx_area: Rect,
@@ -25,12 +27,55 @@ pub struct UiAreas {
     pub shortcuts_hint: Rect,
     pub tab_bar: Rect,
     pub plugin_modal: Rect,
+    /// Byte pad overlay, empty while closed
+    pub byte_pad: Rect,
     /// Native terminal cursor position (set during rendering, used after draw)
     pub cursor_x: u16,
     pub cursor_y: u16,
     pub show_cursor: bool,
 }
 
+impl UiAreas {
+    /// Clip every stored rectangle to `bounds` (the new terminal size), so
+    /// areas recorded before a resize never hit-test outside the screen.
+    /// A cursor left outside `bounds` is hidden.
+    pub fn clamp_to(&mut self, bounds: Rect) {
+        for rect in [
+            &mut self.menu_bar,
+            &mut self.port,
+            &mut self.baud_rate,
+            &mut self.data_bits,
+            &mut self.parity,
+            &mut self.stop_bits,
+            &mut self.flow_control,
+            &mut self.config_summary,
+            &mut self.status_panel,
+            &mut self.log_area,
+            &mut self.tx_area,
+            &mut self.control_area,
+            &mut self.notification_area,
+            &mut self.shortcuts_hint,
+            &mut self.tab_bar,
+            &mut self.plugin_modal,
+            &mut self.byte_pad,
+        ] {
+            *rect = clip_rect(*rect, bounds);
+        }
+        if !is_inside(bounds, self.cursor_x, self.cursor_y) {
+            self.show_cursor = false;
+        }
+    }
+}
+
+/// Intersection of `rect` with `bounds`, or an empty rect if they don't overlap
+fn clip_rect(rect: Rect, bounds: Rect) -> Rect {
+    if rect.intersects(bounds) {
+        rect.intersection(bounds)
+    } else {
+        Rect::default()
+    }
+}
+
 // Thread-local storage for UI areas (single-threaded terminal application)
 thread_local! {
     static UI_AREAS: RefCell<UiAreas> = RefCell::new(UiAreas::default());
@@ -42,11 +87,15 @@ pub fn get_ui_areas() -> UiAreas {
 }
 
 /// Update UI areas (called during rendering)
-#[allow(dead_code)]
 pub fn update_ui_areas(areas: UiAreas) {
     UI_AREAS.with(|a| *a.borrow_mut() = areas);
 }
 
+/// Clip the stored UI areas to a resized terminal of `width` x `height`
+pub fn clamp_ui_areas(width: u16, height: u16) {
+    UI_AREAS.with(|a| a.borrow_mut().clamp_to(Rect::new(0, 0, width, height)));
+}
+
 /// Update specific UI area field
 pub fn update_area(field: UiAreaField, rect: Rect) {
     UI_AREAS.with(|a| {
@@ -59,6 +108,7 @@ pub fn update_area(field: UiAreaField, rect: Rect) {
             UiAreaField::Parity => areas.parity = rect,
             UiAreaField::StopBits => areas.stop_bits = rect,
             UiAreaField::FlowControl => areas.flow_control = rect,
+            UiAreaField::ConfigSummary => areas.config_summary = rect,
             UiAreaField::StatusPanel => areas.status_panel = rect,
             UiAreaField::LogArea => areas.log_area = rect,
             UiAreaField::TxArea => areas.tx_area = rect,
@@ -67,6 +117,7 @@ pub fn update_area(field: UiAreaField, rect: Rect) {
             UiAreaField::ShortcutsHint => areas.shortcuts_hint = rect,
             UiAreaField::TabBar => areas.tab_bar = rect,
             UiAreaField::PluginModal => areas.plugin_modal = rect,
+            UiAreaField::BytePad => areas.byte_pad = rect,
         }
     });
 }
@@ -80,6 +131,7 @@ pub enum UiAreaField {
     Parity,
     StopBits,
     FlowControl,
+    ConfigSummary,
     StatusPanel,
     LogArea,
     TxArea,
@@ -89,6 +141,7 @@ pub enum UiAreaField {
     #[allow(dead_code)]
     TabBar,
     PluginModal,
+    BytePad,
 }
 
 /// Update terminal cursor position and visibility (called during rendering)
@@ -122,6 +175,9 @@ pub fn get_clicked_field(x: u16, y: u16) -> Option<FocusedField> {
         Some(FocusedField::StopBits)
     } else if is_inside(areas.flow_control, x, y) {
         Some(FocusedField::FlowControl)
+    } else if is_inside(areas.config_summary, x, y) {
+        // Focusing the first collapsed field expands the panel
+        Some(FocusedField::DataBits)
     } else if is_inside(areas.log_area, x, y) {
         Some(FocusedField::LogArea)
     } else if is_inside(areas.tx_area, x, y) {
@@ -184,3 +240,46 @@ pub fn get_clicked_tab(x: u16, y: u16, tab_count: usize) -> Option<usize> {
 
     Some(tab_index)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clamp_to_clips_and_drops_areas() {
+        let mut areas = UiAreas {
+            log_area: Rect::new(30, 2, 90, 30),
+            tx_area: Rect::new(30, 32, 90, 6),
+            cursor_x: 40,
+            cursor_y: 34,
+            show_cursor: true,
+            ..Default::default()
+        };
+        areas.clamp_to(Rect::new(0, 0, 80, 24));
+        assert_eq!(areas.log_area, Rect::new(30, 2, 50, 22));
+        assert_eq!(areas.tx_area, Rect::default());
+        assert!(!areas.show_cursor);
+    }
+
+    #[test]
+    fn test_clamp_ui_areas_stops_offscreen_hits() {
+        update_ui_areas(UiAreas {
+            tx_area: Rect::new(30, 32, 90, 6),
+            ..Default::default()
+        });
+        assert_eq!(get_clicked_field(40, 33), Some(FocusedField::TxInput));
+        clamp_ui_areas(80, 24);
+        assert_eq!(get_clicked_field(40, 33), None);
+    }
+
+    #[test]
+    fn test_config_summary_click_focuses_data_bits() {
+        update_ui_areas(UiAreas {
+            config_summary: Rect::new(0, 11, 42, 3),
+            ..Default::default()
+        });
+        assert_eq!(get_clicked_field(5, 12), Some(FocusedField::DataBits));
+        // The collapsed dropdowns have no area, so the click selects nothing
+        assert!(!is_inside(get_ui_areas().data_bits, 5, 12));
+    }
+}
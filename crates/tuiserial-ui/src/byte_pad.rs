@@ -0,0 +1,147 @@
+//! Byte pad overlay — a 16x16 grid of the byte values 00–FF. Clicking a
+//! cell sends that single byte; the last one sent is highlighted.
+
+use ratatui::{
+    Frame,
+    layout::{Alignment, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+use rust_i18n::t;
+use tuiserial_core::BytePad;
+
+use crate::areas::{UiAreaField, is_inside, update_area};
+
+/// Columns taken by the row label ("0_ ")
+const LABEL_WIDTH: u16 = 3;
+/// Columns per cell ("41 ")
+const CELL_WIDTH: u16 = 3;
+/// Overlay size: border, label and 16 cells wide; border, column header,
+/// 16 rows and the hint line high
+const PAD_WIDTH: u16 = 2 + LABEL_WIDTH + 16 * CELL_WIDTH;
+const PAD_HEIGHT: u16 = 2 + 1 + 16 + 1;
+
+/// Draw the grid anchored to the top right of `area` and record its area
+/// for mouse hit-testing
+pub fn draw_byte_pad(f: &mut Frame, area: Rect, pad: &BytePad) {
+    let width = PAD_WIDTH.min(area.width);
+    let height = PAD_HEIGHT.min(area.height);
+    let overlay = Rect {
+        x: area.x + area.width.saturating_sub(width),
+        y: area.y,
+        width,
+        height,
+    };
+    update_area(UiAreaField::BytePad, overlay);
+
+    f.render_widget(Clear, overlay);
+
+    let label = Style::default().fg(Color::Cyan);
+    let mut header = vec![Span::raw(" ".repeat(LABEL_WIDTH as usize))];
+    header.extend((0..16).map(|col| Span::styled(format!("_{col:X} "), label)));
+    let mut lines = vec![Line::from(header)];
+
+    for row in 0..16u8 {
+        let mut spans = vec![Span::styled(format!("{row:X}_ "), label)];
+        for col in 0..16u8 {
+            let byte = row * 16 + col;
+            let style = if pad.last_sent == Some(byte) {
+                Style::default()
+                    .fg(Color::Black)
+                    .bg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD)
+            } else if byte.is_ascii_graphic() {
+                Style::default().fg(Color::White)
+            } else {
+                Style::default().fg(Color::DarkGray)
+            };
+            spans.push(Span::styled(format!("{byte:02X}"), style));
+            spans.push(Span::raw(" "));
+        }
+        lines.push(Line::from(spans));
+    }
+
+    lines.push(Line::from(Span::styled(
+        format!(" {}", t!("byte_pad.hint")),
+        Style::default().fg(Color::DarkGray),
+    )));
+
+    let para = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan))
+            .title(format!(" {} ", t!("byte_pad.title")))
+            .title_alignment(Alignment::Left)
+            .style(Style::default().bg(Color::Black)),
+    );
+
+    f.render_widget(para, overlay);
+}
+
+/// Byte under (`x`, `y`) in a byte pad drawn at `pad`, `None` outside the
+/// cells (border, labels, the gap after each cell, the hint line)
+pub fn byte_pad_cell(pad: Rect, x: u16, y: u16) -> Option<u8> {
+    if !is_inside(pad, x, y) {
+        return None;
+    }
+    let grid_x = pad.x + 1 + LABEL_WIDTH;
+    let grid_y = pad.y + 2;
+    let col = x.checked_sub(grid_x)?;
+    let row = y.checked_sub(grid_y)?;
+    if col % CELL_WIDTH == CELL_WIDTH - 1 {
+        return None;
+    }
+    let col = col / CELL_WIDTH;
+    (col < 16 && row < 16).then(|| (row * 16 + col) as u8)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAD: Rect = Rect {
+        x: 10,
+        y: 5,
+        width: PAD_WIDTH,
+        height: PAD_HEIGHT,
+    };
+
+    /// Screen position of the first character of `byte`'s cell
+    fn cell_origin(byte: u8) -> (u16, u16) {
+        let (row, col) = (u16::from(byte / 16), u16::from(byte % 16));
+        (PAD.x + 1 + LABEL_WIDTH + col * CELL_WIDTH, PAD.y + 2 + row)
+    }
+
+    #[test]
+    fn test_every_cell_maps_to_its_byte() {
+        for byte in 0..=255u8 {
+            let (x, y) = cell_origin(byte);
+            assert_eq!(byte_pad_cell(PAD, x, y), Some(byte));
+            assert_eq!(byte_pad_cell(PAD, x + 1, y), Some(byte));
+        }
+    }
+
+    #[test]
+    fn test_corners() {
+        assert_eq!(byte_pad_cell(PAD, 14, 7), Some(0x00));
+        assert_eq!(byte_pad_cell(PAD, 59, 7), Some(0x0F));
+        assert_eq!(byte_pad_cell(PAD, 14, 22), Some(0xF0));
+        assert_eq!(byte_pad_cell(PAD, 60, 22), Some(0xFF));
+    }
+
+    #[test]
+    fn test_outside_cells() {
+        let (x, y) = cell_origin(0x00);
+        // Border, column header, row label, gap between cells
+        assert_eq!(byte_pad_cell(PAD, PAD.x, y), None);
+        assert_eq!(byte_pad_cell(PAD, x, PAD.y + 1), None);
+        assert_eq!(byte_pad_cell(PAD, x - 1, y), None);
+        assert_eq!(byte_pad_cell(PAD, x + 2, y), None);
+        // Hint line, right border, outside the overlay
+        assert_eq!(byte_pad_cell(PAD, x, PAD.y + PAD_HEIGHT - 2), None);
+        assert_eq!(byte_pad_cell(PAD, PAD.x + PAD_WIDTH - 1, y), None);
+        assert_eq!(byte_pad_cell(PAD, PAD.x + PAD_WIDTH, y), None);
+        assert_eq!(byte_pad_cell(Rect::default(), 0, 0), None);
+    }
+}
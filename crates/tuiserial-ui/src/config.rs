@@ -5,7 +5,7 @@
 
 use ratatui::{
     Frame,
-    layout::Rect,
+    layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     widgets::{Block, Borders, List, ListItem, Paragraph},
 };
@@ -14,6 +14,110 @@ use tuiserial_core::{AppState, FlowControl, FocusedField, Language, MenuState, P
 
 use crate::areas::{UiAreaField, update_area};
 
+/// Where the config panel's widgets go
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConfigPanelLayout {
+    pub port: Rect,
+    pub baud_rate: Rect,
+    pub frame: FrameFields,
+    pub status: Rect,
+}
+
+/// Data bits, parity, stop bits and flow control: one dropdown each, or a
+/// single summary line in compact mode
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrameFields {
+    Expanded([Rect; 4]),
+    Collapsed(Rect),
+}
+
+impl ConfigPanelLayout {
+    /// Split `area`; when `collapsed`, the rows freed by the summary line go
+    /// to the port and baud rate lists
+    pub fn new(area: Rect, collapsed: bool) -> Self {
+        if collapsed {
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([
+                    Constraint::Length(9),  // Port
+                    Constraint::Length(10), // Baud rate
+                    Constraint::Length(3),  // Frame summary
+                    Constraint::Min(10),    // Status panel
+                ])
+                .split(area);
+            Self {
+                port: chunks[0],
+                baud_rate: chunks[1],
+                frame: FrameFields::Collapsed(chunks[2]),
+                status: chunks[3],
+            }
+        } else {
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([
+                    Constraint::Length(5), // Port
+                    Constraint::Length(5), // Baud rate
+                    Constraint::Length(3), // Data bits
+                    Constraint::Length(3), // Parity
+                    Constraint::Length(3), // Stop bits
+                    Constraint::Length(3), // Flow control
+                    Constraint::Min(10),   // Status panel
+                ])
+                .split(area);
+            Self {
+                port: chunks[0],
+                baud_rate: chunks[1],
+                frame: FrameFields::Expanded([chunks[2], chunks[3], chunks[4], chunks[5]]),
+                status: chunks[6],
+            }
+        }
+    }
+}
+
+/// Summary of the collapsed frame settings, e.g. `8-N-1, no flow`
+pub fn config_summary_text(app: &AppState) -> String {
+    let flow = match app.config.flow_control {
+        FlowControl::None => t!("flow.short_none"),
+        FlowControl::Hardware => t!("flow.short_hardware"),
+        FlowControl::Software => t!("flow.short_software"),
+    };
+    format!("{}, {}", app.config.frame_format(), flow)
+}
+
+/// Draw the collapsed frame settings line (compact config panel)
+pub fn draw_config_summary(f: &mut Frame, app: &AppState, area: Rect) {
+    // The summary stands in for all four dropdowns; clicking it focuses
+    // data bits, which expands the panel
+    update_area(UiAreaField::ConfigSummary, area);
+    for field in [
+        UiAreaField::DataBits,
+        UiAreaField::Parity,
+        UiAreaField::StopBits,
+        UiAreaField::FlowControl,
+    ] {
+        update_area(field, Rect::default());
+    }
+
+    let title = if app.config_locked {
+        format!(" {} [{}] ", t!("label.frame"), t!("label.locked"))
+    } else {
+        format!(" {} [{}] ", t!("label.frame"), t!("hint.expand"))
+    };
+    let style = if app.config_locked {
+        Style::default().fg(Color::DarkGray)
+    } else {
+        Style::default()
+    };
+
+    let para = Paragraph::new(config_summary_text(app)).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(title)
+            .border_style(style),
+    );
+    f.render_widget(para, area);
+}
+
 /// Draw the serial port selection dropdown
 pub fn draw_port_dropdown(f: &mut Frame, app: &AppState, area: Rect) {
     // Store area for mouse interaction
@@ -376,3 +480,41 @@ pub fn draw_flow_control_dropdown(f: &mut Frame, app: &AppState, area: Rect) {
 
     f.render_stateful_widget(list, area, &mut app.flow_control_state.clone());
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_layout_expanded_and_collapsed() {
+        let area = Rect::new(0, 1, 42, 40);
+
+        let expanded = ConfigPanelLayout::new(area, false);
+        assert_eq!(expanded.port.height, 5);
+        assert_eq!(expanded.baud_rate.height, 5);
+        let FrameFields::Expanded(fields) = expanded.frame else {
+            panic!("expected expanded frame fields");
+        };
+        assert!(fields.iter().all(|r| r.height == 3));
+        assert_eq!(fields[3].bottom(), expanded.status.y);
+
+        let collapsed = ConfigPanelLayout::new(area, true);
+        assert_eq!(
+            collapsed.frame,
+            FrameFields::Collapsed(Rect::new(0, 20, 42, 3))
+        );
+        // The rows saved by collapsing go to the port and baud rate lists
+        assert!(collapsed.port.height > expanded.port.height);
+        assert!(collapsed.baud_rate.height > expanded.baud_rate.height);
+        assert_eq!(collapsed.status, expanded.status);
+    }
+
+    #[test]
+    fn test_summary_text() {
+        let mut app = AppState::default();
+        assert_eq!(config_summary_text(&app), "8-N-1, no flow");
+        app.config.parity = Parity::Even;
+        app.config.flow_control = FlowControl::Hardware;
+        assert_eq!(config_summary_text(&app), "8-E-1, RTS/CTS");
+    }
+}
@@ -0,0 +1,108 @@
+//! Export dialog — chooses the format, which entries to write and whether
+//! to compress before exporting the log.
+
+use ratatui::{
+    Frame,
+    layout::{Alignment, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+use rust_i18n::t;
+use tuiserial_core::{ExportDialog, ExportDialogField, LogDirection};
+
+/// Draw the export options dialog centered on screen
+pub fn draw_export_dialog(f: &mut Frame, dialog: &ExportDialog) {
+    let area = f.area();
+
+    let width = 56.min(area.width.saturating_sub(4));
+    let height = 9.min(area.height.saturating_sub(2));
+    let dialog_area = Rect {
+        x: (area.width.saturating_sub(width)) / 2,
+        y: (area.height.saturating_sub(height)) / 2,
+        width,
+        height,
+    };
+
+    f.render_widget(Clear, dialog_area);
+
+    let direction = match dialog.filter.direction {
+        None => t!("export.both"),
+        Some(LogDirection::Rx) => t!("export.rx_only"),
+        Some(_) => t!("export.tx_only"),
+    };
+    let scope = if dialog.filter.only_matching {
+        t!("export.scope_matching")
+    } else {
+        t!("export.scope_all")
+    };
+    let compression = if dialog.gzip {
+        t!("export.compression_gzip")
+    } else {
+        t!("export.compression_none")
+    };
+
+    let lines = vec![
+        option_line(
+            t!("export.format").to_string(),
+            dialog.format.to_string().to_uppercase(),
+            dialog.field == ExportDialogField::Format,
+        ),
+        option_line(
+            t!("export.direction").to_string(),
+            direction.to_string(),
+            dialog.field == ExportDialogField::Direction,
+        ),
+        option_line(
+            t!("export.scope").to_string(),
+            scope.to_string(),
+            dialog.field == ExportDialogField::Scope,
+        ),
+        option_line(
+            t!("export.compression").to_string(),
+            compression.to_string(),
+            dialog.field == ExportDialogField::Compression,
+        ),
+        Line::from(""),
+        Line::from(Span::styled(
+            t!("export.hint"),
+            Style::default().fg(Color::DarkGray),
+        )),
+    ];
+
+    let para = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan))
+            .title(format!(" {} ", t!("export.title")))
+            .title_alignment(Alignment::Left)
+            .style(Style::default().bg(Color::Black)),
+    );
+
+    f.render_widget(para, dialog_area);
+}
+
+fn option_line(label: String, value: String, focused: bool) -> Line<'static> {
+    let value_style = if focused {
+        Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(Color::White)
+    };
+    let (open, close) = if focused {
+        ("◀ ", " ▶")
+    } else {
+        ("  ", "  ")
+    };
+
+    Line::from(vec![
+        Span::styled(
+            format!(" {:<12}", format!("{}:", label)),
+            Style::default().fg(Color::Cyan),
+        ),
+        Span::styled(open, Style::default().fg(Color::Yellow)),
+        Span::styled(value, value_style),
+        Span::styled(close, Style::default().fg(Color::Yellow)),
+    ])
+}
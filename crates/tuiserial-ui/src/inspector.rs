@@ -0,0 +1,169 @@
+//! Byte inspector overlay — shows a 2/4-byte window of the focused log
+//! entry decoded as an integer in both byte orders.
+
+use ratatui::{
+    Frame,
+    layout::{Alignment, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+use rust_i18n::t;
+use tuiserial_core::{ByteInspector, EndianReadout, LogDirection, LogEntry};
+
+/// Bytes shown on each side of the window in the context row
+const CONTEXT_BYTES: usize = 4;
+
+/// Draw the inspector for `entry` anchored to the bottom of `area` (the log)
+pub fn draw_byte_inspector(f: &mut Frame, area: Rect, entry: &LogEntry, inspector: &ByteInspector) {
+    let width = 56.min(area.width);
+    let height = 8.min(area.height);
+    let overlay = Rect {
+        x: area.x + area.width.saturating_sub(width),
+        y: area.y + area.height.saturating_sub(height),
+        width,
+        height,
+    };
+
+    f.render_widget(Clear, overlay);
+
+    let direction = match entry.direction {
+        LogDirection::Rx => "RX",
+        LogDirection::Tx => "TX",
+        LogDirection::Event(_) => "--",
+    };
+    let offset = inspector.clamped_offset(entry.data.len());
+
+    let mut lines = vec![
+        Line::from(Span::styled(
+            format!(
+                " {} #{}  {}  {} B  {} {}",
+                t!("inspector.entry"),
+                entry.seq,
+                direction,
+                entry.data.len(),
+                t!("inspector.offset"),
+                offset
+            ),
+            Style::default().fg(Color::DarkGray),
+        )),
+        context_line(&entry.data, offset, inspector.width.bytes()),
+    ];
+
+    match inspector
+        .window(&entry.data)
+        .and_then(EndianReadout::decode)
+    {
+        Some(readout) => lines.extend(readout_lines(&readout)),
+        None => lines.push(Line::from(Span::styled(
+            format!(" {}", t!("inspector.too_short")),
+            Style::default().fg(Color::Yellow),
+        ))),
+    }
+
+    lines.push(Line::from(Span::styled(
+        format!(" {}", t!("inspector.hint")),
+        Style::default().fg(Color::DarkGray),
+    )));
+
+    let para = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan))
+            .title(format!(" {} ", t!("inspector.title")))
+            .title_alignment(Alignment::Left)
+            .style(Style::default().bg(Color::Black)),
+    );
+
+    f.render_widget(para, overlay);
+}
+
+/// Hex bytes around the window, with the window highlighted
+fn context_line(data: &[u8], offset: usize, width: usize) -> Line<'static> {
+    let start = offset.saturating_sub(CONTEXT_BYTES);
+    let end = (offset + width + CONTEXT_BYTES).min(data.len());
+
+    let mut spans = vec![Span::raw(if start > 0 { " …" } else { "  " })];
+    for (i, byte) in data.iter().enumerate().take(end).skip(start) {
+        let style = if (offset..offset + width).contains(&i) {
+            Style::default()
+                .fg(Color::Black)
+                .bg(Color::Yellow)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::White)
+        };
+        spans.push(Span::raw(" "));
+        spans.push(Span::styled(format!("{:02X}", byte), style));
+    }
+    if end < data.len() {
+        spans.push(Span::raw(" …"));
+    }
+    Line::from(spans)
+}
+
+/// Unsigned and signed rows, little- and big-endian side by side
+fn readout_lines(readout: &EndianReadout) -> Vec<Line<'static>> {
+    let bits = readout.width * 8;
+    let hex_digits = readout.width * 2;
+    let label = Style::default().fg(Color::Cyan);
+    let value = Style::default().fg(Color::White);
+
+    vec![
+        Line::from(vec![
+            Span::styled(format!(" u{:<3}", bits), label),
+            Span::styled(" LE ", label),
+            Span::styled(
+                format!("{:<11} 0x{:0w$X}", readout.le, readout.le, w = hex_digits),
+                value,
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled(format!(" u{:<3}", bits), label),
+            Span::styled(" BE ", label),
+            Span::styled(
+                format!("{:<11} 0x{:0w$X}", readout.be, readout.be, w = hex_digits),
+                value,
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled(format!(" i{:<3}", bits), label),
+            Span::styled(" LE ", label),
+            Span::styled(format!("{:<11}", readout.le_signed()), value),
+            Span::styled(" BE ", label),
+            Span::styled(readout.be_signed().to_string(), value),
+        ]),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(line: &Line) -> String {
+        line.spans.iter().map(|s| s.content.to_string()).collect()
+    }
+
+    #[test]
+    fn test_context_line_highlights_window() {
+        let data: Vec<u8> = (0..20).collect();
+        let line = context_line(&data, 10, 2);
+        assert_eq!(text(&line), " … 06 07 08 09 0A 0B 0C 0D 0E 0F …");
+        let highlighted: Vec<String> = line
+            .spans
+            .iter()
+            .filter(|s| s.style.bg == Some(Color::Yellow))
+            .map(|s| s.content.to_string())
+            .collect();
+        assert_eq!(highlighted, vec!["0A", "0B"]);
+    }
+
+    #[test]
+    fn test_readout_lines_both_orders() {
+        let readout = EndianReadout::decode(&[0xFF, 0xFE]).unwrap();
+        let lines: Vec<String> = readout_lines(&readout).iter().map(text).collect();
+        assert!(lines[0].contains("LE 65279") && lines[0].contains("0xFEFF"));
+        assert!(lines[1].contains("BE 65534") && lines[1].contains("0xFFFE"));
+        assert!(lines[2].contains("-257") && lines[2].contains("-2"));
+    }
+}
@@ -13,6 +13,12 @@
 //! - `log`: Log area showing serial communication data
 //! - `tx`: Transmission input area
 //! - `notification`: Notification bar for user messages
+//! - `export_dialog`: Format/filter options shown before exporting the log
+//! - `repeat_prompt`: Count/interval prompt for bounded repeat sends
+//! - `send_confirm`: Confirmation shown before sending a large payload
+//! - `inspector`: Byte inspector overlay for the focused log entry
+//! - `byte_pad`: Clickable 00–FF grid for sending single bytes
+//! - `timing`: RX read timing overlay for the focused log entry
 //! - `utils`: Utility functions for UI rendering
 
 use ratatui::{
@@ -23,15 +29,22 @@ use tuiserial_core::{AppState, MenuState};
 
 // Module declarations
 mod areas;
+mod byte_pad;
 mod config;
+mod export_dialog;
+mod inspector;
 mod log;
 mod menu;
 mod mouse;
 mod notification;
 mod plugin_modal;
 mod plugin_registry;
+mod quit_confirm;
+mod repeat_prompt;
+mod send_confirm;
 mod shortcuts;
 mod status;
+mod timing;
 mod tx;
 mod utils;
 
@@ -41,10 +54,12 @@ i18n!("../../locales");
 
 // Re-exports for external use
 pub use areas::{
-    UiAreas, get_clicked_field, get_clicked_menu, get_clicked_tab, get_ui_areas, is_inside,
-    is_shortcuts_hint_clicked, update_cursor_state,
+    UiAreas, clamp_ui_areas, get_clicked_field, get_clicked_menu, get_clicked_tab, get_ui_areas,
+    is_inside, is_shortcuts_hint_clicked, update_cursor_state, update_ui_areas,
 };
+pub use byte_pad::byte_pad_cell;
 pub use crossterm;
+pub use log::{log_layout, log_viewport_lines};
 pub use mouse::{
     CursorType, MouseAction, ScrollAction, ScrollDirection, calculate_dropdown_area,
     get_cursor_type, get_hover_style, handle_mouse_click, handle_mouse_hover, handle_mouse_scroll,
@@ -54,7 +69,7 @@ pub use ratatui;
 pub use shortcuts::{draw_context_shortcuts, draw_shortcuts_help, draw_shortcuts_hint};
 
 // Re-export menu functions
-pub use menu::find_clicked_menu;
+pub use menu::{find_clicked_menu, menu_dropdown_layout};
 
 /// Main draw function - renders the entire application UI
 ///
@@ -79,7 +94,7 @@ pub fn draw(f: &mut Frame, app: &AppState) {
     notification::draw_notification_bar(f, app, chunks[2]);
 
     // Render shortcuts hint bar
-    shortcuts::draw_shortcuts_hint(f, chunks[3]);
+    shortcuts::draw_shortcuts_hint(f, chunks[3], &app.key_bindings);
 
     // Render menu bar (without dropdown)
     menu::draw_menu_bar(f, app, chunks[0]);
@@ -91,7 +106,7 @@ pub fn draw(f: &mut Frame, app: &AppState) {
 
     // Render shortcuts help overlay if active (on top of everything)
     if app.show_shortcuts_help {
-        shortcuts::draw_shortcuts_help(f);
+        shortcuts::draw_shortcuts_help(f, &app.key_bindings);
     }
 
     // Render plugin manager modal if active (on top of everything)
@@ -99,6 +114,26 @@ pub fn draw(f: &mut Frame, app: &AppState) {
         plugin_modal::draw_plugin_modal(f, app);
     }
 
+    // Render repeat send prompt if active
+    if let Some(prompt) = &app.repeat_prompt {
+        repeat_prompt::draw_repeat_prompt(f, prompt);
+    }
+
+    // Render export options dialog if active
+    if let Some(dialog) = &app.export_dialog {
+        export_dialog::draw_export_dialog(f, dialog);
+    }
+
+    // Render large send confirmation if active
+    if let Some(pending) = &app.pending_send {
+        send_confirm::draw_send_confirm(f, pending, app.settings.large_send_threshold);
+    }
+
+    // Render quit confirmation if active
+    if app.quit_prompt {
+        quit_confirm::draw_quit_confirm(f, app);
+    }
+
     // Store menu bar, notification area, and shortcuts hint for mouse interaction
     areas::update_area(areas::UiAreaField::MenuBar, chunks[0]);
     areas::update_area(areas::UiAreaField::NotificationArea, chunks[2]);
@@ -118,26 +153,23 @@ fn draw_main_content(f: &mut Frame, app: &AppState, area: Rect) {
 
 /// Draw the configuration panel on the left
 fn draw_config_panel(f: &mut Frame, app: &AppState, area: Rect) {
-    let chunks = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Length(5), // Port
-            Constraint::Length(5), // Baud rate
-            Constraint::Length(3), // Data bits
-            Constraint::Length(3), // Parity
-            Constraint::Length(3), // Stop bits
-            Constraint::Length(3), // Flow control
-            Constraint::Min(10),   // Status panel
-        ])
-        .split(area);
-
-    config::draw_port_dropdown(f, app, chunks[0]);
-    config::draw_baud_rate_dropdown(f, app, chunks[1]);
-    config::draw_data_bits_dropdown(f, app, chunks[2]);
-    config::draw_parity_dropdown(f, app, chunks[3]);
-    config::draw_stop_bits_dropdown(f, app, chunks[4]);
-    config::draw_flow_control_dropdown(f, app, chunks[5]);
-    status::draw_status_panel(f, app, chunks[6]);
+    let layout = config::ConfigPanelLayout::new(area, app.config_collapsed());
+
+    config::draw_port_dropdown(f, app, layout.port);
+    config::draw_baud_rate_dropdown(f, app, layout.baud_rate);
+    match layout.frame {
+        config::FrameFields::Expanded([data_bits, parity, stop_bits, flow_control]) => {
+            areas::update_area(areas::UiAreaField::ConfigSummary, Rect::default());
+            config::draw_data_bits_dropdown(f, app, data_bits);
+            config::draw_parity_dropdown(f, app, parity);
+            config::draw_stop_bits_dropdown(f, app, stop_bits);
+            config::draw_flow_control_dropdown(f, app, flow_control);
+        }
+        config::FrameFields::Collapsed(summary) => {
+            config::draw_config_summary(f, app, summary);
+        }
+    }
+    status::draw_status_panel(f, app, layout.status);
 }
 
 /// Draw the main area on the right (log + tx + control)
@@ -151,7 +183,29 @@ fn draw_main_area(f: &mut Frame, app: &AppState, area: Rect) {
         ])
         .split(area);
 
-    log::draw_log_area(f, app, chunks[0]);
+    let log_area = match app.dual_view {
+        Some(view) => {
+            let panes = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+                .split(chunks[0]);
+            log::draw_secondary_log_area(f, app, panes[1], view);
+            panes[0]
+        }
+        None => chunks[0],
+    };
+    log::draw_log_area(f, app, log_area);
+    if let Some(inspector) = &app.byte_inspector
+        && let Some(entry) = app.focused_entry()
+    {
+        inspector::draw_byte_inspector(f, log_area, entry, inspector);
+    } else if app.show_rx_timing {
+        timing::draw_rx_timing(f, log_area, &app.focused_rx_timing(timing::TIMING_ROWS));
+    }
+    match &app.byte_pad {
+        Some(pad) => byte_pad::draw_byte_pad(f, log_area, pad),
+        None => areas::update_area(areas::UiAreaField::BytePad, Rect::default()),
+    }
     tx::draw_tx_area(f, app, chunks[1]);
     status::draw_control_area(f, app, chunks[2]);
 }
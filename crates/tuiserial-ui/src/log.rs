@@ -10,29 +10,143 @@ use ratatui::{
     widgets::{Block, Borders, Paragraph},
 };
 use rust_i18n::t;
-use tuiserial_core::{AppState, DisplayMode, FocusedField, LogDirection};
-use tuiserial_serial::{bytes_to_hex, bytes_to_string};
+use std::borrow::Cow;
+use std::time::Duration;
+use tuiserial_core::{
+    AppState, DisplayMode, DisplayRenderer, FocusedField, LogColumn, LogColumns, LogDirection,
+    LogEntry, LogEventKind, LogLayout, LogView,
+};
+use tuiserial_serial::{bytes_to_control_pictures, bytes_to_hex, bytes_to_string, display_bytes};
 
 use crate::areas::{UiAreaField, update_area};
 
+/// Built-in HEX display mode
+pub struct HexRenderer;
+
+impl DisplayRenderer for HexRenderer {
+    fn render(&self, entry: &LogEntry) -> Vec<Line<'static>> {
+        vec![data_line(bytes_to_hex(&entry.data))]
+    }
+}
+
+/// Built-in TEXT display mode
+pub struct TextRenderer;
+
+impl DisplayRenderer for TextRenderer {
+    fn render(&self, entry: &LogEntry) -> Vec<Line<'static>> {
+        vec![data_line(bytes_to_string(&entry.data))]
+    }
+}
+
+/// TEXT display mode with control bytes shown as control pictures
+pub struct ControlPictureRenderer;
+
+impl DisplayRenderer for ControlPictureRenderer {
+    fn render(&self, entry: &LogEntry) -> Vec<Line<'static>> {
+        vec![data_line(bytes_to_control_pictures(&entry.data))]
+    }
+}
+
+fn data_line(data: String) -> Line<'static> {
+    Line::from(Span::styled(data, Style::default().fg(Color::White)))
+}
+
+/// Renderer for a built-in display mode; text uses control pictures when
+/// `control_pictures` is set
+pub fn builtin_renderer(mode: DisplayMode, control_pictures: bool) -> &'static dyn DisplayRenderer {
+    match mode {
+        DisplayMode::Hex => &HexRenderer,
+        DisplayMode::Text if control_pictures => &ControlPictureRenderer,
+        DisplayMode::Text => &TextRenderer,
+    }
+}
+
+/// `entry` with the bit order and 7-bit display settings applied to its
+/// data; borrowed unchanged when both are off, so only entries that need a
+/// transform allocate
+fn displayed_entry<'a>(app: &AppState, entry: &'a LogEntry) -> Cow<'a, LogEntry> {
+    let (lsb_first, mask_high_bit) = (app.settings.lsb_first, app.settings.mask_high_bit);
+    if !lsb_first && !mask_high_bit {
+        return Cow::Borrowed(entry);
+    }
+    // Built field by field so the original data is not copied first
+    Cow::Owned(LogEntry {
+        timestamp: entry.timestamp,
+        direction: entry.direction,
+        data: display_bytes(&entry.data, lsb_first, mask_high_bit),
+        seq: entry.seq,
+        tx_mode: entry.tx_mode,
+    })
+}
+
+/// Title label of `view`'s display mode: the custom renderer's name or the
+/// built-in mode, followed by any active bit transforms
+fn display_mode_label(app: &AppState, view: LogView) -> String {
+    let mut label = match view
+        .custom_renderer
+        .and_then(|i| app.custom_renderers.get(i))
+    {
+        Some(custom) => custom.name.clone(),
+        None => match view.display_mode {
+            DisplayMode::Hex => t!("display.hex").to_string(),
+            DisplayMode::Text => t!("display.text").to_string(),
+        },
+    };
+    if app.settings.lsb_first {
+        label.push_str(&format!(", {}", t!("display.lsb_first")));
+    }
+    if app.settings.mask_high_bit {
+        label.push_str(&format!(", {}", t!("display.seven_bit")));
+    }
+    if app.settings.control_pictures
+        && view.custom_renderer.is_none()
+        && view.display_mode == DisplayMode::Text
+    {
+        label.push_str(&format!(", {}", t!("display.control_pictures")));
+    }
+    label
+}
+
+/// Rows of log content visible in `area` (inside the border, at least one)
+pub fn log_viewport_lines(area: Rect) -> u16 {
+    area.height.saturating_sub(2).max(1)
+}
+
 /// Draw the log area showing received and transmitted data
 pub fn draw_log_area(f: &mut Frame, app: &AppState, area: Rect) {
     // Store area for mouse interaction
     update_area(UiAreaField::LogArea, area);
 
     let focused = app.focused_field == FocusedField::LogArea;
+    draw_log_view(f, app, area, app.primary_view(), focused, 'x');
+}
 
+/// Draw the second view of the dual log view: the same entries and scroll
+/// position as the main view, rendered with `view`'s settings
+pub fn draw_secondary_log_area(f: &mut Frame, app: &AppState, area: Rect, view: LogView) {
+    draw_log_view(f, app, area, view, false, 'X');
+}
+
+/// Draw the log with `view`'s settings; `toggle_key` switches its display mode
+fn draw_log_view(
+    f: &mut Frame,
+    app: &AppState,
+    area: Rect,
+    view: LogView,
+    focused: bool,
+    toggle_key: char,
+) {
     if app.message_log.entries.is_empty() {
-        draw_empty_log(f, app, area, focused);
+        draw_empty_log(f, app, area, view, focused);
         return;
     }
 
-    draw_log_entries(f, app, area, focused);
+    draw_log_entries(f, app, area, view, focused, toggle_key);
 }
 
 /// Draw empty log area with help text
-fn draw_empty_log(f: &mut Frame, app: &AppState, area: Rect, focused: bool) {
-    let status_msg = if app.is_connected {
+fn draw_empty_log(f: &mut Frame, app: &AppState, area: Rect, view: LogView, focused: bool) {
+    let status_msg = if app.is_connected() {
         t!("empty.connect_hint")
     } else {
         t!("status.not_connected")
@@ -44,7 +158,7 @@ fn draw_empty_log(f: &mut Frame, app: &AppState, area: Rect, focused: bool) {
         Line::from(Span::styled(
             status_msg,
             Style::default()
-                .fg(if app.is_connected {
+                .fg(if app.is_connected() {
                     Color::Cyan
                 } else {
                     Color::Yellow
@@ -77,10 +191,7 @@ fn draw_empty_log(f: &mut Frame, app: &AppState, area: Rect, focused: bool) {
                 .title(format!(
                     " {} - {} ",
                     t!("label.message"),
-                    match app.display_mode {
-                        DisplayMode::Hex => t!("display.hex"),
-                        DisplayMode::Text => t!("display.text"),
-                    }
+                    display_mode_label(app, view)
                 ))
                 .title_alignment(Alignment::Left),
         )
@@ -89,67 +200,125 @@ fn draw_empty_log(f: &mut Frame, app: &AppState, area: Rect, focused: bool) {
     f.render_widget(para, area);
 }
 
-/// Draw log entries
-fn draw_log_entries(f: &mut Frame, app: &AppState, area: Rect, focused: bool) {
+/// Row layout of `view`: how many screen rows each visible entry takes
+pub fn log_layout(app: &AppState, view: LogView) -> LogLayout {
+    log_lines(app, view).1
+}
+
+/// Rows of `view` for the visible entries, with the layout mapping entries
+/// to them. Scroll limits and hit-testing use the same layout so they
+/// always agree with what is drawn.
+fn log_lines(app: &AppState, view: LogView) -> (Vec<Line<'static>>, LogLayout) {
     let mut lines: Vec<Line> = Vec::new();
+    let mut layout = LogLayout::default();
+    let inspected = app.byte_inspector.and_then(|_| app.focused_entry_index());
+    let custom = view
+        .custom_renderer
+        .and_then(|i| app.custom_renderers.get(i))
+        .map(|named| named.renderer.as_ref());
 
-    for entry in app.message_log.entries.iter() {
-        let (time_color, dir_str, dir_icon) = match entry.direction {
-            LogDirection::Rx => (Color::Cyan, t!("label.rx_count"), "<"),
-            LogDirection::Tx => (Color::Green, t!("label.tx_count"), ">"),
-        };
+    let gap_threshold = app
+        .settings
+        .show_idle_gaps
+        .then_some(app.settings.idle_gap_ms);
+    let mut previous: Option<&LogEntry> = None;
 
-        let time_str = entry.timestamp.format("%H:%M:%S%.3f").to_string();
-        let data_len = entry.data.len();
+    let selection = app.log_selection;
+    let selection_style = Style::default().bg(Color::Blue);
+    let select = |line: Line<'static>, selected: bool| {
+        if selected {
+            line.style(selection_style)
+        } else {
+            line
+        }
+    };
 
-        let data_str = match app.display_mode {
-            DisplayMode::Hex => bytes_to_hex(&entry.data),
-            DisplayMode::Text => bytes_to_string(&entry.data),
+    for idx in app.visible_entry_indices() {
+        let entry = &app.message_log.entries[idx];
+        let first_row = lines.len();
+        let is_selected = selection.is_some_and(|s| s.contains(entry.seq));
+        if let (Some(threshold_ms), Some(prev)) = (gap_threshold, previous)
+            && let Some(gap) = idle_gap(prev, entry, threshold_ms)
+        {
+            // Inside the selection when the entries on both sides are
+            let gap_selected = is_selected && selection.is_some_and(|s| s.contains(prev.seq));
+            lines.push(select(build_gap_line(gap), gap_selected));
+        }
+        previous = Some(entry);
+
+        if let Some(kind) = entry.event_kind() {
+            let line = build_event_line(entry, kind, &app.settings.log_columns);
+            lines.push(select(line, is_selected));
+            layout.push(lines.len() - first_row);
+            continue;
+        }
+
+        let shown = displayed_entry(app, entry);
+        let rendered = match custom {
+            Some(renderer) => renderer.render(&shown),
+            None => builtin_renderer(
+                entry.display_mode(view.display_mode, view.native_tx_display),
+                app.settings.control_pictures,
+            )
+            .render(&shown),
         };
+        let mut rendered = rendered.into_iter();
 
-        let mut spans: Vec<Span> = Vec::new();
-        // Timestamp
-        spans.push(Span::styled(
-            format!("[{}] ", time_str),
-            Style::default().fg(Color::DarkGray),
-        ));
-        // Direction icon and label
-        spans.push(Span::styled(
-            format!("{} {} ", dir_icon, dir_str),
-            Style::default().fg(time_color).add_modifier(Modifier::BOLD),
-        ));
-        // Data length
-        spans.push(Span::styled(
-            format!("({:>4} B) ", data_len),
-            Style::default().fg(Color::Yellow),
-        ));
-        // Actual data
-        spans.push(Span::styled(data_str, Style::default().fg(Color::White)));
-        lines.push(Line::from(spans));
-    }
-
-    let display_mode_str = match app.display_mode {
-        DisplayMode::Hex => t!("display.hex"),
-        DisplayMode::Text => t!("display.text"),
-    };
+        let mut spans = build_prefix_spans(entry, &app.settings.log_columns);
+        // Actual data is always last; extra rendered lines follow below
+        if let Some(first) = rendered.next() {
+            spans.extend(first.spans);
+        }
+        let line = Line::from(spans);
+        lines.push(if is_selected {
+            line.style(selection_style)
+        } else if inspected == Some(idx) {
+            line.style(Style::default().bg(Color::DarkGray))
+        } else {
+            line
+        });
+        lines.extend(rendered.map(|line| select(line, is_selected)));
+        layout.push(lines.len() - first_row);
+    }
+
+    (lines, layout)
+}
+
+/// Draw log entries
+fn draw_log_entries(
+    f: &mut Frame,
+    app: &AppState,
+    area: Rect,
+    view: LogView,
+    focused: bool,
+    toggle_key: char,
+) {
+    let (lines, layout) = log_lines(app, view);
+
+    let display_mode_str = display_mode_label(app, view);
 
+    let count = if app.only_binary {
+        format!(
+            "{}/{} {}",
+            layout.len(),
+            app.message_log.entries.len(),
+            t!("log.only_binary")
+        )
+    } else {
+        app.message_log.entries.len().to_string()
+    };
     let title = format!(
-        " {} - {} | {} 条 [x {} | c {}] ",
+        " {} - {} | {} 条 [{} {} | c {}] ",
         t!("label.message"),
         display_mode_str,
-        app.message_log.entries.len(),
+        count,
+        toggle_key,
         t!("hint.toggle"),
         t!("hint.clear")
     );
 
-    let total_lines = lines.len() as u16;
-    let viewport_lines = area.height.saturating_sub(2).max(1);
-    let max_scroll = total_lines.saturating_sub(viewport_lines);
-    let scroll_top = if app.auto_scroll {
-        max_scroll
-    } else {
-        app.scroll_offset.min(max_scroll)
-    };
+    let scroll_top = layout.top_row(app.scroll_offset, app.auto_scroll, log_viewport_lines(area));
+    let scroll_top = u16::try_from(scroll_top).unwrap_or(u16::MAX);
 
     let para = Paragraph::new(lines)
         .block(
@@ -169,3 +338,492 @@ fn draw_log_entries(f: &mut Frame, app: &AppState, area: Rect, focused: bool) {
 
     f.render_widget(para, area);
 }
+
+/// Pause between two consecutive entries, if it exceeds `threshold_ms`
+fn idle_gap(prev: &LogEntry, next: &LogEntry, threshold_ms: u64) -> Option<Duration> {
+    let gap = (next.timestamp - prev.timestamp).to_std().ok()?;
+    (gap > Duration::from_millis(threshold_ms)).then_some(gap)
+}
+
+/// Subtle separator line marking an idle period, e.g. `⋯ 2.3s gap ⋯`
+fn build_gap_line(gap: Duration) -> Line<'static> {
+    Line::from(Span::styled(
+        t!("log.idle_gap", secs = format!("{:.1}", gap.as_secs_f64())).to_string(),
+        Style::default()
+            .fg(Color::DarkGray)
+            .add_modifier(Modifier::DIM),
+    ))
+}
+
+/// Build a full-width line for an inline event entry.
+///
+/// Only the timestamp and seq columns apply to events; the event text is
+/// rendered in place of direction/length/data.
+fn build_event_line(entry: &LogEntry, kind: LogEventKind, columns: &LogColumns) -> Line<'static> {
+    let (icon, color) = match kind {
+        LogEventKind::Connect | LogEventKind::Reconnect => ("●", Color::Green),
+        LogEventKind::Disconnect => ("○", Color::Yellow),
+        LogEventKind::Error => ("✗", Color::Red),
+        LogEventKind::Marker => ("─", Color::Magenta),
+    };
+
+    let mut spans: Vec<Span<'static>> = build_prefix_spans(entry, columns)
+        .into_iter()
+        .enumerate()
+        .filter(|(i, _)| matches!(columns.columns()[*i], LogColumn::Timestamp | LogColumn::Seq))
+        .map(|(_, span)| span)
+        .collect();
+    spans.push(Span::styled(
+        format!("── {} {} ──", icon, String::from_utf8_lossy(&entry.data)),
+        Style::default().fg(color).add_modifier(Modifier::BOLD),
+    ));
+    Line::from(spans)
+}
+
+/// Build the prefix spans for a log entry from the configured columns
+fn build_prefix_spans(entry: &LogEntry, columns: &LogColumns) -> Vec<Span<'static>> {
+    let (dir_color, dir_str, dir_icon) = match entry.direction {
+        LogDirection::Rx => (Color::Cyan, t!("label.rx_count"), "<"),
+        LogDirection::Tx => (Color::Green, t!("label.tx_count"), ">"),
+        LogDirection::Event(_) => (Color::Magenta, t!("label.event"), "•"),
+    };
+
+    columns
+        .columns()
+        .iter()
+        .map(|column| match column {
+            LogColumn::Timestamp => Span::styled(
+                format!("[{}] ", entry.timestamp.format("%H:%M:%S%.3f")),
+                Style::default().fg(Color::DarkGray),
+            ),
+            LogColumn::Direction => Span::styled(
+                format!("{} {} ", dir_icon, dir_str),
+                Style::default().fg(dir_color).add_modifier(Modifier::BOLD),
+            ),
+            LogColumn::Length => Span::styled(
+                format!("({:>4} B) ", entry.data.len()),
+                Style::default().fg(Color::Yellow),
+            ),
+            LogColumn::Seq => Span::styled(
+                format!("#{:<5} ", entry.seq),
+                Style::default().fg(Color::Magenta),
+            ),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_entry() -> LogEntry {
+        let mut entry = LogEntry::new(LogDirection::Tx, vec![0x41, 0x42, 0x43]);
+        entry.seq = 7;
+        entry
+    }
+
+    fn prefix_text(columns: &LogColumns) -> String {
+        build_prefix_spans(&sample_entry(), columns)
+            .iter()
+            .map(|s| s.content.to_string())
+            .collect()
+    }
+
+    fn entry_at(ms: i64) -> LogEntry {
+        use tuiserial_core::chrono::{Local, TimeDelta, TimeZone};
+        let mut entry = sample_entry();
+        entry.timestamp =
+            Local.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap() + TimeDelta::milliseconds(ms);
+        entry
+    }
+
+    #[test]
+    fn test_idle_gap_above_threshold() {
+        let gap = idle_gap(&entry_at(0), &entry_at(2300), 1000).unwrap();
+        assert_eq!(gap, Duration::from_millis(2300));
+        let text: String = build_gap_line(gap)
+            .spans
+            .iter()
+            .map(|s| s.content.to_string())
+            .collect();
+        assert!(text.contains("2.3s"), "{text}");
+    }
+
+    #[test]
+    fn test_idle_gap_below_threshold() {
+        assert_eq!(idle_gap(&entry_at(0), &entry_at(999), 1000), None);
+        assert_eq!(idle_gap(&entry_at(0), &entry_at(1000), 1000), None);
+        // Out-of-order timestamps never produce a marker
+        assert_eq!(idle_gap(&entry_at(5000), &entry_at(0), 1000), None);
+    }
+
+    #[test]
+    fn test_default_prefix_columns() {
+        let entry = sample_entry();
+        let spans = build_prefix_spans(&entry, &LogColumns::default());
+        assert_eq!(spans.len(), 3);
+        assert!(spans[0].content.starts_with('['));
+        assert!(spans[1].content.starts_with('>'));
+        assert_eq!(spans[2].content, "(   3 B) ");
+    }
+
+    #[test]
+    fn test_prefix_follows_column_order() {
+        let columns = LogColumns::new([LogColumn::Seq, LogColumn::Length, LogColumn::Direction]);
+        let text = prefix_text(&columns);
+        assert!(text.starts_with("#7"));
+        let len_pos = text.find("(   3 B)").unwrap();
+        let dir_pos = text.find('>').unwrap();
+        assert!(len_pos < dir_pos);
+        assert!(!text.contains('['));
+    }
+
+    #[test]
+    fn test_prefix_hides_length() {
+        let columns = LogColumns::new([LogColumn::Timestamp, LogColumn::Direction]);
+        let text = prefix_text(&columns);
+        assert!(!text.contains(" B)"));
+        assert!(!text.contains('#'));
+    }
+
+    #[test]
+    fn test_event_line_keeps_only_time_and_seq() {
+        let mut entry = LogEntry::new(
+            LogDirection::Event(LogEventKind::Connect),
+            b"Connected".to_vec(),
+        );
+        entry.seq = 3;
+        let columns = LogColumns::new([LogColumn::Seq, LogColumn::Direction, LogColumn::Length]);
+        let text: String = build_event_line(&entry, LogEventKind::Connect, &columns)
+            .spans
+            .iter()
+            .map(|s| s.content.to_string())
+            .collect();
+        assert!(text.starts_with("#3"));
+        assert!(text.contains("Connected"));
+        assert!(!text.contains(" B)"));
+    }
+
+    #[test]
+    fn test_empty_prefix() {
+        assert!(prefix_text(&LogColumns::new([])).is_empty());
+    }
+
+    #[test]
+    fn test_render_after_clear_with_stale_scroll() {
+        use ratatui::{Terminal, backend::TestBackend};
+
+        let mut app = AppState::default();
+        for i in 0..100 {
+            app.append_rx(vec![i]);
+        }
+        app.auto_scroll = false;
+        app.scroll_offset = 90;
+        app.clear_log();
+
+        let mut terminal = Terminal::new(TestBackend::new(60, 12)).unwrap();
+        terminal.draw(|f| draw_log_area(f, &app, f.area())).unwrap();
+
+        // A single new entry after the clear is visible on the first row
+        app.auto_scroll = false;
+        app.append_rx(b"hello".to_vec());
+        terminal.draw(|f| draw_log_area(f, &app, f.area())).unwrap();
+        let row: String = (0..60)
+            .map(|x| terminal.backend().buffer()[(x, 1)].symbol().to_string())
+            .collect();
+        assert!(row.contains("68 65 6C 6C 6F"), "{row}");
+    }
+
+    #[test]
+    fn test_native_tx_display_renders_ascii_as_text() {
+        use ratatui::{Terminal, backend::TestBackend};
+        use tuiserial_core::TxMode;
+
+        let mut app = AppState::default();
+        assert_eq!(app.display_mode, DisplayMode::Hex);
+        app.message_log
+            .push_tx_as(b"AT+GMR".to_vec(), TxMode::Ascii);
+        app.message_log.push_rx(b"OK".to_vec());
+
+        let render = |app: &AppState| {
+            let mut terminal = Terminal::new(TestBackend::new(60, 6)).unwrap();
+            terminal.draw(|f| draw_log_area(f, app, f.area())).unwrap();
+            let buffer = terminal.backend().buffer().clone();
+            (1..3)
+                .map(|y| {
+                    (0..60)
+                        .map(|x| buffer[(x, y)].symbol().to_string())
+                        .collect::<String>()
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let rows = render(&app);
+        assert!(rows[0].contains("41 54 2B 47 4D 52"), "{}", rows[0]);
+
+        app.settings.native_tx_display = true;
+        let rows = render(&app);
+        assert!(rows[0].contains("AT+GMR"), "{}", rows[0]);
+        assert!(rows[1].contains("4F 4B"), "{}", rows[1]);
+    }
+
+    #[test]
+    fn test_dual_view_renders_same_log_independently() {
+        use ratatui::{Terminal, backend::TestBackend};
+
+        let mut app = AppState::default();
+        app.message_log.push_rx(b"OK".to_vec());
+        app.toggle_dual_view();
+        let secondary = app.dual_view.unwrap();
+
+        let row = |app: &AppState, secondary: Option<LogView>| {
+            let mut terminal = Terminal::new(TestBackend::new(60, 5)).unwrap();
+            terminal
+                .draw(|f| match secondary {
+                    Some(view) => draw_secondary_log_area(f, app, f.area(), view),
+                    None => draw_log_area(f, app, f.area()),
+                })
+                .unwrap();
+            (0..60)
+                .map(|x| terminal.backend().buffer()[(x, 1)].symbol().to_string())
+                .collect::<String>()
+        };
+
+        // Both views render the one shared entry, each in its own mode
+        assert!(row(&app, None).contains("4F 4B"));
+        let text_row = row(&app, Some(secondary));
+        assert!(text_row.contains("OK"), "{text_row}");
+        assert!(!text_row.contains("4F 4B"));
+
+        app.toggle_display_mode();
+        assert!(row(&app, None).contains("OK"));
+        assert!(!row(&app, Some(secondary)).contains("4F 4B"));
+    }
+
+    #[test]
+    fn test_custom_renderer_in_display_cycle() {
+        use ratatui::{Terminal, backend::TestBackend};
+
+        struct Decoder;
+        impl DisplayRenderer for Decoder {
+            fn render(&self, entry: &LogEntry) -> Vec<Line<'static>> {
+                vec![
+                    Line::from(format!("frame len={}", entry.data.len())),
+                    Line::from("  payload"),
+                ]
+            }
+        }
+
+        let mut app = AppState::default();
+        app.message_log.push_rx(b"OK".to_vec());
+        app.register_renderer("Decoder", Decoder);
+        app.toggle_display_mode();
+        app.toggle_display_mode();
+
+        let mut terminal = Terminal::new(TestBackend::new(60, 5)).unwrap();
+        terminal.draw(|f| draw_log_area(f, &app, f.area())).unwrap();
+        let row = |y| {
+            (0..60)
+                .map(|x| terminal.backend().buffer()[(x, y)].symbol().to_string())
+                .collect::<String>()
+        };
+        assert!(row(0).contains("Decoder"), "{}", row(0));
+        assert!(row(1).contains("frame len=2"), "{}", row(1));
+        assert!(row(2).contains("payload"), "{}", row(2));
+    }
+
+    #[test]
+    fn test_bit_transforms_apply_to_display_only() {
+        use ratatui::{Terminal, backend::TestBackend};
+
+        let mut app = AppState::default();
+        app.message_log.push_rx(vec![0x82, 0xC1]);
+        let row = |app: &AppState| {
+            let mut terminal = Terminal::new(TestBackend::new(60, 5)).unwrap();
+            terminal.draw(|f| draw_log_area(f, app, f.area())).unwrap();
+            (0..60)
+                .map(|x| terminal.backend().buffer()[(x, 1)].symbol().to_string())
+                .collect::<String>()
+        };
+        assert!(row(&app).contains("82 C1"));
+
+        app.settings.lsb_first = true;
+        assert!(row(&app).contains("41 83"), "{}", row(&app));
+        app.settings.mask_high_bit = true;
+        assert!(row(&app).contains("41 03"), "{}", row(&app));
+        app.settings.lsb_first = false;
+        assert!(row(&app).contains("02 41"), "{}", row(&app));
+
+        // Stored data is untouched
+        assert_eq!(app.message_log.entries[0].data, vec![0x82, 0xC1]);
+    }
+
+    #[test]
+    fn test_displayed_entry_borrows_without_transforms() {
+        let mut app = AppState::default();
+        app.message_log.push_rx(vec![0x82]);
+        let entry = &app.message_log.entries[0];
+        assert!(matches!(displayed_entry(&app, entry), Cow::Borrowed(_)));
+
+        let mut masked = AppState::default();
+        masked.settings.mask_high_bit = true;
+        let shown = displayed_entry(&masked, entry);
+        assert!(matches!(shown, Cow::Owned(_)));
+        assert_eq!(
+            (shown.data.as_slice(), shown.seq),
+            ([0x02].as_slice(), entry.seq)
+        );
+    }
+
+    #[test]
+    fn test_control_pictures_in_text_mode() {
+        use ratatui::{Terminal, backend::TestBackend};
+
+        let mut app = AppState::default();
+        app.message_log.push_rx(b"OK\r\n".to_vec());
+        app.toggle_display_mode();
+        let row = |app: &AppState, y| {
+            let mut terminal = Terminal::new(TestBackend::new(60, 5)).unwrap();
+            terminal.draw(|f| draw_log_area(f, app, f.area())).unwrap();
+            (0..60)
+                .map(|x| terminal.backend().buffer()[(x, y)].symbol().to_string())
+                .collect::<String>()
+        };
+        assert!(row(&app, 1).contains("OK\\x0D\\x0A"), "{}", row(&app, 1));
+
+        app.settings.control_pictures = true;
+        assert!(row(&app, 1).contains("OK␍␊"), "{}", row(&app, 1));
+        assert!(
+            row(&app, 0).contains("control pictures"),
+            "{}",
+            row(&app, 0)
+        );
+
+        // HEX mode is unaffected
+        app.toggle_display_mode();
+        assert!(row(&app, 1).contains("4F 4B 0D 0A"), "{}", row(&app, 1));
+        assert!(!row(&app, 0).contains("control pictures"));
+    }
+
+    #[test]
+    fn test_scroll_and_focus_count_idle_gap_rows() {
+        use ratatui::{Terminal, backend::TestBackend};
+        use tuiserial_core::ByteInspector;
+
+        let mut app = AppState::default();
+        app.settings.show_idle_gaps = true;
+        app.settings.idle_gap_ms = 1000;
+        for i in 0..10u8 {
+            app.message_log.push_rx(vec![0xA0 + i]);
+            app.message_log.entries[i as usize].timestamp = entry_at(i as i64 * 2000).timestamp;
+        }
+        // Every entry after the first has a gap row above it
+        let layout = log_layout(&app, app.primary_view());
+        assert_eq!(layout.total_rows(), 19);
+
+        let rows = |app: &AppState| {
+            let mut terminal = Terminal::new(TestBackend::new(60, 7)).unwrap();
+            terminal.draw(|f| draw_log_area(f, app, f.area())).unwrap();
+            let buffer = terminal.backend().buffer().clone();
+            (1..6)
+                .map(|y| {
+                    let text: String = (0..60).map(|x| buffer[(x, y)].symbol()).collect();
+                    (text, buffer[(1, y)].bg)
+                })
+                .collect::<Vec<_>>()
+        };
+
+        // Following: the newest entry is on the last row
+        let shown = rows(&app);
+        assert!(shown[0].0.contains("A7"), "{}", shown[0].0);
+        assert!(shown[4].0.contains("A9"), "{}", shown[4].0);
+
+        // Scrolled to entry 2: its gap row, then the entry, highlighted
+        app.auto_scroll = false;
+        app.scroll_offset = 2;
+        app.byte_inspector = Some(ByteInspector::default());
+        let shown = rows(&app);
+        assert!(shown[0].0.contains("2.0s"), "{}", shown[0].0);
+        assert!(shown[1].0.contains("A2"), "{}", shown[1].0);
+        assert_eq!(shown[1].1, Color::DarkGray);
+
+        // Scrolled past the last page: clamped by rows, focus still shown
+        app.scroll_offset = 9;
+        let shown = rows(&app);
+        assert!(shown[0].0.contains("A7"), "{}", shown[0].0);
+        assert!(shown[4].0.contains("A9"), "{}", shown[4].0);
+        assert_eq!(shown[4].1, Color::DarkGray);
+    }
+
+    #[test]
+    fn test_scroll_and_focus_count_multi_line_renderer_rows() {
+        use ratatui::{Terminal, backend::TestBackend};
+        use tuiserial_core::ByteInspector;
+
+        struct Frames;
+        impl DisplayRenderer for Frames {
+            fn render(&self, entry: &LogEntry) -> Vec<Line<'static>> {
+                vec![
+                    Line::from(format!("frame {:02X}", entry.data[0])),
+                    Line::from("  header"),
+                    Line::from("  payload"),
+                ]
+            }
+        }
+
+        let mut app = AppState::default();
+        for i in 0..10u8 {
+            app.message_log.push_rx(vec![0xA0 + i]);
+        }
+        app.register_renderer("Frames", Frames);
+        app.toggle_display_mode();
+        app.toggle_display_mode();
+        let layout = log_layout(&app, app.primary_view());
+        assert_eq!(layout.total_rows(), 30);
+        // View row 3 of a 5-row view scrolled to entry 2 is entry 3's first row
+        app.auto_scroll = false;
+        app.scroll_offset = 2;
+        assert_eq!(app.log_position_at_row(&layout, 5, 3), Some(3));
+        assert_eq!(app.log_position_at_row(&layout, 5, 2), Some(2));
+
+        let rows = |app: &AppState| {
+            let mut terminal = Terminal::new(TestBackend::new(60, 7)).unwrap();
+            terminal.draw(|f| draw_log_area(f, app, f.area())).unwrap();
+            let buffer = terminal.backend().buffer().clone();
+            (1..6)
+                .map(|y| {
+                    let text: String = (0..60).map(|x| buffer[(x, y)].symbol()).collect();
+                    (text, buffer[(1, y)].bg)
+                })
+                .collect::<Vec<_>>()
+        };
+
+        // Scrolled to entry 2: its three rows, the first highlighted
+        app.byte_inspector = Some(ByteInspector::default());
+        let shown = rows(&app);
+        assert!(shown[0].0.contains("frame A2"), "{}", shown[0].0);
+        assert_eq!(shown[0].1, Color::DarkGray);
+        assert!(shown[2].0.contains("payload"), "{}", shown[2].0);
+        assert!(shown[3].0.contains("frame A3"), "{}", shown[3].0);
+
+        // Following: the newest entry's last row is on the last row
+        app.auto_scroll = true;
+        app.byte_inspector = None;
+        let shown = rows(&app);
+        assert!(shown[2].0.contains("frame A9"), "{}", shown[2].0);
+        assert!(shown[4].0.contains("payload"), "{}", shown[4].0);
+
+        // Scrolled past the last page: clamped by rows, not entries
+        app.auto_scroll = false;
+        app.scroll_offset = 9;
+        app.byte_inspector = Some(ByteInspector::default());
+        let shown = rows(&app);
+        assert!(shown[4].0.contains("payload"), "{}", shown[4].0);
+        assert!(
+            shown
+                .iter()
+                .any(|(text, bg)| text.contains("frame A9") && *bg == Color::DarkGray),
+            "{shown:?}"
+        );
+    }
+}
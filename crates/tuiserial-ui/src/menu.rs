@@ -8,7 +8,7 @@ use ratatui::{
     layout::Rect,
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Clear, List, ListItem, Paragraph},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
 };
 use rust_i18n::t;
 use tuiserial_core::{AppState, MenuState, menu_def::MENU_BAR};
@@ -83,36 +83,53 @@ pub fn draw_menu_bar(f: &mut Frame, app: &AppState, area: Rect) {
     f.render_widget(menu_bar, area);
 }
 
-/// Draw dropdown menu
-pub fn draw_menu_dropdown(
-    f: &mut Frame,
+/// Dropdown item labels of `menu_idx` with whether each is a separator
+fn dropdown_items(menu_idx: usize) -> Option<Vec<(String, bool)>> {
+    let menu = MENU_BAR.get_menu(menu_idx)?;
+    Some(
+        menu.items
+            .iter()
+            .map(|action| {
+                let label = if action.is_separator() {
+                    String::new()
+                } else {
+                    t!(action.label_key()).to_string()
+                };
+                (label, action.is_separator())
+            })
+            .collect(),
+    )
+}
+
+/// Area of the dropdown of `menu_idx` on a `screen`-sized terminal and the
+/// index of its first visible item. A menu taller than the screen is cut
+/// off at the bottom edge and scrolled so `selected_item` stays in view.
+pub fn menu_dropdown_layout(
+    screen: Rect,
     menu_bar_area: Rect,
     menu_idx: usize,
     selected_item: usize,
-) {
-    // Get menu
-    let menu = match MENU_BAR.get_menu(menu_idx) {
-        Some(m) => m,
-        None => return,
-    };
+) -> Option<(Rect, usize)> {
+    let items = dropdown_items(menu_idx)?;
+    Some(dropdown_layout(
+        screen,
+        menu_bar_area,
+        menu_idx,
+        &items,
+        selected_item,
+    ))
+}
 
+fn dropdown_layout(
+    screen: Rect,
+    menu_bar_area: Rect,
+    menu_idx: usize,
+    items: &[(String, bool)],
+    selected_item: usize,
+) -> (Rect, usize) {
     // Calculate x position based on menu index
     let x_offset = tuiserial_core::menu_def::calculate_menu_x_offset(menu_idx);
 
-    // Build menu items with translations
-    let items: Vec<(String, bool)> = menu
-        .items
-        .iter()
-        .map(|action| {
-            let label = if action.is_separator() {
-                String::new()
-            } else {
-                t!(action.label_key()).to_string()
-            };
-            (label, action.is_separator())
-        })
-        .collect();
-
     // Calculate dropdown dimensions
     let max_width = items
         .iter()
@@ -122,13 +139,35 @@ pub fn draw_menu_dropdown(
         + 6; // +6 for borders and padding
     let height = items.len() as u16 + 2; // +2 for borders
 
-    // Position dropdown below menu bar
+    // Position dropdown below menu bar, clipped to the screen
     let dropdown_area = Rect {
         x: menu_bar_area.x + x_offset,
         y: menu_bar_area.y + 1,
         width: max_width,
         height,
+    }
+    .intersection(screen);
+
+    let rows = dropdown_area.height.saturating_sub(2).max(1) as usize;
+    (dropdown_area, selected_item.saturating_sub(rows - 1))
+}
+
+/// Draw dropdown menu
+pub fn draw_menu_dropdown(
+    f: &mut Frame,
+    menu_bar_area: Rect,
+    menu_idx: usize,
+    selected_item: usize,
+) {
+    let Some(items) = dropdown_items(menu_idx) else {
+        return;
     };
+    let (dropdown_area, first_visible) =
+        dropdown_layout(f.area(), menu_bar_area, menu_idx, &items, selected_item);
+    if dropdown_area.is_empty() {
+        return;
+    }
+    let max_width = dropdown_area.width;
 
     // Create list items
     let list_items: Vec<ListItem> = items
@@ -156,10 +195,13 @@ pub fn draw_menu_dropdown(
             .border_style(Style::default().fg(Color::Cyan))
             .style(Style::default().bg(Color::Black)),
     );
+    let mut state = ListState::default()
+        .with_offset(first_visible)
+        .with_selected(Some(selected_item));
 
     // Clear the area first to ensure dropdown overlays other content
     f.render_widget(Clear, dropdown_area);
-    f.render_widget(list, dropdown_area);
+    f.render_stateful_widget(list, dropdown_area, &mut state);
 }
 
 /// Find which menu was clicked based on mouse position
@@ -197,4 +239,30 @@ mod tests {
         let result = find_clicked_menu(2, 1, area);
         assert_eq!(result, None);
     }
+
+    #[test]
+    fn test_every_dropdown_fits_small_terminal() {
+        use ratatui::{Terminal, backend::TestBackend};
+
+        let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+        let menu_bar = Rect::new(0, 0, 80, 1);
+        for menu_idx in 0..MENU_BAR.menu_count() {
+            let count = MENU_BAR.get_menu(menu_idx).unwrap().items.len();
+            for selected in [0, count - 1] {
+                terminal
+                    .draw(|f| draw_menu_dropdown(f, menu_bar, menu_idx, selected))
+                    .unwrap();
+
+                // The selected item is drawn inside the screen
+                let (area, first) =
+                    menu_dropdown_layout(Rect::new(0, 0, 80, 24), menu_bar, menu_idx, selected)
+                        .unwrap();
+                assert!(area.bottom() <= 24);
+                let row = area.y + 1 + (selected - first) as u16;
+                assert!(row < area.bottom() - 1, "menu {menu_idx} item {selected}");
+                let cell = &terminal.backend().buffer()[(area.x + 1, row)];
+                assert_eq!(cell.bg, Color::Cyan, "menu {menu_idx} item {selected}");
+            }
+        }
+    }
 }
@@ -244,6 +244,7 @@ pub fn is_clickable_area(x: u16, y: u16) -> bool {
         || is_inside(areas.parity, x, y)
         || is_inside(areas.stop_bits, x, y)
         || is_inside(areas.flow_control, x, y)
+        || is_inside(areas.config_summary, x, y)
         || is_inside(areas.tx_area, x, y)
         || is_inside(areas.shortcuts_hint, x, y)
         || is_inside(areas.tab_bar, x, y)
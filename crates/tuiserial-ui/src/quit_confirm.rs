@@ -0,0 +1,67 @@
+//! Quit confirmation — shown when quitting while connected or while a
+//! checkpoint capture is running.
+
+use ratatui::{
+    Frame,
+    layout::{Alignment, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+use rust_i18n::t;
+use tuiserial_core::AppState;
+
+/// Draw the quit confirmation centered on screen
+pub fn draw_quit_confirm(f: &mut Frame, app: &AppState) {
+    let area = f.area();
+
+    let width = 60.min(area.width.saturating_sub(4));
+    let height = 7.min(area.height.saturating_sub(2));
+    let dialog_area = Rect {
+        x: (area.width.saturating_sub(width)) / 2,
+        y: (area.height.saturating_sub(height)) / 2,
+        width,
+        height,
+    };
+
+    f.render_widget(Clear, dialog_area);
+
+    let warning = Style::default()
+        .fg(Color::Yellow)
+        .add_modifier(Modifier::BOLD);
+    let mut lines = Vec::new();
+    if app.is_connected() {
+        lines.push(Line::from(Span::styled(
+            format!(
+                " {}",
+                t!("quit_confirm.connected", port = &app.active_config().port)
+            ),
+            warning,
+        )));
+    }
+    if let Some(checkpoint) = app.checkpoint {
+        lines.push(Line::from(Span::styled(
+            format!(
+                " {}",
+                t!("quit_confirm.recording", number = checkpoint.number)
+            ),
+            warning,
+        )));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        format!(" {}", t!("quit_confirm.hint")),
+        Style::default().fg(Color::DarkGray),
+    )));
+
+    let para = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Yellow))
+            .title(format!(" {} ", t!("quit_confirm.title")))
+            .title_alignment(Alignment::Left)
+            .style(Style::default().bg(Color::Black)),
+    );
+
+    f.render_widget(para, dialog_area);
+}
@@ -0,0 +1,77 @@
+//! Repeat send prompt — asks for the repeat count and interval before
+//! starting a bounded repeat of the current TX input.
+
+use ratatui::{
+    Frame,
+    layout::{Alignment, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+use rust_i18n::t;
+use tuiserial_core::{RepeatPrompt, RepeatPromptField};
+
+/// Draw the repeat count/interval prompt centered on screen
+pub fn draw_repeat_prompt(f: &mut Frame, prompt: &RepeatPrompt) {
+    let area = f.area();
+
+    let width = 44.min(area.width.saturating_sub(4));
+    let height = 7.min(area.height.saturating_sub(2));
+    let prompt_area = Rect {
+        x: (area.width.saturating_sub(width)) / 2,
+        y: (area.height.saturating_sub(height)) / 2,
+        width,
+        height,
+    };
+
+    f.render_widget(Clear, prompt_area);
+
+    let lines = vec![
+        field_line(
+            t!("repeat.count").to_string(),
+            &prompt.count,
+            prompt.field == RepeatPromptField::Count,
+        ),
+        field_line(
+            t!("repeat.interval").to_string(),
+            &prompt.interval_ms,
+            prompt.field == RepeatPromptField::Interval,
+        ),
+        Line::from(""),
+        Line::from(Span::styled(
+            t!("repeat.hint"),
+            Style::default().fg(Color::DarkGray),
+        )),
+    ];
+
+    let para = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan))
+            .title(format!(" {} ", t!("repeat.title")))
+            .title_alignment(Alignment::Left)
+            .style(Style::default().bg(Color::Black)),
+    );
+
+    f.render_widget(para, prompt_area);
+}
+
+fn field_line(label: String, value: &str, focused: bool) -> Line<'static> {
+    let value_style = if focused {
+        Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD | Modifier::UNDERLINED)
+    } else {
+        Style::default().fg(Color::White)
+    };
+    let cursor = if focused { "▏" } else { " " };
+
+    Line::from(vec![
+        Span::styled(
+            format!(" {:<16}", format!("{}:", label)),
+            Style::default().fg(Color::Cyan),
+        ),
+        Span::styled(value.to_string(), value_style),
+        Span::styled(cursor, Style::default().fg(Color::Yellow)),
+    ])
+}
@@ -0,0 +1,95 @@
+//! Send confirmation — shows the size and first bytes of a large payload
+//! before it is transmitted.
+
+use ratatui::{
+    Frame,
+    layout::{Alignment, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+use rust_i18n::t;
+use tuiserial_core::PendingSend;
+use tuiserial_serial::bytes_to_hex;
+
+/// Bytes shown in the preview line
+const PREVIEW_BYTES: usize = 16;
+
+/// Draw the send confirmation centered on screen
+pub fn draw_send_confirm(f: &mut Frame, pending: &PendingSend, threshold: usize) {
+    let area = f.area();
+
+    let width = 60.min(area.width.saturating_sub(4));
+    let height = 7.min(area.height.saturating_sub(2));
+    let dialog_area = Rect {
+        x: (area.width.saturating_sub(width)) / 2,
+        y: (area.height.saturating_sub(height)) / 2,
+        width,
+        height,
+    };
+
+    f.render_widget(Clear, dialog_area);
+
+    let lines = vec![
+        Line::from(Span::styled(
+            format!(
+                " {}",
+                t!(
+                    "send_confirm.size",
+                    bytes = pending.data.len(),
+                    threshold = threshold
+                )
+            ),
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(vec![
+            Span::styled(
+                format!(" {}: ", t!("send_confirm.preview")),
+                Style::default().fg(Color::Cyan),
+            ),
+            Span::styled(preview(&pending.data), Style::default().fg(Color::White)),
+        ]),
+        Line::from(""),
+        Line::from(Span::styled(
+            format!(" {}", t!("send_confirm.hint")),
+            Style::default().fg(Color::DarkGray),
+        )),
+    ];
+
+    let para = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Yellow))
+            .title(format!(" {} ", t!("send_confirm.title")))
+            .title_alignment(Alignment::Left)
+            .style(Style::default().bg(Color::Black)),
+    );
+
+    f.render_widget(para, dialog_area);
+}
+
+/// Hex of the first `PREVIEW_BYTES` bytes, with "…" if there are more
+fn preview(data: &[u8]) -> String {
+    let head = bytes_to_hex(&data[..data.len().min(PREVIEW_BYTES)]);
+    if data.len() > PREVIEW_BYTES {
+        format!("{head} …")
+    } else {
+        head
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_preview_truncates() {
+        assert_eq!(preview(&[0x41, 0x54]), "41 54");
+        let long: Vec<u8> = (0..20).collect();
+        let text = preview(&long);
+        assert!(text.starts_with("00 01 02"));
+        assert!(text.ends_with("0F …"), "{text}");
+    }
+}
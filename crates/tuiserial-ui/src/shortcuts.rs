@@ -11,14 +11,91 @@ use ratatui::{
     widgets::{Block, Borders, Clear, Paragraph, Wrap},
 };
 use rust_i18n::t;
+use tuiserial_core::{KeyBindings, ShortcutAction};
+
+/// Shortcuts listed in the help overlay: section title key and actions
+const HELP_SECTIONS: &[(&str, &[ShortcutAction])] = &[
+    (
+        "shortcuts.session",
+        &[
+            ShortcutAction::NewSession,
+            ShortcutAction::CloneSession,
+            ShortcutAction::CloseSession,
+            ShortcutAction::NextSession,
+            ShortcutAction::PrevSession,
+            ShortcutAction::SwitchSessionArrows,
+            ShortcutAction::SwitchSessionNumber,
+        ],
+    ),
+    (
+        "shortcuts.layout",
+        &[
+            ShortcutAction::CycleLayout,
+            ShortcutAction::PrevLayout,
+            ShortcutAction::NextPane,
+            ShortcutAction::PrevPane,
+            ShortcutAction::CyclePaneSession,
+        ],
+    ),
+    (
+        "shortcuts.general",
+        &[
+            ShortcutAction::NextField,
+            ShortcutAction::PrevField,
+            ShortcutAction::ToggleConnection,
+            ShortcutAction::PanicDisconnect,
+            ShortcutAction::RepeatSend,
+            ShortcutAction::ByteInspector,
+            ShortcutAction::RxTiming,
+            ShortcutAction::BaudPrev,
+            ShortcutAction::BaudNext,
+            ShortcutAction::Checkpoint,
+            ShortcutAction::ExportSinceCheckpoint,
+            ShortcutAction::BytePad,
+            ShortcutAction::ApplyStaged,
+            ShortcutAction::RevertStaged,
+            ShortcutAction::NextBinaryEntry,
+            ShortcutAction::BinaryOnly,
+            ShortcutAction::CopySelection,
+            ShortcutAction::SendClipboard,
+            ShortcutAction::DualViewMode,
+            ShortcutAction::ClearLog,
+            ShortcutAction::ToggleDisplayMode,
+            ShortcutAction::ToggleAutoScroll,
+            ShortcutAction::FlowControl,
+            ShortcutAction::AppendMode,
+            ShortcutAction::RefreshPorts,
+            ShortcutAction::OpenMenu,
+            ShortcutAction::Quit,
+            ShortcutAction::ForceQuit,
+        ],
+    ),
+];
+
+/// Shortcuts in the hint bar and their short labels
+const HINTS: &[(ShortcutAction, &str)] = &[
+    (ShortcutAction::OpenMenu, "Menu"),
+    (ShortcutAction::OpenPlugins, "Plugins"),
+    (ShortcutAction::NewSession, "New"),
+    (ShortcutAction::CloseSession, "Close"),
+    (ShortcutAction::CycleLayout, "Layout"),
+    (ShortcutAction::NextPane, "Pane"),
+    (ShortcutAction::ToggleConnection, "Connect"),
+    (ShortcutAction::ClearLog, "Clear"),
+    (ShortcutAction::ToggleDisplayMode, "Mode"),
+    (ShortcutAction::ShowHelp, "Help"),
+];
+
+/// Width of the key column in the help overlay
+const KEY_COLUMN: usize = 16;
 
 /// Draw the keyboard shortcuts help overlay
-pub fn draw_shortcuts_help(f: &mut Frame) {
+pub fn draw_shortcuts_help(f: &mut Frame, bindings: &KeyBindings) {
     let area = f.area();
 
     // Calculate centered position
     let help_width = 70.min(area.width.saturating_sub(4));
-    let help_height = 28.min(area.height.saturating_sub(4));
+    let help_height = 56.min(area.height.saturating_sub(4));
     let x = (area.width.saturating_sub(help_width)) / 2;
     let y = (area.height.saturating_sub(help_height)) / 2;
 
@@ -32,207 +109,7 @@ pub fn draw_shortcuts_help(f: &mut Frame) {
     // Clear the area first
     f.render_widget(Clear, help_area);
 
-    // Create the help content
-    let lines = vec![
-        Line::from(""),
-        Line::from(vec![Span::styled(
-            t!("shortcuts.session"),
-            Style::default()
-                .fg(Color::Cyan)
-                .add_modifier(Modifier::BOLD),
-        )]),
-        Line::from(vec![
-            Span::styled("  Ctrl+T", Style::default().fg(Color::Yellow)),
-            Span::raw("          "),
-            Span::raw(
-                t!("shortcuts.new_session")
-                    .split(':')
-                    .nth(1)
-                    .unwrap_or("New Session")
-                    .to_string(),
-            ),
-        ]),
-        Line::from(vec![
-            Span::styled("  Ctrl+W", Style::default().fg(Color::Yellow)),
-            Span::raw("          "),
-            Span::raw(
-                t!("shortcuts.close_session")
-                    .split(':')
-                    .nth(1)
-                    .unwrap_or("Close Session")
-                    .to_string(),
-            ),
-        ]),
-        Line::from(vec![
-            Span::styled("  Ctrl+Tab", Style::default().fg(Color::Yellow)),
-            Span::raw("        "),
-            Span::raw(
-                t!("shortcuts.next_session")
-                    .split(':')
-                    .nth(1)
-                    .unwrap_or("Next Session")
-                    .to_string(),
-            ),
-        ]),
-        Line::from(vec![
-            Span::styled("  Ctrl+Shift+Tab", Style::default().fg(Color::Yellow)),
-            Span::raw("  "),
-            Span::raw(
-                t!("shortcuts.prev_session")
-                    .split(':')
-                    .nth(1)
-                    .unwrap_or("Previous Session")
-                    .to_string(),
-            ),
-        ]),
-        Line::from(vec![
-            Span::styled("  Ctrl+←/→", Style::default().fg(Color::Yellow)),
-            Span::raw("        "),
-            Span::raw("Switch between sessions"),
-        ]),
-        Line::from(vec![
-            Span::styled("  Ctrl+1~9", Style::default().fg(Color::Yellow)),
-            Span::raw("        "),
-            Span::raw(
-                t!("shortcuts.switch_1_9")
-                    .split(':')
-                    .nth(1)
-                    .unwrap_or("Switch to session")
-                    .to_string(),
-            ),
-        ]),
-        Line::from(""),
-        Line::from(vec![Span::styled(
-            t!("shortcuts.layout"),
-            Style::default()
-                .fg(Color::Cyan)
-                .add_modifier(Modifier::BOLD),
-        )]),
-        Line::from(vec![
-            Span::styled("  Ctrl+L", Style::default().fg(Color::Yellow)),
-            Span::raw("          "),
-            Span::raw(
-                t!("shortcuts.cycle_layout")
-                    .split(':')
-                    .nth(1)
-                    .unwrap_or("Cycle layout mode")
-                    .to_string(),
-            ),
-        ]),
-        Line::from(vec![
-            Span::styled("  Ctrl+Shift+L", Style::default().fg(Color::Yellow)),
-            Span::raw("    "),
-            Span::raw(
-                t!("shortcuts.prev_layout")
-                    .split(':')
-                    .nth(1)
-                    .unwrap_or("Previous layout")
-                    .to_string(),
-            ),
-        ]),
-        Line::from(vec![
-            Span::styled("  Ctrl+P", Style::default().fg(Color::Yellow)),
-            Span::raw("          "),
-            Span::raw(
-                t!("shortcuts.next_pane")
-                    .split(':')
-                    .nth(1)
-                    .unwrap_or("Focus next pane")
-                    .to_string(),
-            ),
-        ]),
-        Line::from(vec![
-            Span::styled("  Ctrl+Shift+P", Style::default().fg(Color::Yellow)),
-            Span::raw("    "),
-            Span::raw(
-                t!("shortcuts.prev_pane_key")
-                    .split(':')
-                    .nth(1)
-                    .unwrap_or("Focus previous pane")
-                    .to_string(),
-            ),
-        ]),
-        Line::from(vec![
-            Span::styled("  Ctrl+N", Style::default().fg(Color::Yellow)),
-            Span::raw("          "),
-            Span::raw(
-                t!("shortcuts.cycle_pane_session")
-                    .split(':')
-                    .nth(1)
-                    .unwrap_or("Cycle session in pane")
-                    .to_string(),
-            ),
-        ]),
-        Line::from(""),
-        Line::from(vec![Span::styled(
-            t!("shortcuts.general"),
-            Style::default()
-                .fg(Color::Cyan)
-                .add_modifier(Modifier::BOLD),
-        )]),
-        Line::from(vec![
-            Span::styled("  Tab", Style::default().fg(Color::Yellow)),
-            Span::raw("             "),
-            Span::raw(
-                t!("shortcuts.tab")
-                    .split(':')
-                    .nth(1)
-                    .unwrap_or("Next field")
-                    .to_string(),
-            ),
-        ]),
-        Line::from(vec![
-            Span::styled("  Shift+Tab", Style::default().fg(Color::Yellow)),
-            Span::raw("       "),
-            Span::raw(
-                t!("shortcuts.shift_tab")
-                    .split(':')
-                    .nth(1)
-                    .unwrap_or("Previous field")
-                    .to_string(),
-            ),
-        ]),
-        Line::from(vec![
-            Span::styled("  O", Style::default().fg(Color::Yellow)),
-            Span::raw("               "),
-            Span::raw("Connect/Disconnect"),
-        ]),
-        Line::from(vec![
-            Span::styled("  C", Style::default().fg(Color::Yellow)),
-            Span::raw("               "),
-            Span::raw("Clear log"),
-        ]),
-        Line::from(vec![
-            Span::styled("  X", Style::default().fg(Color::Yellow)),
-            Span::raw("               "),
-            Span::raw("Toggle display mode (HEX/TEXT)"),
-        ]),
-        Line::from(vec![
-            Span::styled("  A", Style::default().fg(Color::Yellow)),
-            Span::raw("               "),
-            Span::raw("Toggle auto scroll"),
-        ]),
-        Line::from(vec![
-            Span::styled("  F10", Style::default().fg(Color::Yellow)),
-            Span::raw("             "),
-            Span::raw("Open menu"),
-        ]),
-        Line::from(vec![
-            Span::styled("  Ctrl+C/Q", Style::default().fg(Color::Yellow)),
-            Span::raw("        "),
-            Span::raw("Quit application"),
-        ]),
-        Line::from(""),
-        Line::from(vec![
-            Span::styled("Press ", Style::default().fg(Color::DarkGray)),
-            Span::styled("Esc", Style::default().fg(Color::Yellow)),
-            Span::styled(" or ", Style::default().fg(Color::DarkGray)),
-            Span::styled("Q", Style::default().fg(Color::Yellow)),
-            Span::styled(" to close this help", Style::default().fg(Color::DarkGray)),
-        ]),
-    ];
-
-    let paragraph = Paragraph::new(lines)
+    let paragraph = Paragraph::new(help_lines(bindings))
         .block(
             Block::default()
                 .borders(Borders::ALL)
@@ -253,28 +130,48 @@ pub fn draw_shortcuts_help(f: &mut Frame) {
     f.render_widget(paragraph, help_area);
 }
 
-/// Draw a compact shortcuts hint bar
-pub fn draw_shortcuts_hint(f: &mut Frame, area: Rect) {
-    let hints = vec![
-        ("F10", "Menu"),
-        ("P", "Plugins"),
-        ("Ctrl+T", "New"),
-        ("Ctrl+W", "Close"),
-        ("Ctrl+L", "Layout"),
-        ("Ctrl+P", "Pane"),
-        ("O", "Connect"),
-        ("C", "Clear"),
-        ("X", "Mode"),
-        ("?", "Help"),
-    ];
+/// Help overlay content, showing each shortcut's current keys
+fn help_lines(bindings: &KeyBindings) -> Vec<Line<'static>> {
+    let mut lines = Vec::new();
+    for (title, actions) in HELP_SECTIONS {
+        lines.push(Line::from(""));
+        lines.push(Line::from(vec![Span::styled(
+            t!(*title).to_string(),
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        )]));
+        for &action in *actions {
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("  {:<KEY_COLUMN$}", bindings.key_label(action)),
+                    Style::default().fg(Color::Yellow),
+                ),
+                Span::raw(format!(" {}", action.description())),
+            ]));
+        }
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled("Press ", Style::default().fg(Color::DarkGray)),
+        Span::styled("Esc", Style::default().fg(Color::Yellow)),
+        Span::styled(" or ", Style::default().fg(Color::DarkGray)),
+        Span::styled("Q", Style::default().fg(Color::Yellow)),
+        Span::styled(" to close this help", Style::default().fg(Color::DarkGray)),
+    ]));
+    lines
+}
 
+/// Draw a compact shortcuts hint bar
+pub fn draw_shortcuts_hint(f: &mut Frame, area: Rect, bindings: &KeyBindings) {
     let mut spans = vec![];
-    for (i, (key, desc)) in hints.iter().enumerate() {
+    for (i, (action, desc)) in HINTS.iter().enumerate() {
         if i > 0 {
             spans.push(Span::raw("  "));
         }
         spans.push(Span::styled(
-            *key,
+            bindings.key_label(*action),
             Style::default()
                 .fg(Color::Yellow)
                 .add_modifier(Modifier::BOLD),
@@ -292,11 +189,13 @@ pub fn draw_shortcuts_hint(f: &mut Frame, area: Rect) {
     f.render_widget(paragraph, area);
 }
 
-/// Draw an inline shortcuts reference (for specific contexts)
+/// Draw an inline shortcuts reference (for specific contexts), listing the
+/// current keys of `actions`
 pub fn draw_context_shortcuts(
     f: &mut Frame,
     area: Rect,
-    shortcuts: &[(&str, &str)],
+    bindings: &KeyBindings,
+    actions: &[ShortcutAction],
     title: Option<&str>,
 ) {
     let mut lines = vec![];
@@ -311,17 +210,17 @@ pub fn draw_context_shortcuts(
         lines.push(Line::from(""));
     }
 
-    for (key, description) in shortcuts {
+    for &action in actions {
         lines.push(Line::from(vec![
             Span::raw("  "),
             Span::styled(
-                *key,
+                bindings.key_label(action),
                 Style::default()
                     .fg(Color::Yellow)
                     .add_modifier(Modifier::BOLD),
             ),
             Span::raw(": "),
-            Span::raw(*description),
+            Span::raw(action.description()),
         ]));
     }
 
@@ -334,12 +233,26 @@ pub fn draw_context_shortcuts(
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+    use tuiserial_core::KeyChord;
+
+    fn line_text(line: &Line) -> String {
+        line.spans.iter().map(|s| s.content.as_ref()).collect()
+    }
 
     #[test]
-    fn test_shortcuts_rendering() {
-        // Just ensure the functions can be called without panic
-        // Actual rendering tests would require a terminal backend
-        let shortcuts = [("Ctrl+T", "New Session"), ("Ctrl+W", "Close Session")];
-        assert_eq!(shortcuts.len(), 2);
+    fn test_help_shows_current_bindings() {
+        let mut bindings = KeyBindings::default();
+        let text: Vec<String> = help_lines(&bindings).iter().map(line_text).collect();
+        assert!(text.contains(&"  C                Clear Log".to_string()));
+        assert!(text.contains(&"  Ctrl+T           New Session".to_string()));
+
+        bindings.bind(
+            ShortcutAction::ClearLog,
+            KeyChord::ctrl(ratatui::crossterm::event::KeyCode::Char('k')),
+        );
+        let text: Vec<String> = help_lines(&bindings).iter().map(line_text).collect();
+        assert!(text.contains(&"  Ctrl+K           Clear Log".to_string()));
+        assert!(!text.iter().any(|l| l.starts_with("  C ")));
     }
 }
@@ -11,27 +11,111 @@ use ratatui::{
     widgets::{Block, Borders, Paragraph},
 };
 use rust_i18n::t;
-use tuiserial_core::{AppState, Parity};
+use tuiserial_core::{
+    AppState, AppendMode, ConnectionState, CountDisplay, LineEndingStats, LinkQuality, Parity,
+};
 
 use crate::areas::{UiAreaField, update_area};
 
+/// Frames of the spinner shown while connecting
+const SPINNER_FRAMES: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+
+/// Spinner frame for the current time (advances every 100ms)
+fn spinner_frame() -> &'static str {
+    let ms = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_millis())
+        .unwrap_or(0);
+    SPINNER_FRAMES[(ms / 100) as usize % SPINNER_FRAMES.len()]
+}
+
+/// Icon, text and color for the connection status line
+fn connection_status(state: &ConnectionState) -> (String, String, Color) {
+    match state {
+        ConnectionState::Disconnected => (
+            "✗".into(),
+            t!("status.disconnected").to_string(),
+            Color::Red,
+        ),
+        ConnectionState::Connecting => (
+            spinner_frame().into(),
+            t!("status.connecting").to_string(),
+            Color::Yellow,
+        ),
+        ConnectionState::Connected => {
+            ("✓".into(), t!("status.connected").to_string(), Color::Green)
+        }
+        ConnectionState::Error(reason) => (
+            "✗".into(),
+            format!("{}: {reason}", t!("status.error")),
+            Color::Red,
+        ),
+    }
+}
+
+/// Bar cells, color and label for a link quality level
+fn quality_bar(quality: LinkQuality) -> (&'static str, Color, String) {
+    match quality {
+        LinkQuality::Good => ("▮▮▮", Color::Green, t!("status.quality_good").to_string()),
+        LinkQuality::Degraded => (
+            "▮▮▯",
+            Color::Yellow,
+            t!("status.quality_degraded").to_string(),
+        ),
+        LinkQuality::Poor => ("▮▯▯", Color::Red, t!("status.quality_poor").to_string()),
+    }
+}
+
+/// Quality bar while connected with I/O in the window; blank otherwise
+fn quality_line(app: &AppState) -> Line<'static> {
+    let (Some(quality), Some(percent)) =
+        (app.link_quality.quality(), app.link_quality.error_percent())
+    else {
+        return Line::raw("");
+    };
+    if !app.is_connected() {
+        return Line::raw("");
+    }
+    let (bar, color, label) = quality_bar(quality);
+    Line::from(vec![
+        Span::styled(bar, Style::default().fg(color)),
+        Span::raw(" "),
+        Span::styled(label, Style::default().fg(color)),
+        Span::styled(
+            format!(" ({percent:.0}% {})", t!("status.quality_errors")),
+            Style::default().fg(Color::DarkGray),
+        ),
+    ])
+}
+
+/// Lock state plus the unsaved-changes marker, like an editor's modified dot
+fn config_status_line(
+    app: &AppState,
+    (icon, text, color): (&'static str, impl Into<String>, Color),
+) -> Line<'static> {
+    let mut spans = vec![
+        Span::styled(icon, Style::default().fg(color)),
+        Span::raw(" "),
+        Span::styled(text.into(), Style::default().fg(color)),
+    ];
+    if app.has_unsaved_changes() {
+        spans.push(Span::raw("  "));
+        spans.push(Span::styled(
+            t!("status.unsaved").to_string(),
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        ));
+    }
+    Line::from(spans)
+}
+
 /// Draw the connection status panel
 pub fn draw_status_panel(f: &mut Frame, app: &AppState, area: Rect) {
     // Store area for mouse interaction
     update_area(UiAreaField::StatusPanel, area);
 
-    let status_color = if app.is_connected {
-        Color::Green
-    } else {
-        Color::Red
-    };
-
-    let status_icon = if app.is_connected { "✓" } else { "✗" };
-    let status_text = if app.is_connected {
-        t!("status.connected")
-    } else {
-        t!("status.disconnected")
-    };
+    let (status_icon, status_text, status_color) = connection_status(&app.connection);
 
     let config_status = if app.config_locked {
         ("🔒", t!("status.locked"), Color::Yellow)
@@ -46,7 +130,7 @@ pub fn draw_status_panel(f: &mut Frame, app: &AppState, area: Rect) {
         Parity::Odd => t!("parity.odd").chars().next().unwrap_or('O'),
     };
 
-    let text = vec![
+    let mut text = vec![
         Line::from(vec![
             Span::styled(
                 status_icon,
@@ -62,12 +146,8 @@ pub fn draw_status_panel(f: &mut Frame, app: &AppState, area: Rect) {
                     .add_modifier(Modifier::BOLD),
             ),
         ]),
-        Line::from(vec![
-            Span::styled(config_status.0, Style::default().fg(config_status.2)),
-            Span::raw(" "),
-            Span::styled(config_status.1, Style::default().fg(config_status.2)),
-        ]),
-        Line::raw(""),
+        config_status_line(app, config_status),
+        quality_line(app),
         Line::from(vec![
             Span::styled(
                 format!("{}: ", t!("label.port")),
@@ -134,6 +214,9 @@ pub fn draw_status_panel(f: &mut Frame, app: &AppState, area: Rect) {
         ]),
     ];
 
+    // Pending edits go right below the config they modify
+    text.splice(6..6, staged_lines(app));
+
     let para = Paragraph::new(text).block(
         Block::default()
             .borders(Borders::ALL)
@@ -144,12 +227,40 @@ pub fn draw_status_panel(f: &mut Frame, app: &AppState, area: Rect) {
     f.render_widget(para, area);
 }
 
+/// Pending vs applied values while config staging has unapplied edits
+fn staged_lines(app: &AppState) -> Vec<Line<'static>> {
+    let changes = app.staged_changes();
+    if changes.is_empty() {
+        return Vec::new();
+    }
+
+    let mut lines = vec![Line::from(Span::styled(
+        t!("status.staged_pending").to_string(),
+        Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD),
+    ))];
+    lines.extend(changes.into_iter().map(|change| {
+        Line::from(vec![
+            Span::styled(
+                format!(" {}: ", t!(change.label)),
+                Style::default().fg(Color::Cyan),
+            ),
+            Span::styled(change.applied, Style::default().fg(Color::DarkGray)),
+            Span::raw(" → "),
+            Span::styled(change.pending, Style::default().fg(Color::Yellow)),
+        ])
+    }));
+    lines
+}
+
 /// Draw the control/status bar showing statistics
 pub fn draw_control_area(f: &mut Frame, app: &AppState, area: Rect) {
     // Store area for mouse interaction
     update_area(UiAreaField::ControlArea, area);
 
     let auto_scroll_icon = if app.auto_scroll { "🔄" } else { "⏸" };
+    let (retained_rx, retained_tx) = app.message_log.retained_counts();
 
     let stats = vec![
         Span::styled(
@@ -159,7 +270,14 @@ pub fn draw_control_area(f: &mut Frame, app: &AppState, area: Rect) {
                 .add_modifier(Modifier::BOLD),
         ),
         Span::styled(
-            format!("{} ", app.message_log.tx_count),
+            format!(
+                "{} ",
+                count_text(
+                    app.message_log.tx_count,
+                    retained_tx,
+                    app.settings.count_display
+                )
+            ),
             Style::default().fg(Color::White),
         ),
         Span::raw("│ "),
@@ -170,7 +288,14 @@ pub fn draw_control_area(f: &mut Frame, app: &AppState, area: Rect) {
                 .add_modifier(Modifier::BOLD),
         ),
         Span::styled(
-            format!("{} ", app.message_log.rx_count),
+            format!(
+                "{} ",
+                count_text(
+                    app.message_log.rx_count,
+                    retained_rx,
+                    app.settings.count_display
+                )
+            ),
             Style::default().fg(Color::White),
         ),
         Span::raw("│ "),
@@ -200,8 +325,51 @@ pub fn draw_control_area(f: &mut Frame, app: &AppState, area: Rect) {
         ),
     ];
 
-    // Add plugin error count if any
     let mut final_stats: Vec<Span> = stats;
+
+    if app.is_connected() {
+        final_stats.push(Span::raw(" │ "));
+        final_stats.push(Span::styled(
+            format!(
+                "↓{} ↑{}",
+                rate_text(app.rx_rate.bytes_per_sec()),
+                rate_text(app.tx_rate.bytes_per_sec())
+            ),
+            Style::default().fg(Color::DarkGray),
+        ));
+    }
+
+    if let Some(selected) = app.selected_byte_count() {
+        final_stats.push(Span::raw(" │ "));
+        final_stats.push(Span::styled(
+            selection_readout(selected),
+            Style::default().fg(Color::Cyan),
+        ));
+    }
+
+    // Detected RX line ending; green once the TX append mode matches it
+    if let Some(text) = line_ending_text(&app.rx_line_endings) {
+        let color = match app.rx_line_endings.dominant() {
+            Some(mode) if mode == app.tx_append_mode => Color::Green,
+            Some(_) => Color::Yellow,
+            None => Color::DarkGray,
+        };
+        final_stats.push(Span::raw(" │ "));
+        final_stats.push(Span::styled(text, Style::default().fg(color)));
+    }
+
+    // Add repeat send progress while a repeat is running
+    if let Some(repeat) = &app.repeat_send {
+        final_stats.push(Span::raw(" │ "));
+        final_stats.push(Span::styled(
+            format!("⟳ {} {}", t!("repeat.progress"), repeat.progress()),
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        ));
+    }
+
+    // Add plugin error count if any
     if app.plugin_error_count > 0 {
         final_stats.push(Span::raw(" "));
         final_stats.push(Span::styled(
@@ -231,3 +399,193 @@ pub fn draw_control_area(f: &mut Frame, app: &AppState, area: Rect) {
 
     f.render_widget(para, area);
 }
+
+/// Length of the selected bytes in bytes, bits and hex, e.g.
+/// "Sel: 12 B / 96 bit / 0x0C"
+fn selection_readout(len: usize) -> String {
+    format!(
+        "{}: {} B / {} bit / 0x{:02X}",
+        t!("label.selection"),
+        len,
+        len * 8,
+        len
+    )
+}
+
+/// "RX line ending: CRLF" for the detected style, `None` before any
+/// terminator has been received
+fn line_ending_text(stats: &LineEndingStats) -> Option<String> {
+    if stats.total() == 0 {
+        return None;
+    }
+    let style = match stats.dominant() {
+        Some(AppendMode::LF) => "LF".into(),
+        Some(AppendMode::CR) => "CR".into(),
+        Some(AppendMode::CRLF) => "CRLF".into(),
+        _ => t!("label.line_ending_mixed"),
+    };
+    Some(format!("{}: {}", t!("label.rx_line_ending"), style))
+}
+
+/// Byte rate with a decimal unit, e.g. "11.5 kB/s"
+fn rate_text(bytes_per_sec: f64) -> String {
+    if bytes_per_sec >= 1_000_000.0 {
+        format!("{:.1} MB/s", bytes_per_sec / 1_000_000.0)
+    } else if bytes_per_sec >= 1_000.0 {
+        format!("{:.1} kB/s", bytes_per_sec / 1_000.0)
+    } else {
+        format!("{bytes_per_sec:.0} B/s")
+    }
+}
+
+/// Format an RX/TX count according to the display option, labelling which
+/// figure is which when they can differ
+fn count_text(total: u64, retained: usize, display: CountDisplay) -> String {
+    match display {
+        CountDisplay::Total => total.to_string(),
+        CountDisplay::Retained => format!("{} {}", retained, t!("label.count_shown")),
+        CountDisplay::Both => format!(
+            "{} {} / {} {}",
+            total,
+            t!("label.count_total"),
+            retained,
+            t!("label.count_shown")
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tuiserial_core::MAX_LOG_LINES;
+
+    #[test]
+    fn test_count_text_modes() {
+        assert_eq!(count_text(50000, 8000, CountDisplay::Total), "50000");
+        assert_eq!(
+            count_text(50000, 8000, CountDisplay::Retained),
+            "8000 shown"
+        );
+        assert_eq!(
+            count_text(50000, 8000, CountDisplay::Both),
+            "50000 total / 8000 shown"
+        );
+    }
+
+    #[test]
+    fn test_count_text_after_eviction() {
+        let mut app = AppState::default();
+        for _ in 0..MAX_LOG_LINES + 250 {
+            app.append_rx(vec![0]);
+        }
+        let (rx, _) = app.message_log.retained_counts();
+        assert_eq!(
+            count_text(app.message_log.rx_count, rx, CountDisplay::Both),
+            format!("{} total / {} shown", MAX_LOG_LINES + 250, MAX_LOG_LINES)
+        );
+    }
+
+    #[test]
+    fn test_staged_lines_only_when_pending() {
+        let mut app = AppState::default();
+        assert!(staged_lines(&app).is_empty());
+        app.set_config_staging(true);
+        assert!(staged_lines(&app).is_empty());
+
+        app.config.baud_rate = 115200;
+        app.config.stop_bits = 2;
+        let lines = staged_lines(&app);
+        assert_eq!(lines.len(), 3);
+        let text: String = lines[1].spans.iter().map(|s| s.content.as_ref()).collect();
+        assert!(text.ends_with("9600 → 115200"));
+    }
+
+    #[test]
+    fn test_connection_status_states() {
+        let (icon, _, color) = connection_status(&ConnectionState::Connecting);
+        assert!(SPINNER_FRAMES.contains(&icon.as_str()));
+        assert_eq!(color, Color::Yellow);
+
+        let (icon, text, color) = connection_status(&ConnectionState::Error("busy".into()));
+        assert_eq!(icon, "✗");
+        assert!(text.ends_with(": busy"));
+        assert_eq!(color, Color::Red);
+
+        let (icon, _, color) = connection_status(&ConnectionState::Connected);
+        assert_eq!((icon.as_str(), color), ("✓", Color::Green));
+    }
+
+    #[test]
+    fn test_unsaved_marker() {
+        let line_text = |app: &AppState| -> String {
+            config_status_line(app, ("🔓", "Modifiable", Color::Green))
+                .spans
+                .iter()
+                .map(|s| s.content.as_ref())
+                .collect()
+        };
+        let mut app = AppState::default();
+        assert!(!line_text(&app).contains('*'));
+        app.config.baud_rate = 115200;
+        assert!(line_text(&app).ends_with("* Unsaved"));
+    }
+
+    #[test]
+    fn test_line_ending_text() {
+        let mut stats = LineEndingStats::default();
+        assert_eq!(line_ending_text(&stats), None);
+        stats.feed(b"OK\r\n");
+        assert!(line_ending_text(&stats).unwrap().ends_with(": CRLF"));
+        stats.feed(b"A\rB\n");
+        assert!(
+            line_ending_text(&stats)
+                .unwrap()
+                .ends_with(&format!(": {}", t!("label.line_ending_mixed")))
+        );
+    }
+
+    #[test]
+    fn test_selection_readout() {
+        let label = t!("label.selection");
+        assert_eq!(selection_readout(0), format!("{label}: 0 B / 0 bit / 0x00"));
+        assert_eq!(selection_readout(1), format!("{label}: 1 B / 8 bit / 0x01"));
+        assert_eq!(
+            selection_readout(255),
+            format!("{label}: 255 B / 2040 bit / 0xFF")
+        );
+        assert_eq!(
+            selection_readout(4096),
+            format!("{label}: 4096 B / 32768 bit / 0x1000")
+        );
+    }
+
+    #[test]
+    fn test_quality_line_only_while_connected() {
+        let text = |app: &AppState| -> String {
+            quality_line(app)
+                .spans
+                .iter()
+                .map(|s| s.content.as_ref())
+                .collect()
+        };
+        let mut app = AppState::default();
+        app.mark_connected();
+        assert_eq!(text(&app), "");
+
+        for ok in [true, true, true, false] {
+            app.link_quality.record(ok);
+        }
+        assert_eq!(text(&app), "▮▯▯ Poor link (25% errors)");
+
+        app.mark_disconnected(None);
+        assert_eq!(text(&app), "");
+    }
+
+    #[test]
+    fn test_rate_text() {
+        assert_eq!(rate_text(0.0), "0 B/s");
+        assert_eq!(rate_text(960.0), "960 B/s");
+        assert_eq!(rate_text(11_520.0), "11.5 kB/s");
+        assert_eq!(rate_text(1_250_000.0), "1.2 MB/s");
+    }
+}
@@ -0,0 +1,135 @@
+//! RX read timing overlay — shows the gaps between the focused RX read and
+//! the reads that follow it, at microsecond resolution.
+
+use std::time::Duration;
+
+use ratatui::{
+    Frame,
+    layout::{Alignment, Rect},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+use rust_i18n::t;
+use tuiserial_core::ChunkDelta;
+
+/// Reads listed in the overlay, starting at the focused one
+pub const TIMING_ROWS: usize = 8;
+
+/// Draw the timing table for `deltas` anchored to the bottom of `area`
+pub fn draw_rx_timing(f: &mut Frame, area: Rect, deltas: &[ChunkDelta]) {
+    let width = 56.min(area.width);
+    let height = (TIMING_ROWS as u16 + 4).min(area.height);
+    let overlay = Rect {
+        x: area.x + area.width.saturating_sub(width),
+        y: area.y + area.height.saturating_sub(height),
+        width,
+        height,
+    };
+
+    f.render_widget(Clear, overlay);
+
+    let header = t!("timing.header");
+    let columns: Vec<&str> = header.split('|').collect();
+    let mut lines = vec![Line::from(Span::styled(
+        row_text(
+            columns.first().copied().unwrap_or(""),
+            columns.get(1).copied().unwrap_or(""),
+            columns.get(2).copied().unwrap_or(""),
+            columns.get(3).copied().unwrap_or(""),
+            columns.get(4).copied().unwrap_or(""),
+        ),
+        Style::default().fg(Color::Cyan),
+    ))];
+
+    if deltas.is_empty() {
+        lines.push(Line::from(Span::styled(
+            format!(" {}", t!("timing.not_recorded")),
+            Style::default().fg(Color::Yellow),
+        )));
+    }
+    for (i, d) in deltas.iter().enumerate() {
+        let style = if i == 0 {
+            Style::default().fg(Color::Yellow)
+        } else {
+            Style::default().fg(Color::White)
+        };
+        lines.push(Line::from(Span::styled(delta_row(d), style)));
+    }
+
+    lines.push(Line::from(Span::styled(
+        format!(" {}", t!("timing.hint")),
+        Style::default().fg(Color::DarkGray),
+    )));
+
+    let para = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan))
+            .title(format!(" {} ", t!("timing.title")))
+            .title_alignment(Alignment::Left)
+            .style(Style::default().bg(Color::Black)),
+    );
+
+    f.render_widget(para, overlay);
+}
+
+fn row_text(seq: &str, offset: &str, len: &str, gap: &str, per_byte: &str) -> String {
+    format!(" {seq:<8}{offset:>8}{len:>7}{gap:>12}{per_byte:>12}")
+}
+
+/// One table row for `d`; the gap columns show "-" for the first read
+fn delta_row(d: &ChunkDelta) -> String {
+    let dash = || "-".to_string();
+    row_text(
+        &format!("#{}", d.seq),
+        &d.offset.to_string(),
+        &d.len.to_string(),
+        &d.delta.map(format_gap).unwrap_or_else(dash),
+        &d.per_byte().map(format_gap).unwrap_or_else(dash),
+    )
+}
+
+/// A gap in µs below one millisecond, otherwise in ms with µs precision
+fn format_gap(d: Duration) -> String {
+    let micros = d.as_micros();
+    if micros < 1000 {
+        format!("{micros} µs")
+    } else {
+        format!("{}.{:03} ms", micros / 1000, micros % 1000)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_gap() {
+        assert_eq!(format_gap(Duration::from_micros(0)), "0 µs");
+        assert_eq!(format_gap(Duration::from_micros(999)), "999 µs");
+        assert_eq!(format_gap(Duration::from_micros(1250)), "1.250 ms");
+        assert_eq!(format_gap(Duration::from_millis(42)), "42.000 ms");
+    }
+
+    #[test]
+    fn test_delta_row() {
+        let first = ChunkDelta {
+            seq: 7,
+            offset: 0,
+            len: 4,
+            delta: None,
+        };
+        let row = delta_row(&first);
+        assert!(row.starts_with(" #7 "), "{row}");
+        assert!(row.trim_end().ends_with("-"), "{row}");
+
+        let next = ChunkDelta {
+            delta: Some(Duration::from_micros(2000)),
+            ..first
+        };
+        let row = delta_row(&next);
+        assert!(row.contains("2.000 ms"), "{row}");
+        assert!(row.contains("500 µs"), "{row}");
+    }
+}
@@ -11,7 +11,8 @@ use ratatui::{
     widgets::{Block, Borders, List, ListItem, Paragraph, Wrap},
 };
 use rust_i18n::t;
-use tuiserial_core::{AppState, FocusedField, TxMode, display_width};
+use tuiserial_core::{AppState, FocusedField, TxMode};
+use tuiserial_serial::bytes_to_string;
 
 use crate::areas::{UiAreaField, update_area, update_cursor_state};
 
@@ -69,57 +70,11 @@ fn draw_tx_input(f: &mut Frame, app: &AppState, area: Rect) {
 
     let inner_width = area.width.saturating_sub(2) as usize; // minus borders
 
-    // Compute visible text and cursor visual position, with horizontal scrolling
+    // Visible text and cursor column, scrolled horizontally to the cursor
     let (visible_text, cursor_visual_x) = if app.tx_input.is_empty() {
         (prompt_text.to_string(), 0)
     } else {
-        let chars: Vec<char> = app.tx_input.chars().collect();
-        let cursor_pos = app.tx_cursor.min(chars.len());
-        let text_before_cursor: String = chars[..cursor_pos].iter().collect();
-        let cursor_x = display_width(&text_before_cursor);
-        let text_width = display_width(&app.tx_input);
-
-        if text_width <= inner_width {
-            (app.tx_input.clone(), cursor_x)
-        } else {
-            // Stateless scroll: keep cursor visible within the input box
-            let max_scroll = text_width.saturating_sub(inner_width);
-            let scroll = if cursor_x < inner_width {
-                0
-            } else {
-                cursor_x
-                    .saturating_sub(inner_width.saturating_sub(1))
-                    .min(max_scroll)
-            };
-
-            // Find start char index from display scroll position
-            let mut acc = 0;
-            let mut start = 0;
-            for (i, c) in chars.iter().enumerate() {
-                let w = if c.is_ascii() { 1 } else { 2 };
-                if acc + w > scroll {
-                    start = i;
-                    break;
-                }
-                acc += w;
-            }
-
-            // Find end char index within visible area
-            let mut acc = 0;
-            let mut end = chars.len();
-            for (i, c) in chars.iter().enumerate().skip(start) {
-                let w = if c.is_ascii() { 1 } else { 2 };
-                if acc + w > inner_width {
-                    end = i;
-                    break;
-                }
-                acc += w;
-            }
-
-            let visible: String = chars[start..end].iter().collect();
-            let visual_x = cursor_x.saturating_sub(scroll);
-            (visible, visual_x)
-        }
+        app.tx_input.visible_window(inner_width)
     };
 
     let cursor_line = if app.tx_input.is_empty() {
@@ -154,7 +109,7 @@ fn draw_tx_input(f: &mut Frame, app: &AppState, area: Rect) {
     let text = vec![
         Line::from(""),
         cursor_line,
-        Line::from(""),
+        tx_wrap_line(app),
         Line::from(Span::styled(
             help_text,
             Style::default().fg(Color::DarkGray),
@@ -183,6 +138,23 @@ fn draw_tx_input(f: &mut Frame, app: &AppState, area: Rect) {
     }
 }
 
+/// The prefix/suffix added to every send, so they are not forgotten;
+/// empty when neither is set
+fn tx_wrap_line(app: &AppState) -> Line<'static> {
+    let (prefix, suffix) = (&app.settings.tx_prefix, &app.settings.tx_suffix);
+    let mut parts = Vec::new();
+    if !prefix.is_empty() {
+        parts.push(format!("{}: {}", t!("tx.prefix"), bytes_to_string(prefix)));
+    }
+    if !suffix.is_empty() {
+        parts.push(format!("{}: {}", t!("tx.suffix"), bytes_to_string(suffix)));
+    }
+    Line::from(Span::styled(
+        parts.join("  "),
+        Style::default().fg(Color::Magenta),
+    ))
+}
+
 /// Draw the append mode selector
 fn draw_append_selector(f: &mut Frame, app: &AppState, area: Rect) {
     let items: Vec<ListItem> = app